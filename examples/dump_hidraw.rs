@@ -0,0 +1,37 @@
+//! Pretty print the report descriptor of a hidraw device.
+//!
+//! ```sh
+//! cargo run --features std --example dump_hidraw -- /dev/hidraw0
+//! ```
+//!
+//! A path to a binary descriptor file is read as is, e.g.
+//! `/sys/class/hidraw/hidraw0/device/report_descriptor`.
+
+use std::{env, path::PathBuf, process::ExitCode};
+
+use hid_report::{parse_file, pretty_print};
+
+fn main() -> ExitCode {
+    let Some(arg) = env::args().nth(1) else {
+        eprintln!("usage: dump_hidraw <hidraw device or descriptor file>");
+        return ExitCode::FAILURE;
+    };
+    // The kernel exposes the descriptor of /dev/hidrawN through sysfs, which
+    // avoids the HIDIOCGRDESC ioctl.
+    let path = match arg.strip_prefix("/dev/") {
+        Some(name) if name.starts_with("hidraw") => {
+            PathBuf::from(format!("/sys/class/hidraw/{name}/device/report_descriptor"))
+        }
+        _ => PathBuf::from(arg),
+    };
+    match parse_file(&path) {
+        Ok(items) => {
+            println!("{}", pretty_print(&items));
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{}: {}", path.display(), error);
+            ExitCode::FAILURE
+        }
+    }
+}
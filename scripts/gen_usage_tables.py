@@ -0,0 +1,44 @@
+#!/usr/bin/env python3
+"""Generate src/usage_tables.rs from the HID Usage Tables JSON.
+
+The JSON is published by the USB-IF alongside the HID Usage Tables document
+(HidUsageTables.json). Usage:
+
+    python3 scripts/gen_usage_tables.py HidUsageTables.json > src/usage_tables.rs
+
+Pages whose usages are generated rather than listed (Button, Ordinal, Unicode,
+Monitor Enumerated) only contribute their explicitly named usages; the crate
+formats the others itself.
+"""
+
+import json
+import sys
+
+HEADER = """\
+// Generated by scripts/gen_usage_tables.py from the HID Usage Tables JSON,
+// do not edit by hand.
+
+/// Usage names of the HID Usage Tables, sorted by extended usage, i.e. the usage
+/// page in the high 16 bits and the usage ID in the low 16 bits.
+#[rustfmt::skip]
+pub(crate) static USAGE_NAMES: &[(u32, &str)] = &[
+"""
+
+
+def main():
+    with open(sys.argv[1], encoding="utf-8") as file:
+        tables = json.load(file)
+    usages = {}
+    for page in tables["UsagePages"]:
+        for usage in page.get("UsageIds") or []:
+            name = " ".join(usage["Name"].split())
+            usages[page["Id"] << 16 | usage["Id"]] = name
+    sys.stdout.write(HEADER)
+    for usage, name in sorted(usages.items()):
+        name = name.replace("\\", "\\\\").replace('"', '\\"')
+        sys.stdout.write(f'    (0x{usage >> 16:04X}_{usage & 0xFFFF:04X}, "{name}"),\n')
+    sys.stdout.write("];\n")
+
+
+if __name__ == "__main__":
+    main()
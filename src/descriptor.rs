@@ -0,0 +1,163 @@
+use crate::{dump, parse, pretty_print, HidError, ReportItem};
+use alloc::{string::String, vec::Vec};
+use std::ops::Index;
+
+/// A report descriptor, i.e. a list of [ReportItem]s.
+///
+/// Behaves like the [Vec] it wraps while carrying the crate's functions as methods.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{Descriptor, LogicalMaximum, ReportItem};
+///
+/// let mut descriptor = Descriptor::parse([0x05, 0x0C, 0x09, 0x01]);
+/// descriptor.push(ReportItem::LogicalMaximum(LogicalMaximum::new_with(&[0x3C, 0x02]).unwrap()));
+/// assert_eq!(descriptor.len(), 3);
+/// assert_eq!(descriptor[1].to_string(), "Usage (Consumer Control)");
+/// assert_eq!(descriptor.dump(), [0x05, 0x0C, 0x09, 0x01, 0x26, 0x3C, 0x02]);
+///
+/// for item in &descriptor {
+///     assert!(!item.to_string().is_empty());
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Descriptor(Vec<ReportItem>);
+
+impl Descriptor {
+    /// Create an empty descriptor.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Parse a byte stream into a descriptor, see [`parse()`].
+    pub fn parse<ByteStream: IntoIterator<Item = u8>>(byte_stream: ByteStream) -> Self {
+        Self(parse(byte_stream).collect())
+    }
+
+    /// Get the number of items.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the descriptor has no item.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the items.
+    pub fn iter(&self) -> std::slice::Iter<'_, ReportItem> {
+        self.0.iter()
+    }
+
+    /// Get the items as a slice.
+    pub fn as_slice(&self) -> &[ReportItem] {
+        &self.0
+    }
+
+    /// Unwrap into the inner [Vec].
+    pub fn into_inner(self) -> Vec<ReportItem> {
+        self.0
+    }
+
+    /// Append an item.
+    pub fn push(&mut self, item: ReportItem) {
+        self.0.push(item);
+    }
+
+    /// Append an item in strict mode.
+    ///
+    /// [`Reserved`](ReportItem::Reserved) items will be rejected with [`HidError::ReservedItem`].
+    pub fn push_strict(&mut self, item: ReportItem) -> Result<(), HidError> {
+        if let ReportItem::Reserved(reserved) = item {
+            return Err(HidError::ReservedItem(reserved));
+        }
+        self.0.push(item);
+        Ok(())
+    }
+
+    /// Append items in strict mode.
+    ///
+    /// If any [`Reserved`](ReportItem::Reserved) item is found, it will be reported
+    /// as [`HidError::ReservedItem`] and none of the items will be appended.
+    pub fn extend_strict<ItemStream: IntoIterator<Item = ReportItem>>(
+        &mut self,
+        item_stream: ItemStream,
+    ) -> Result<(), HidError> {
+        let items = item_stream.into_iter().collect::<Vec<_>>();
+        if let Some(ReportItem::Reserved(reserved)) = items
+            .iter()
+            .find(|item| matches!(item, ReportItem::Reserved(_)))
+        {
+            return Err(HidError::ReservedItem(reserved.clone()));
+        }
+        self.0.extend(items);
+        Ok(())
+    }
+
+    /// Dump items into a byte stream, see [`dump()`].
+    pub fn dump(&self) -> Vec<u8> {
+        dump(&self.0)
+    }
+
+    /// Print items to string in a pretty way, see [`pretty_print()`].
+    pub fn pretty_print(&self) -> String {
+        pretty_print(&self.0)
+    }
+}
+
+impl From<Vec<ReportItem>> for Descriptor {
+    fn from(items: Vec<ReportItem>) -> Self {
+        Self(items)
+    }
+}
+
+impl From<Descriptor> for Vec<ReportItem> {
+    fn from(descriptor: Descriptor) -> Self {
+        descriptor.0
+    }
+}
+
+impl AsRef<[ReportItem]> for Descriptor {
+    fn as_ref(&self) -> &[ReportItem] {
+        &self.0
+    }
+}
+
+impl FromIterator<ReportItem> for Descriptor {
+    fn from_iter<ItemStream: IntoIterator<Item = ReportItem>>(item_stream: ItemStream) -> Self {
+        Self(item_stream.into_iter().collect())
+    }
+}
+
+impl Extend<ReportItem> for Descriptor {
+    fn extend<ItemStream: IntoIterator<Item = ReportItem>>(&mut self, item_stream: ItemStream) {
+        self.0.extend(item_stream);
+    }
+}
+
+impl IntoIterator for Descriptor {
+    type Item = ReportItem;
+    type IntoIter = alloc::vec::IntoIter<ReportItem>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Descriptor {
+    type Item = &'a ReportItem;
+    type IntoIter = std::slice::Iter<'a, ReportItem>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl Index<usize> for Descriptor {
+    type Output = ReportItem;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
@@ -18,4 +18,52 @@ pub enum HidError {
     EmptyRawInput,
     /// Strict mode is set and reserved item is found.
     ReservedItem(Reserved),
+    /// Strict mode is set and a long item is found, whose data is skipped.
+    LongItem {
+        /// Data size of the long item, i.e. `bDataSize`.
+        data_size: u8,
+        /// Tag of the long item, i.e. `bLongItemTag`.
+        tag: u8,
+    },
+    /// A token of a hex string isn't a byte, see
+    /// [`ReportItem::from_str()`](crate::ReportItem#impl-FromStr-for-ReportItem).
+    InvalidHex,
+    /// More items are found than allowed, see [`parse_limited()`](crate::parse_limited()).
+    ItemLimitExceeded,
+    /// Collections are nested deeper than allowed, see
+    /// [`build_tree_with()`](crate::build_tree_with()).
+    NestingTooDeep {
+        /// Depth of the first collection exceeding the limit.
+        depth: usize,
+    },
+    /// A [Collection](crate::Collection) or [Push](crate::Push) item is never
+    /// closed, or an [End Collection](crate::EndCollection) or [Pop](crate::Pop)
+    /// item closes nothing, see [`build_tree()`](crate::build_tree()) and
+    /// [`pretty_print_checked()`](crate::pretty_print_checked()).
+    UnbalancedItem {
+        /// Index of the offending item. For unclosed items, it's the innermost one.
+        index: usize,
+    },
+    /// The output buffer is too small, see [`dump_into()`](crate::dump_into()).
+    BufferTooSmall {
+        /// Byte length of the items.
+        required: usize,
+        /// Length of the buffer.
+        provided: usize,
+    },
+}
+
+/// Error type of [`parse_try()`](crate::parse_try()).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError<E> {
+    /// The byte source failed.
+    Source(E),
+    /// The bytes are not a valid report item.
+    Hid(HidError),
+}
+
+impl<E> From<HidError> for ParseError<E> {
+    fn from(error: HidError) -> Self {
+        Self::Hid(error)
+    }
 }
@@ -0,0 +1,176 @@
+use crate::{byte_len, pretty_lines, ReportItem};
+use alloc::{format, string::String, vec::Vec};
+
+/// Options of [`to_c_array_with()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CArrayOptions<'a> {
+    /// Element type of the array, `uint8_t` by default.
+    pub element_type: &'a str,
+    /// Whether to annotate each item with a comment, `true` by default.
+    pub comments: bool,
+}
+
+impl Default for CArrayOptions<'_> {
+    fn default() -> Self {
+        Self {
+            element_type: "uint8_t",
+            comments: true,
+        }
+    }
+}
+
+/// Export items as a C array literal, with default [CArrayOptions].
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, to_c_array};
+///
+/// let items = parse([0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0xC0]).collect::<Vec<_>>();
+///
+/// const EXPECTED: &str = indoc::indoc! {"
+///     static const uint8_t report_descriptor[] = {
+///         0x05, 0x0C,  // Usage Page (Consumer)
+///         0x09, 0x01,  // Usage (Consumer Control)
+///         0xA1, 0x01,  //   Collection (Application)
+///         0xC0,        // End Collection
+///     };"
+/// };
+///
+/// assert_eq!(to_c_array(&items, "report_descriptor"), EXPECTED);
+/// ```
+pub fn to_c_array<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+    item_stream: ItemStream,
+    name: &str,
+) -> String {
+    to_c_array_with(item_stream, name, &CArrayOptions::default())
+}
+
+/// Export items as a C array literal.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, to_c_array_with, CArrayOptions};
+///
+/// let items = parse([0x05, 0x0C, 0x09, 0x01]).collect::<Vec<_>>();
+/// let options = CArrayOptions {
+///     element_type: "char",
+///     comments: false,
+/// };
+///
+/// const EXPECTED: &str = indoc::indoc! {"
+///     static const char report_descriptor[] = {
+///         0x05, 0x0C,
+///         0x09, 0x01,
+///     };"
+/// };
+///
+/// assert_eq!(to_c_array_with(&items, "report_descriptor", &options), EXPECTED);
+/// ```
+pub fn to_c_array_with<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+    item_stream: ItemStream,
+    name: &str,
+    options: &CArrayOptions,
+) -> String {
+    let mut lines = Vec::new();
+    lines.push(format!(
+        "static const {} {}[] = {{",
+        options.element_type, name
+    ));
+    lines.extend(__array_body(item_stream, options.comments));
+    lines.push(String::from("};"));
+    lines.join("\n")
+}
+
+/// Export items as a Rust byte array constant, the length is computed automatically.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, to_rust_array};
+///
+/// let items = parse([0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0xC0]).collect::<Vec<_>>();
+///
+/// const EXPECTED: &str = indoc::indoc! {"
+///     pub const REPORT_DESCRIPTOR: [u8; 7] = [
+///         0x05, 0x0C,  // Usage Page (Consumer)
+///         0x09, 0x01,  // Usage (Consumer Control)
+///         0xA1, 0x01,  //   Collection (Application)
+///         0xC0,        // End Collection
+///     ];"
+/// };
+///
+/// assert_eq!(to_rust_array(&items, "REPORT_DESCRIPTOR"), EXPECTED);
+/// ```
+pub fn to_rust_array<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+    item_stream: ItemStream,
+    name: &str,
+) -> String {
+    let items = item_stream.into_iter().collect::<Vec<_>>();
+    let len = byte_len(items.iter().copied());
+    let mut lines = Vec::new();
+    lines.push(format!("pub const {}: [u8; {}] = [", name, len));
+    lines.extend(__array_body(items, true));
+    lines.push(String::from("];"));
+    lines.join("\n")
+}
+
+fn __array_body<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+    item_stream: ItemStream,
+    comments: bool,
+) -> Vec<String> {
+    let lines = pretty_lines(item_stream);
+    let width_of_raw = lines
+        .iter()
+        .map(|line| line.raw.len() + 3)
+        .max()
+        .unwrap_or(0);
+    lines
+        .into_iter()
+        .map(|line| {
+            let raw = format!("{},", line.raw);
+            if comments {
+                let tab = line.indent * 2 + 1;
+                format!("    {:<width_of_raw$}//{:<tab$}{}", raw, ' ', line.comment)
+            } else {
+                format!("    {}", raw)
+            }
+        })
+        .collect()
+}
+
+/// Generate the HID class descriptor (HID 1.11, section 6.2.1) that announces a
+/// single report descriptor of `report_len` bytes, see [`byte_len()`].
+///
+/// # Panics
+///
+/// Panics if `report_len` doesn't fit in the 16-bit `wDescriptorLength` field.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{byte_len, hid_descriptor_header, parse};
+///
+/// let items = parse([0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0xC0]).collect::<Vec<_>>();
+/// assert_eq!(
+///     hid_descriptor_header(byte_len(&items), 0x00),
+///     [0x09, 0x21, 0x11, 0x01, 0x00, 0x01, 0x22, 0x07, 0x00]
+/// );
+/// ```
+pub fn hid_descriptor_header(report_len: usize, country_code: u8) -> [u8; 9] {
+    let report_len = u16::try_from(report_len)
+        .expect("report descriptor is too long")
+        .to_le_bytes();
+    [
+        0x09, // bLength
+        0x21, // bDescriptorType: HID
+        0x11, // bcdHID: 1.11
+        0x01,
+        country_code,  // bCountryCode
+        0x01,          // bNumDescriptors
+        0x22,          // bDescriptorType: Report
+        report_len[0], // wDescriptorLength
+        report_len[1],
+    ]
+}
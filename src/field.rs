@@ -0,0 +1,640 @@
+use crate::{
+    __data_to_signed, __data_to_unsigned, __extended_usage, __signed_to_data, GlobalState,
+    ReportId, ReportItem, Unit, UnitExponent,
+};
+use alloc::{collections::BTreeMap, vec::Vec};
+
+/// Types of reports, i.e. which main item declares a field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReportType {
+    /// Declared by an [Input](crate::Input) item.
+    Input,
+    /// Declared by an [Output](crate::Output) item.
+    Output,
+    /// Declared by a [Feature](crate::Feature) item.
+    Feature,
+}
+
+/// A single field of a report, see [`field_map()`].
+///
+/// Usages are extended usages, i.e. the usage page in the high 16 bits and the
+/// usage ID in the low 16 bits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// [Report ID](crate::ReportId) of the report, `None` if the descriptor declares none.
+    pub report_id: Option<u32>,
+    /// Type of the report.
+    pub report_type: ReportType,
+    /// Offset of the field in bits, counted from the start of the report
+    /// excluding the report ID byte.
+    pub bit_offset: usize,
+    /// Width of the field in bits.
+    pub bit_width: usize,
+    /// Usage of a variable field, or every usage an array field may report.
+    pub usages: Vec<u32>,
+    /// Alternative usages of each usage in `usages`, declared after it in a set
+    /// bracketed by [Delimiter](crate::Delimiter)s, e.g. the same key on another
+    /// keyboard layout. Empty for usages declared outside of delimiters.
+    pub alternatives: Vec<Vec<u32>>,
+    /// Whether the field is an array, whose value is an index into `usages`
+    /// (offset by `logical_minimum`) rather than the value of a single usage.
+    pub is_array: bool,
+    /// [Logical Minimum](crate::LogicalMinimum) of the field.
+    pub logical_minimum: i32,
+    /// [Logical Maximum](crate::LogicalMaximum) of the field.
+    pub logical_maximum: i32,
+    /// [Unit] of the field, `None` if no unit is declared.
+    pub unit: Option<Unit>,
+    /// [Unit Exponent](UnitExponent) of the field, `None` if no unit exponent is declared.
+    pub unit_exponent: Option<UnitExponent>,
+    /// Whether the field is constant padding without usage, which should be
+    /// skipped when matching usages to values.
+    pub is_padding: bool,
+    /// Extended usages of the enclosing [Collection](crate::Collection)s, outermost
+    /// first, which tell identical usages of different collections apart. A
+    /// collection without usage is represented by 0.
+    pub collection_path: Vec<u32>,
+}
+
+impl FieldInfo {
+    /// Create a padding field, i.e. a constant field without usage.
+    pub fn padding(
+        report_id: Option<u32>,
+        report_type: ReportType,
+        bit_offset: usize,
+        bit_width: usize,
+    ) -> Self {
+        Self {
+            report_id,
+            report_type,
+            bit_offset,
+            bit_width,
+            usages: Vec::new(),
+            alternatives: Vec::new(),
+            is_array: false,
+            logical_minimum: 0,
+            logical_maximum: 0,
+            unit: None,
+            unit_exponent: None,
+            is_padding: true,
+            collection_path: Vec::new(),
+        }
+    }
+}
+
+// Bounds on what [field_map()] lays out, so that a hostile descriptor, e.g. a huge
+// Report Count, can't exhaust memory: the fields laid out, and the usages,
+// alternatives and collection path entries they hold in total.
+const MAX_FIELDS: usize = 0x10000;
+pub(crate) const MAX_USAGES: usize = 0x10_0000;
+
+struct Globals {
+    report_id: Option<u32>,
+    report_size: usize,
+    report_count: usize,
+    logical_minimum: i32,
+    logical_maximum: i32,
+    unit: Option<Unit>,
+    unit_exponent: Option<UnitExponent>,
+}
+
+impl From<&GlobalState> for Globals {
+    fn from(state: &GlobalState) -> Self {
+        Self {
+            report_id: state.report_id.as_ref().map(ReportId::value),
+            report_size: state
+                .report_size
+                .as_ref()
+                .map_or(0, |item| __data_to_unsigned(item.data()) as usize),
+            report_count: state
+                .report_count
+                .as_ref()
+                .map_or(0, |item| __data_to_unsigned(item.data()) as usize),
+            logical_minimum: state
+                .logical_minimum
+                .as_ref()
+                .map_or(0, |item| __data_to_signed(item.data())),
+            logical_maximum: state
+                .logical_maximum
+                .as_ref()
+                .map_or(0, |item| __data_to_signed(item.data())),
+            unit: state.unit.clone(),
+            unit_exponent: state.unit_exponent.clone(),
+        }
+    }
+}
+
+/// Flatten main items into the fields of each report, with their bit layout.
+///
+/// Every field declared by an [Input](crate::Input), [Output](crate::Output) or
+/// [Feature](crate::Feature) item gets its own [FieldInfo], i.e. a main item
+/// declares [Report Count](crate::ReportCount) fields of
+/// [Report Size](crate::ReportSize) bits each.
+///
+/// Constant fields without usage are reported as [padding](FieldInfo::padding()).
+///
+/// If a variable item declares fewer usages than fields, the last usage applies to
+/// the remaining fields. Usages beyond the field count are ignored.
+///
+/// Only the first usage of a delimited set counts as a usage, the others are its
+/// [alternatives](FieldInfo::alternatives).
+///
+/// A [Usage Minimum](crate::UsageMinimum) / [Usage Maximum](crate::UsageMaximum)
+/// range expands within the usage page of its minimum. The map stops after 65536
+/// fields, or once its fields hold 1048576 usages, alternatives and collection path
+/// entries in total, so hostile descriptors declaring billions of fields or usages
+/// are cut short rather than exhausting memory.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{field_map, parse, FieldInfo, ReportType};
+///
+/// let bytes = [
+///     0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x85, 0x01, 0x05, 0x09, 0x19, 0x01,
+///     0x29, 0x03, 0x15, 0x00, 0x25, 0x01, 0x75, 0x01, 0x95, 0x03, 0x81, 0x02,
+///     0x05, 0x01, 0x09, 0x30, 0x15, 0x81, 0x25, 0x7F, 0x75, 0x08, 0x95, 0x01,
+///     0x81, 0x06, 0xC0,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// let fields = field_map(&items);
+/// assert_eq!(fields.len(), 4);
+/// assert_eq!(fields[2].report_id, Some(1));
+/// assert_eq!(fields[2].report_type, ReportType::Input);
+/// assert_eq!(fields[2].bit_offset, 2);
+/// assert_eq!(fields[2].bit_width, 1);
+/// assert_eq!(fields[2].usages, [0x0009_0003]);
+/// assert_eq!(fields[3].bit_offset, 3);
+/// assert_eq!(fields[3].bit_width, 8);
+/// assert_eq!(fields[3].usages, [0x0001_0030]);
+/// assert_eq!(fields[3].logical_minimum, -127);
+/// assert_eq!(fields[3].logical_maximum, 127);
+/// assert_eq!(fields[3].collection_path, [0x0001_0002]);
+/// assert_eq!(fields[3].unit, None);
+///
+/// // A distance in 10^-2 cm.
+/// let bytes = [0x65, 0x11, 0x55, 0x0E, 0x75, 0x10, 0x95, 0x01, 0x81, 0x02];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// let fields = field_map(&items);
+/// assert_eq!(fields[0].unit.as_ref().unwrap().data(), [0x11]);
+/// assert_eq!(fields[0].unit_exponent.as_ref().unwrap().exponent(), Some(-2));
+///
+/// // 3 buttons followed by 5 bits of padding.
+/// let bytes = [
+///     0x05, 0x09, 0x19, 0x01, 0x29, 0x03, 0x75, 0x01, 0x95, 0x03, 0x81, 0x02,
+///     0x75, 0x05, 0x95, 0x01, 0x81, 0x01,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// let fields = field_map(&items);
+/// assert!(fields[..3].iter().all(|field| !field.is_padding));
+/// assert_eq!(fields[3], FieldInfo::padding(None, ReportType::Input, 3, 5));
+///
+/// // 2 usages for 3 fields, then 2 usages for 1 field.
+/// let bytes = [
+///     0x05, 0x01, 0x09, 0x30, 0x09, 0x31, 0x75, 0x08, 0x95, 0x03, 0x81, 0x02,
+///     0x09, 0x38, 0x09, 0x32, 0x95, 0x01, 0x81, 0x06,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// let usages = field_map(&items)
+///     .into_iter()
+///     .map(|field| field.usages)
+///     .collect::<Vec<_>>();
+/// assert_eq!(usages, [[0x0001_0030], [0x0001_0031], [0x0001_0031], [0x0001_0038]]);
+///
+/// // A key that is Q on QWERTY layouts and A on AZERTY layouts.
+/// let bytes = [
+///     0x05, 0x07, 0xA9, 0x01, 0x09, 0x14, 0x09, 0x04, 0xA9, 0x00, 0x75, 0x01,
+///     0x95, 0x01, 0x81, 0x02,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// let fields = field_map(&items);
+/// assert_eq!(fields.len(), 1);
+/// assert_eq!(fields[0].usages, [0x0007_0014]);
+/// assert_eq!(fields[0].alternatives, [[0x0007_0004]]);
+///
+/// // Report Count (4294967295) and Usage Minimum (0) to Usage Maximum (4294967295).
+/// let bytes = [
+///     0x19, 0x00, 0x2B, 0xFF, 0xFF, 0xFF, 0xFF, 0x75, 0x01, 0x97, 0xFF, 0xFF, 0xFF,
+///     0xFF, 0x81, 0x02,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// let fields = field_map(&items);
+/// assert_eq!(fields.len(), 0x10000);
+/// assert_eq!(fields[0xFFFF].usages, [0xFFFF]);
+/// ```
+pub fn field_map(items: &[ReportItem]) -> Vec<FieldInfo> {
+    let mut fields = Vec::new();
+    let mut state = GlobalState::new();
+    let mut usages = Vec::new();
+    let mut alternatives: Vec<Vec<u32>> = Vec::new();
+    // Whether a delimited set is open, and whether it has its preferred usage.
+    let mut delimited = None;
+    let mut usage_minimum = None;
+    let mut offsets: BTreeMap<(Option<u32>, ReportType), usize> = BTreeMap::new();
+    let mut collection_path = Vec::new();
+    let mut budget = MAX_USAGES;
+    for item in items {
+        let report_type = match item {
+            ReportItem::Input(_) => Some(ReportType::Input),
+            ReportItem::Output(_) => Some(ReportType::Output),
+            ReportItem::Feature(_) => Some(ReportType::Feature),
+            _ => None,
+        };
+        if let Some(report_type) = report_type {
+            let globals = Globals::from(&state);
+            let flags = __data_to_unsigned(item.data());
+            let is_array = flags & 0b10 == 0;
+            let is_padding = flags & 0b01 != 0 && usages.is_empty();
+            let offset = offsets.entry((globals.report_id, report_type)).or_insert(0);
+            for index in 0..globals.report_count {
+                if fields.len() == MAX_FIELDS {
+                    return fields;
+                }
+                let usage_index = index.min(usages.len().saturating_sub(1));
+                let field = match is_padding {
+                    true => FieldInfo {
+                        collection_path: collection_path.clone(),
+                        ..FieldInfo::padding(
+                            globals.report_id,
+                            report_type,
+                            *offset,
+                            globals.report_size,
+                        )
+                    },
+                    false => FieldInfo {
+                        report_id: globals.report_id,
+                        report_type,
+                        bit_offset: *offset,
+                        bit_width: globals.report_size,
+                        usages: match is_array {
+                            true => usages.clone(),
+                            false => usages.get(usage_index).copied().into_iter().collect(),
+                        },
+                        alternatives: match is_array {
+                            true => alternatives.clone(),
+                            false => alternatives.get(usage_index).cloned().into_iter().collect(),
+                        },
+                        is_array,
+                        logical_minimum: globals.logical_minimum,
+                        logical_maximum: globals.logical_maximum,
+                        unit: globals.unit.clone(),
+                        unit_exponent: globals.unit_exponent.clone(),
+                        is_padding: false,
+                        collection_path: collection_path.clone(),
+                    },
+                };
+                let entries = field.usages.len()
+                    + field
+                        .alternatives
+                        .iter()
+                        .map(|set| set.len() + 1)
+                        .sum::<usize>()
+                    + field.collection_path.len();
+                budget = match budget.checked_sub(entries) {
+                    Some(budget) => budget,
+                    None => return fields,
+                };
+                fields.push(field);
+                *offset += globals.report_size;
+            }
+        }
+        match item {
+            ReportItem::Collection(_) => collection_path.push(usages.last().copied().unwrap_or(0)),
+            ReportItem::EndCollection(_) => {
+                collection_path.pop();
+            }
+            _ => (),
+        }
+        match item {
+            ReportItem::Input(_)
+            | ReportItem::Output(_)
+            | ReportItem::Feature(_)
+            | ReportItem::Collection(_)
+            | ReportItem::EndCollection(_) => {
+                usages.clear();
+                alternatives.clear();
+                delimited = None;
+                usage_minimum = None;
+            }
+            ReportItem::Delimiter(inner) => {
+                delimited = (__data_to_unsigned(inner.data()) != 0).then_some(false)
+            }
+            ReportItem::Usage(inner) => {
+                let usage = __extended_usage(inner.data(), &state);
+                match (delimited, alternatives.last_mut()) {
+                    (Some(true), Some(last)) => last.push(usage),
+                    _ => {
+                        usages.push(usage);
+                        alternatives.push(Vec::new());
+                        delimited = delimited.map(|_| true);
+                    }
+                }
+            }
+            ReportItem::UsageMinimum(inner) => {
+                usage_minimum = Some(__extended_usage(inner.data(), &state))
+            }
+            ReportItem::UsageMaximum(inner) => {
+                if let Some(minimum) = usage_minimum.take() {
+                    let maximum = __extended_usage(inner.data(), &state).min(minimum | 0xFFFF);
+                    let room = MAX_USAGES.saturating_sub(usages.len());
+                    usages.extend((minimum..=maximum).take(room));
+                    alternatives.resize(usages.len(), Vec::new());
+                }
+            }
+            _ => state.update(item),
+        }
+    }
+    fields
+}
+
+/// Find the field occupying `bit` of a report, e.g. to explain a byte of a
+/// captured report.
+///
+/// `bit` counts from the start of the report as transmitted, so if `report_id`
+/// is not 0 the first 8 bits are the report ID byte, which belongs to no field.
+/// Use 0 for descriptors without [Report ID](crate::ReportId).
+///
+/// Input, Output and Feature reports may share a report ID, hence `report_type`.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{field_at_bit, parse, ReportType};
+///
+/// let bytes = [
+///     0x85, 0x01, 0x05, 0x09, 0x19, 0x01, 0x29, 0x03, 0x75, 0x01, 0x95, 0x03,
+///     0x81, 0x02, 0x75, 0x05, 0x95, 0x01, 0x81, 0x01, 0x05, 0x01, 0x09, 0x30,
+///     0x75, 0x08, 0x81, 0x06,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// assert_eq!(field_at_bit(&items, 1, ReportType::Input, 3), None);
+/// let button = field_at_bit(&items, 1, ReportType::Input, 9).unwrap();
+/// assert_eq!(button.usages, [0x0009_0002]);
+/// assert!(field_at_bit(&items, 1, ReportType::Input, 12).unwrap().is_padding);
+/// let x = field_at_bit(&items, 1, ReportType::Input, 20).unwrap();
+/// assert_eq!(x.usages, [0x0001_0030]);
+/// assert_eq!(field_at_bit(&items, 1, ReportType::Input, 24), None);
+/// assert_eq!(field_at_bit(&items, 1, ReportType::Output, 9), None);
+/// ```
+pub fn field_at_bit(
+    items: &[ReportItem],
+    report_id: u8,
+    report_type: ReportType,
+    bit: usize,
+) -> Option<FieldInfo> {
+    let (report_id, bit) = match report_id {
+        0 => (None, bit),
+        report_id => (Some(report_id as u32), bit.checked_sub(8)?),
+    };
+    field_map(items).into_iter().find(|field| {
+        field.report_id == report_id
+            && field.report_type == report_type
+            && (field.bit_offset..field.bit_offset + field.bit_width).contains(&bit)
+    })
+}
+
+/// Find the modifier byte of a keyboard, i.e. the 8 variable fields for Left Control
+/// through Right GUI (usages `0xE0-0xE7` on the Keyboard/Keypad page), usually
+/// declared with a [Usage Minimum](crate::UsageMinimum) /
+/// [Usage Maximum](crate::UsageMaximum) pair.
+///
+/// The fields are merged into one [FieldInfo] covering all modifier bits, whose
+/// usages are the modifiers in bit order. Returns `None` if no such fields exist.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{keyboard_modifiers, parse};
+///
+/// let bytes = [
+///     0x05, 0x01, 0x09, 0x06, 0xA1, 0x01, 0x85, 0x01, 0x05, 0x07, 0x19, 0xE0,
+///     0x29, 0xE7, 0x15, 0x00, 0x25, 0x01, 0x75, 0x01, 0x95, 0x08, 0x81, 0x02,
+///     0x95, 0x01, 0x75, 0x08, 0x81, 0x01, 0xC0,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// let modifiers = keyboard_modifiers(&items).unwrap();
+/// assert_eq!(modifiers.report_id, Some(1));
+/// assert_eq!(modifiers.bit_offset, 0);
+/// assert_eq!(modifiers.bit_width, 8);
+/// assert_eq!(modifiers.usages[0], 0x0007_00E0);
+///
+/// let items = parse([0x05, 0x07, 0x09, 0xE0, 0x75, 0x01, 0x95, 0x01, 0x81, 0x02]);
+/// assert_eq!(keyboard_modifiers(&items.collect::<Vec<_>>()), None);
+/// ```
+pub fn keyboard_modifiers(items: &[ReportItem]) -> Option<FieldInfo> {
+    const LEFT_CONTROL: u32 = 0x0007_00E0;
+    let fields = field_map(items);
+    fields.windows(8).find_map(|window| {
+        let first = &window[0];
+        let is_modifiers = window.iter().enumerate().all(|(index, field)| {
+            !field.is_array
+                && field.bit_width == 1
+                && field.report_id == first.report_id
+                && field.report_type == first.report_type
+                && field.bit_offset == first.bit_offset + index
+                && field.usages == [LEFT_CONTROL + index as u32]
+        });
+        is_modifiers.then(|| FieldInfo {
+            bit_width: 8,
+            usages: (LEFT_CONTROL..LEFT_CONTROL + 8).collect(),
+            alternatives: (0..8).map(|_| Vec::new()).collect(),
+            ..first.clone()
+        })
+    })
+}
+
+/// Check whether the first input report matches the boot protocol keyboard report
+/// (HID 1.11, appendix B.1): a modifier byte, a reserved byte and 6 key codes on the
+/// Keyboard/Keypad page, which a host may read without parsing the descriptor.
+///
+/// Only the first 8 bytes of the report are checked, and the
+/// [Report ID](crate::ReportId) is ignored since boot reports don't carry one.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{is_boot_keyboard, is_boot_mouse, parse};
+///
+/// let bytes = [
+///     0x05, 0x01, 0x09, 0x06, 0xA1, 0x01, 0x05, 0x07, 0x19, 0xE0, 0x29, 0xE7,
+///     0x15, 0x00, 0x25, 0x01, 0x75, 0x01, 0x95, 0x08, 0x81, 0x02, 0x95, 0x01,
+///     0x75, 0x08, 0x81, 0x01, 0x95, 0x06, 0x75, 0x08, 0x15, 0x00, 0x25, 0x65,
+///     0x19, 0x00, 0x29, 0x65, 0x81, 0x00, 0xC0,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// assert!(is_boot_keyboard(&items));
+/// assert!(!is_boot_mouse(&items));
+///
+/// // 5 key codes only.
+/// let mut bytes = bytes;
+/// bytes[29] = 0x05;
+/// assert!(!is_boot_keyboard(&parse(bytes).collect::<Vec<_>>()));
+/// ```
+pub fn is_boot_keyboard(items: &[ReportItem]) -> bool {
+    const LEFT_CONTROL: u32 = 0x0007_00E0;
+    let fields = __first_input_report(items);
+    let mut bit = 0;
+    for field in fields {
+        let matches = match bit {
+            0..=7 => {
+                field.bit_width == 1
+                    && !field.is_array
+                    && field.usages == [LEFT_CONTROL + bit as u32]
+            }
+            8..=15 => field.usages.is_empty() && field.bit_offset + field.bit_width <= 16,
+            16..=63 => {
+                field.bit_width == 8
+                    && field.is_array
+                    && !field.usages.is_empty()
+                    && field.usages.iter().all(|usage| usage >> 16 == 0x07)
+            }
+            _ => return true,
+        };
+        if !matches || field.bit_offset != bit {
+            return false;
+        }
+        bit += field.bit_width;
+    }
+    bit >= 64
+}
+
+/// Check whether the first input report matches the boot protocol mouse report
+/// (HID 1.11, appendix B.2): 3 buttons, device specific bits to a byte, e.g. more
+/// buttons or padding, then 8-bit X and Y.
+///
+/// Only the first 3 bytes of the report are checked, since a host in boot protocol
+/// ignores the rest, and the [Report ID](crate::ReportId) is ignored since boot
+/// reports don't carry one.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{is_boot_keyboard, is_boot_mouse, parse};
+///
+/// let bytes = [
+///     0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x09, 0x01, 0xA1, 0x00, 0x05, 0x09,
+///     0x19, 0x01, 0x29, 0x03, 0x15, 0x00, 0x25, 0x01, 0x95, 0x03, 0x75, 0x01,
+///     0x81, 0x02, 0x95, 0x01, 0x75, 0x05, 0x81, 0x01, 0x05, 0x01, 0x09, 0x30,
+///     0x09, 0x31, 0x09, 0x38, 0x15, 0x81, 0x25, 0x7F, 0x75, 0x08, 0x95, 0x03,
+///     0x81, 0x06, 0xC0, 0xC0,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// assert!(is_boot_mouse(&items));
+/// assert!(!is_boot_keyboard(&items));
+///
+/// // 5 buttons and 3 bits of padding.
+/// let mut bytes = bytes;
+/// (bytes[15], bytes[21], bytes[29]) = (0x05, 0x05, 0x03);
+/// assert!(is_boot_mouse(&parse(bytes).collect::<Vec<_>>()));
+/// ```
+pub fn is_boot_mouse(items: &[ReportItem]) -> bool {
+    let fields = __first_input_report(items);
+    let mut bit = 0;
+    for field in fields {
+        let matches = match bit {
+            0..=2 => {
+                field.bit_width == 1
+                    && !field.is_array
+                    && field.usages == [0x0009_0001 + bit as u32]
+            }
+            // Device specific, e.g. more buttons or padding.
+            3..=7 => field.bit_offset + field.bit_width <= 8,
+            8 => field.bit_width == 8 && !field.is_array && field.usages == [0x0001_0030],
+            16 => field.bit_width == 8 && !field.is_array && field.usages == [0x0001_0031],
+            _ => return true,
+        };
+        if !matches || field.bit_offset != bit {
+            return false;
+        }
+        bit += field.bit_width;
+    }
+    bit >= 24
+}
+
+/// Check whether two descriptors declare the same reports, however they are encoded.
+///
+/// The [field maps](field_map()) of both descriptors are compared, sorted by report
+/// ID, report type and bit offset, so "semantic" covers exactly what a [FieldInfo]
+/// records:
+///
+/// * the layout of each report, i.e. the offset and width of every field;
+/// * usages, including [alternatives](FieldInfo::alternatives), and whether a field
+///   is an array or padding;
+/// * logical ranges;
+/// * units and unit exponents, where 0 is the same as none;
+/// * usages of the enclosing collections.
+///
+/// Data sizes, redundant global items, the order of local items and reports, and
+/// anything a [FieldInfo] doesn't record, e.g. physical ranges, collection types or
+/// main item flags besides Constant and Variable, are ignored.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, semantically_equal};
+///
+/// // Logical Maximum (255) in 2 bytes, then in 4 bytes with a redundant Report Size.
+/// let a = parse([0x26, 0xFF, 0x00, 0x75, 0x08, 0x95, 0x01, 0x81, 0x02]).collect::<Vec<_>>();
+/// let b = parse([
+///     0x27, 0xFF, 0x00, 0x00, 0x00, 0x75, 0x08, 0x75, 0x08, 0x95, 0x01, 0x81, 0x02,
+/// ])
+/// .collect::<Vec<_>>();
+/// assert!(semantically_equal(&a, &b));
+///
+/// // Logical Maximum (127) changes the range.
+/// let c = parse([0x25, 0x7F, 0x75, 0x08, 0x95, 0x01, 0x81, 0x02]).collect::<Vec<_>>();
+/// assert!(!semantically_equal(&a, &c));
+///
+/// // Unit Exponent (-2) as a nibble and as a full signed byte, but not none.
+/// let d = parse([0x55, 0x0E, 0x26, 0xFF, 0x00, 0x75, 0x08, 0x95, 0x01, 0x81, 0x02]);
+/// let e = parse([0x55, 0xFE, 0x26, 0xFF, 0x00, 0x75, 0x08, 0x95, 0x01, 0x81, 0x02]);
+/// let (d, e) = (d.collect::<Vec<_>>(), e.collect::<Vec<_>>());
+/// assert!(semantically_equal(&d, &e));
+/// assert!(!semantically_equal(&a, &e));
+///
+/// // Unit Exponent (16) is out of the 4-bit range, which doesn't make it none.
+/// let f = parse([0x55, 0x10, 0x26, 0xFF, 0x00, 0x75, 0x08, 0x95, 0x01, 0x81, 0x02]);
+/// assert!(!semantically_equal(&a, &f.collect::<Vec<_>>()));
+/// ```
+pub fn semantically_equal(a: &[ReportItem], b: &[ReportItem]) -> bool {
+    __semantic_fields(a) == __semantic_fields(b)
+}
+
+fn __semantic_fields(items: &[ReportItem]) -> Vec<FieldInfo> {
+    let mut fields = field_map(items);
+    for field in &mut fields {
+        field.unit = match field.unit.as_ref().map(Unit::value) {
+            None | Some(0) => None,
+            Some(value) => Some(Unit::from_value(value)),
+        };
+        // Exponents out of the 4-bit range are compared by their signed value.
+        let exponent = field.unit_exponent.as_ref().map_or(0, |item| {
+            item.exponent()
+                .unwrap_or_else(|| __data_to_signed(item.data()))
+        });
+        field.unit_exponent = match exponent {
+            0 => None,
+            -8..=7 => UnitExponent::new_with(&[exponent as u8 & 0x0F]).ok(),
+            _ => {
+                let (data, size) = __signed_to_data(exponent);
+                UnitExponent::new_with(&data[..size]).ok()
+            }
+        };
+    }
+    fields.sort_by_key(|field| (field.report_id, field.report_type, field.bit_offset));
+    fields
+}
+
+fn __first_input_report(items: &[ReportItem]) -> Vec<FieldInfo> {
+    let mut fields = field_map(items)
+        .into_iter()
+        .filter(|field| field.report_type == ReportType::Input)
+        .peekable();
+    let report_id = match fields.peek() {
+        Some(field) => field.report_id,
+        None => return Vec::new(),
+    };
+    fields
+        .filter(|field| field.report_id == report_id)
+        .collect()
+}
@@ -1,5 +1,4 @@
-use crate::{__data_to_signed, __data_to_unsigned, macros::*};
-use alloc::vec::Vec;
+use crate::{__alternate_hex, __data_to_signed, __data_to_unsigned, macros::*};
 use std::fmt::Display;
 
 __impls_for_short_items! {
@@ -28,28 +27,93 @@ __impls_for_short_items! {
     /// * 0x0A: Ordinal
     /// * 0x0B: Telephony Device
     /// * 0x0C: Consumer
-    /// * 0x0D: Digitizer
+    /// * 0x0D: Digitizers
     /// * 0x0E: Haptics
-    /// * 0x0F: PID
+    /// * 0x0F: Physical Input Device
     /// * 0x10: Unicode
+    /// * 0x11: SoC
     /// * 0x12: Eye and Head Trackers
     /// * 0x14: Auxiliary Display
     /// * 0x20: Sensors
     /// * 0x40: Medical Instrument
     /// * 0x41: Braille Display
     /// * 0x59: Lighting And Illumination
-    /// * 0x80-0x83: Monitor
-    /// * 0x84-0x87: Power
-    /// * 0x8C: Bar Code Scanner
-    /// * 0x8D: Scale
-    /// * 0x8E: Magnetic Stripe Reading
+    /// * 0x80: Monitor
+    /// * 0x81: Monitor Enumerated
+    /// * 0x82: VESA Virtual Controls
+    /// * 0x83: Monitor
+    /// * 0x84: Power
+    /// * 0x85: Battery System
+    /// * 0x86-0x87: Power
+    /// * 0x8C: Barcode Scanner
+    /// * 0x8D: Scales
+    /// * 0x8E: Magnetic Stripe Reader
     /// * 0x8F: Reserved Point of Sale
     /// * 0x90: Camera Control
     /// * 0x91: Arcade
     /// * 0x92: Gaming Device
     /// * 0xF1D0: FIDO Alliance
-    /// * 0xFF00-x0FFFF: Vendor Defined
+    /// * 0xFF00-0xFFFF: Vendor Defined
     /// * Other: Reserved
+    ///
+    /// # Example
+    ///
+    /// Every page listed above is named as documented, following the
+    /// [HID Usage Tables](https://usb.org/sites/default/files/hut1_5.pdf):
+    ///
+    /// ```
+    /// use hid_report::UsagePage;
+    ///
+    /// let pages = [
+    ///     (0x00, "Undefined"),
+    ///     (0x01, "Generic Desktop"),
+    ///     (0x02, "Simulation Controls"),
+    ///     (0x03, "VR Controls"),
+    ///     (0x04, "Sport Controls"),
+    ///     (0x05, "Game Controls"),
+    ///     (0x06, "Generic Device Controls"),
+    ///     (0x07, "Keyboard/Keypad"),
+    ///     (0x08, "LED"),
+    ///     (0x09, "Button"),
+    ///     (0x0A, "Ordinal"),
+    ///     (0x0B, "Telephony Device"),
+    ///     (0x0C, "Consumer"),
+    ///     (0x0D, "Digitizers"),
+    ///     (0x0E, "Haptics"),
+    ///     (0x0F, "Physical Input Device"),
+    ///     (0x10, "Unicode"),
+    ///     (0x11, "SoC"),
+    ///     (0x12, "Eye and Head Trackers"),
+    ///     (0x14, "Auxiliary Display"),
+    ///     (0x20, "Sensors"),
+    ///     (0x40, "Medical Instrument"),
+    ///     (0x41, "Braille Display"),
+    ///     (0x59, "Lighting And Illumination"),
+    ///     (0x80, "Monitor"),
+    ///     (0x81, "Monitor Enumerated"),
+    ///     (0x82, "VESA Virtual Controls"),
+    ///     (0x83, "Monitor"),
+    ///     (0x84, "Power"),
+    ///     (0x85, "Battery System"),
+    ///     (0x86, "Power"),
+    ///     (0x87, "Power"),
+    ///     (0x8C, "Barcode Scanner"),
+    ///     (0x8D, "Scales"),
+    ///     (0x8E, "Magnetic Stripe Reader"),
+    ///     (0x8F, "Reserved Point of Sale"),
+    ///     (0x90, "Camera Control"),
+    ///     (0x91, "Arcade"),
+    ///     (0x92, "Gaming Device"),
+    ///     (0xF1D0, "FIDO Alliance"),
+    ///     (0xFF00, "Vendor Defined"),
+    ///     (0xFFFF, "Vendor Defined"),
+    /// ];
+    /// for (value, name) in pages {
+    ///     let usage_page = UsagePage::from_value(value);
+    ///     assert_eq!(usage_page.to_string(), format!("Usage Page ({})", name));
+    /// }
+    /// assert_eq!(UsagePage::from_value(0x13).to_string(), "Usage Page (Reserved)");
+    /// ```
     UsagePage: 0b0000_0100;
     /// Extent value in logical units. This is the
     /// minimum value that a variable or array item will
@@ -68,6 +132,24 @@ __impls_for_short_items! {
     /// position values from 0 to 128 would have a
     /// [Logical Minimum](LogicalMinimum) of 0 and a Logical Maximum
     /// of 128.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::{LogicalMaximum, ReportCount};
+    ///
+    /// let mut item = LogicalMaximum::from_value(127);
+    /// item.set_value_signed(128);
+    /// assert_eq!(item.as_ref(), [0x26, 0x80, 0x00]);
+    /// assert_eq!(item.value(), 128);
+    /// item.set_value_signed(-1);
+    /// assert_eq!(item.as_ref(), [0x25, 0xFF]);
+    ///
+    /// let mut item = ReportCount::from_value(1);
+    /// item.set_value_unsigned(0x1_0000);
+    /// assert_eq!(item.as_ref(), [0x97, 0x00, 0x00, 0x01, 0x00]);
+    /// assert_eq!(item.value(), 0x1_0000);
+    /// ```
     LogicalMaximum: 0b0010_0100;
     /// Minimum value for the physical extent of a variable item.
     /// This represents the [Logical Minimum](LogicalMinimum)
@@ -160,63 +242,324 @@ __impls_for_short_items! {
     Pop: 0b1011_0100;
 }
 
+__impls_for_numeric_items! {
+    signed: LogicalMinimum, LogicalMaximum, PhysicalMinimum, PhysicalMaximum
+}
+
+__impls_for_numeric_items! {
+    unsigned: UsagePage, Unit, ReportSize, ReportCount
+}
+
+impl ReportId {
+    /// Create an item with the minimal data size that can represent
+    /// the unsigned `value`.
+    ///
+    /// *NOTE*: `0` is encoded in 1 byte rather than 0 bytes.
+    pub fn from_value(value: u32) -> Self {
+        let (data, size) = crate::__unsigned_to_data(value);
+        Self::new_with(&data[..size]).unwrap()
+    }
+
+    /// Get the report ID, i.e. the first data byte, 0 if there is no data.
+    ///
+    /// Reports are prefixed with a single byte, so further data bytes are not part
+    /// of the ID reports carry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::ReportId;
+    ///
+    /// assert_eq!(ReportId::from_value(2).value(), 2);
+    /// assert_eq!(ReportId::new_with(&[0x02, 0x01]).unwrap().value(), 2);
+    ///
+    /// // Formatting labels wider encodings.
+    /// let report_id = ReportId::new_with(&[0x02, 0x01]).unwrap();
+    /// assert_eq!(report_id.to_string(), "Report ID (2, 2 bytes)");
+    /// assert_eq!(format!("{:#}", report_id), "Report ID (2 [0x0102], 2 bytes)");
+    /// ```
+    pub fn value(&self) -> u32 {
+        self.data().first().copied().unwrap_or(0) as u32
+    }
+
+    /// Set data to the minimal data size that can represent the unsigned
+    /// `value`, like [`from_value()`](Self::from_value()) does.
+    pub fn set_value_unsigned(&mut self, value: u32) -> &mut Self {
+        let (data, size) = crate::__unsigned_to_data(value);
+        self.set_data(&data[..size]).unwrap()
+    }
+}
+
+/// Usage pages named by the [HID Usage Tables](https://usb.org/sites/default/files/hut1_5.pdf),
+/// see [UsagePage].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KnownUsagePage {
+    /// Undefined (`0x00`).
+    Undefined = 0x00,
+    /// Generic Desktop (`0x01`).
+    GenericDesktop = 0x01,
+    /// Simulation Controls (`0x02`).
+    SimulationControls = 0x02,
+    /// VR Controls (`0x03`).
+    VrControls = 0x03,
+    /// Sport Controls (`0x04`).
+    SportControls = 0x04,
+    /// Game Controls (`0x05`).
+    GameControls = 0x05,
+    /// Generic Device Controls (`0x06`).
+    GenericDeviceControls = 0x06,
+    /// Keyboard/Keypad (`0x07`).
+    Keyboard = 0x07,
+    /// LED (`0x08`).
+    Led = 0x08,
+    /// Button (`0x09`).
+    Button = 0x09,
+    /// Ordinal (`0x0A`).
+    Ordinal = 0x0A,
+    /// Telephony Device (`0x0B`).
+    TelephonyDevice = 0x0B,
+    /// Consumer (`0x0C`).
+    Consumer = 0x0C,
+    /// Digitizers (`0x0D`).
+    Digitizers = 0x0D,
+    /// Haptics (`0x0E`).
+    Haptics = 0x0E,
+    /// Physical Input Device (`0x0F`).
+    PhysicalInputDevice = 0x0F,
+    /// Unicode (`0x10`).
+    Unicode = 0x10,
+    /// SoC (`0x11`).
+    Soc = 0x11,
+    /// Eye and Head Trackers (`0x12`).
+    EyeAndHeadTrackers = 0x12,
+    /// Auxiliary Display (`0x14`).
+    AuxiliaryDisplay = 0x14,
+    /// Sensors (`0x20`).
+    Sensors = 0x20,
+    /// Medical Instrument (`0x40`).
+    MedicalInstrument = 0x40,
+    /// Braille Display (`0x41`).
+    BrailleDisplay = 0x41,
+    /// Lighting And Illumination (`0x59`).
+    LightingAndIllumination = 0x59,
+    /// Monitor (`0x80`).
+    Monitor = 0x80,
+    /// Monitor Enumerated (`0x81`).
+    MonitorEnumerated = 0x81,
+    /// VESA Virtual Controls (`0x82`).
+    VesaVirtualControls = 0x82,
+    /// Power (`0x84`).
+    Power = 0x84,
+    /// Battery System (`0x85`).
+    BatterySystem = 0x85,
+    /// Barcode Scanner (`0x8C`).
+    BarcodeScanner = 0x8C,
+    /// Scales (`0x8D`).
+    Scales = 0x8D,
+    /// Magnetic Stripe Reader (`0x8E`).
+    MagneticStripeReader = 0x8E,
+    /// Camera Control (`0x90`).
+    CameraControl = 0x90,
+    /// Arcade (`0x91`).
+    Arcade = 0x91,
+    /// Gaming Device (`0x92`).
+    GamingDevice = 0x92,
+    /// FIDO Alliance (`0xF1D0`).
+    FidoAlliance = 0xF1D0,
+}
+
+impl KnownUsagePage {
+    /// Get the value of the usage page.
+    pub fn value(self) -> u32 {
+        self as u32
+    }
+
+    /// Get the usage page of `value`, `None` if it's not a named page.
+    pub fn from_value(value: u32) -> Option<Self> {
+        Some(match value {
+            0x00 => KnownUsagePage::Undefined,
+            0x01 => KnownUsagePage::GenericDesktop,
+            0x02 => KnownUsagePage::SimulationControls,
+            0x03 => KnownUsagePage::VrControls,
+            0x04 => KnownUsagePage::SportControls,
+            0x05 => KnownUsagePage::GameControls,
+            0x06 => KnownUsagePage::GenericDeviceControls,
+            0x07 => KnownUsagePage::Keyboard,
+            0x08 => KnownUsagePage::Led,
+            0x09 => KnownUsagePage::Button,
+            0x0A => KnownUsagePage::Ordinal,
+            0x0B => KnownUsagePage::TelephonyDevice,
+            0x0C => KnownUsagePage::Consumer,
+            0x0D => KnownUsagePage::Digitizers,
+            0x0E => KnownUsagePage::Haptics,
+            0x0F => KnownUsagePage::PhysicalInputDevice,
+            0x10 => KnownUsagePage::Unicode,
+            0x11 => KnownUsagePage::Soc,
+            0x12 => KnownUsagePage::EyeAndHeadTrackers,
+            0x14 => KnownUsagePage::AuxiliaryDisplay,
+            0x20 => KnownUsagePage::Sensors,
+            0x40 => KnownUsagePage::MedicalInstrument,
+            0x41 => KnownUsagePage::BrailleDisplay,
+            0x59 => KnownUsagePage::LightingAndIllumination,
+            0x80 => KnownUsagePage::Monitor,
+            0x81 => KnownUsagePage::MonitorEnumerated,
+            0x82 => KnownUsagePage::VesaVirtualControls,
+            0x84 => KnownUsagePage::Power,
+            0x85 => KnownUsagePage::BatterySystem,
+            0x8C => KnownUsagePage::BarcodeScanner,
+            0x8D => KnownUsagePage::Scales,
+            0x8E => KnownUsagePage::MagneticStripeReader,
+            0x90 => KnownUsagePage::CameraControl,
+            0x91 => KnownUsagePage::Arcade,
+            0x92 => KnownUsagePage::GamingDevice,
+            0xF1D0 => KnownUsagePage::FidoAlliance,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for KnownUsagePage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", __usage_page_name(self.value()))
+    }
+}
+
+impl UsagePage {
+    /// Create an item declaring a known usage page, with the minimal data size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::{KnownUsagePage, UsagePage};
+    ///
+    /// let usage_page = UsagePage::from_page(KnownUsagePage::Consumer);
+    /// assert_eq!(usage_page.as_ref(), [0x05, 0x0C]);
+    /// assert_eq!(usage_page.known_page(), Some(KnownUsagePage::Consumer));
+    ///
+    /// let usage_page = UsagePage::from_page(KnownUsagePage::FidoAlliance);
+    /// assert_eq!(usage_page.as_ref(), [0x06, 0xD0, 0xF1]);
+    /// assert_eq!(usage_page.to_string(), "Usage Page (FIDO Alliance)");
+    ///
+    /// assert_eq!(UsagePage::from_value(0xFF00).known_page(), None);
+    /// ```
+    pub fn from_page(page: KnownUsagePage) -> Self {
+        Self::from_value(page.value())
+    }
+
+    /// Get the usage page as a [KnownUsagePage], `None` if it's not a named page.
+    pub fn known_page(&self) -> Option<KnownUsagePage> {
+        KnownUsagePage::from_value(__data_to_unsigned(self.data()))
+    }
+
+    /// Check whether the usage page is vendor defined, i.e. `0xFF00-0xFFFF`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::UsagePage;
+    ///
+    /// assert!(UsagePage::from_value(0xFF00).is_vendor_defined());
+    /// assert!(UsagePage::from_value(0xFFFF).is_vendor_defined());
+    /// assert!(!UsagePage::from_value(0xFEFF).is_vendor_defined());
+    /// assert!(!UsagePage::from_value(0x01).is_vendor_defined());
+    /// ```
+    pub fn is_vendor_defined(&self) -> bool {
+        (0xFF00..=0xFFFF).contains(&__data_to_unsigned(self.data()))
+    }
+
+    /// Check whether the usage page is reserved, i.e. neither defined by the
+    /// [HID Usage Tables](https://usb.org/sites/default/files/hut1_5.pdf) nor vendor
+    /// defined, so there's no name to resolve.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::UsagePage;
+    ///
+    /// assert!(UsagePage::from_value(0x13).is_reserved());
+    /// assert!(UsagePage::from_value(0x8F).is_reserved());
+    /// assert!(UsagePage::from_value(0x1_0000).is_reserved());
+    /// assert!(!UsagePage::from_value(0x01).is_reserved());
+    /// assert!(!UsagePage::from_value(0x86).is_reserved());
+    /// assert!(!UsagePage::from_value(0xFF00).is_reserved());
+    /// ```
+    pub fn is_reserved(&self) -> bool {
+        let value = __data_to_unsigned(self.data());
+        // Monitor and Power pages beyond the named ones aren't reserved.
+        self.known_page().is_none()
+            && !self.is_vendor_defined()
+            && !matches!(value, 0x83 | 0x86 | 0x87)
+    }
+}
+
 impl Display for UsagePage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.data().len() {
             0 => write!(f, "Usage Page"),
             1.. => write!(
                 f,
-                "Usage Page ({})",
-                match __data_to_unsigned(self.data()) {
-                    0x00 => "Undefined",
-                    0x01 => "Generic Desktop",
-                    0x02 => "Simulation Controls",
-                    0x03 => "VR Controls",
-                    0x04 => "Sport Controls",
-                    0x05 => "Game Controls",
-                    0x06 => "Generic Device Controls",
-                    0x07 => "Keyboard/Keypad",
-                    0x08 => "LED",
-                    0x09 => "Button",
-                    0x0A => "Ordinal",
-                    0x0B => "Telephony Device",
-                    0x0C => "Consumer",
-                    0x0D => "Digitizers",
-                    0x0E => "Haptics",
-                    0x0F => "Physical Input Device",
-                    0x10 => "Unicode",
-                    0x11 => "SoC",
-                    0x12 => "Eye and Head Trackers",
-                    0x14 => "Auxiliary Display",
-                    0x20 => "Sensors",
-                    0x40 => "Medical Instrument",
-                    0x41 => "Braille Display",
-                    0x59 => "Lighting And Illumination",
-                    0x80 => "Monitor",
-                    0x81 => "Monitor Enumerated",
-                    0x82 => "VESA Virtual Controls",
-                    0x84 => "Power",
-                    0x85 => "Battery System",
-                    0x8C => "Bar Code Scanner",
-                    0x8D => "Scale",
-                    0x8E => "Magnetic Stripe Reading",
-                    0x90 => "Camera Control",
-                    0x91 => "Arcade",
-                    0x92 => "Gaming Device",
-                    0xF1D0 => "FIDO Alliance",
-                    0xFF00..=0xFFFF => "Vendor Defined",
-                    _ => "Reserved",
-                }
+                "Usage Page ({}{})",
+                __usage_page_name(__data_to_unsigned(self.data())),
+                __alternate_hex(f.alternate(), self.data())
             ),
         }
     }
 }
 
+pub(crate) fn __usage_page_name(usage_page: u32) -> &'static str {
+    match usage_page {
+        0x00 => "Undefined",
+        0x01 => "Generic Desktop",
+        0x02 => "Simulation Controls",
+        0x03 => "VR Controls",
+        0x04 => "Sport Controls",
+        0x05 => "Game Controls",
+        0x06 => "Generic Device Controls",
+        0x07 => "Keyboard/Keypad",
+        0x08 => "LED",
+        0x09 => "Button",
+        0x0A => "Ordinal",
+        0x0B => "Telephony Device",
+        0x0C => "Consumer",
+        0x0D => "Digitizers",
+        0x0E => "Haptics",
+        0x0F => "Physical Input Device",
+        0x10 => "Unicode",
+        0x11 => "SoC",
+        0x12 => "Eye and Head Trackers",
+        0x14 => "Auxiliary Display",
+        0x20 => "Sensors",
+        0x40 => "Medical Instrument",
+        0x41 => "Braille Display",
+        0x59 => "Lighting And Illumination",
+        0x80 | 0x83 => "Monitor",
+        0x81 => "Monitor Enumerated",
+        0x82 => "VESA Virtual Controls",
+        0x84 | 0x86 | 0x87 => "Power",
+        0x85 => "Battery System",
+        0x8C => "Barcode Scanner",
+        0x8D => "Scales",
+        0x8E => "Magnetic Stripe Reader",
+        0x8F => "Reserved Point of Sale",
+        0x90 => "Camera Control",
+        0x91 => "Arcade",
+        0x92 => "Gaming Device",
+        0xF1D0 => "FIDO Alliance",
+        0xFF00..=0xFFFF => "Vendor Defined",
+        _ => "Reserved",
+    }
+}
+
 impl Display for LogicalMinimum {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.data().len() {
             0 => write!(f, "Logical Minimum"),
-            1.. => write!(f, "Logical Minimum ({})", __data_to_signed(self.data())),
+            1.. => write!(
+                f,
+                "Logical Minimum ({}{})",
+                __data_to_signed(self.data()),
+                __alternate_hex(f.alternate(), self.data())
+            ),
         }
     }
 }
@@ -225,7 +568,12 @@ impl Display for LogicalMaximum {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.data().len() {
             0 => write!(f, "Logical Maximum"),
-            1.. => write!(f, "Logical Maximum ({})", __data_to_signed(self.data())),
+            1.. => write!(
+                f,
+                "Logical Maximum ({}{})",
+                __data_to_signed(self.data()),
+                __alternate_hex(f.alternate(), self.data())
+            ),
         }
     }
 }
@@ -234,7 +582,12 @@ impl Display for PhysicalMinimum {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.data().len() {
             0 => write!(f, "Physical Minimum"),
-            1.. => write!(f, "Physical Minimum ({})", __data_to_signed(self.data())),
+            1.. => write!(
+                f,
+                "Physical Minimum ({}{})",
+                __data_to_signed(self.data()),
+                __alternate_hex(f.alternate(), self.data())
+            ),
         }
     }
 }
@@ -243,105 +596,216 @@ impl Display for PhysicalMaximum {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.data().len() {
             0 => write!(f, "Physical Maximum"),
-            1.. => write!(f, "Physical Maximum ({})", __data_to_signed(self.data())),
+            1.. => write!(
+                f,
+                "Physical Maximum ({}{})",
+                __data_to_signed(self.data()),
+                __alternate_hex(f.alternate(), self.data())
+            ),
         }
     }
 }
 
-impl Display for UnitExponent {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl UnitExponent {
+    /// Get the base 10 exponent, `None` if there is no data or it's out of the
+    /// 4-bit range.
+    ///
+    /// The exponent is a 4-bit two's complement nibble, e.g. `0x0E` for -2, but
+    /// negative exponents encoded as a full signed value, e.g. `0xFE`, are accepted
+    /// too, like the Linux HID core does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::UnitExponent;
+    ///
+    /// assert_eq!(UnitExponent::new_with(&[0x0E]).unwrap().exponent(), Some(-2));
+    /// assert_eq!(UnitExponent::new_with(&[0xFE]).unwrap().exponent(), Some(-2));
+    /// assert_eq!(UnitExponent::new_with(&[0x07]).unwrap().exponent(), Some(7));
+    /// assert_eq!(UnitExponent::new_with(&[0x10]).unwrap().exponent(), None);
+    /// assert_eq!(UnitExponent::new_with(&[]).unwrap().exponent(), None);
+    /// ```
+    pub fn exponent(&self) -> Option<i32> {
         match self.data().len() {
-            0 => write!(f, "Unit Exponent"),
+            0 => None,
             1.. => match __data_to_signed(self.data()) {
-                exp @ 0..=7 => write!(f, "Unit Exponent {exp}"),
-                exp @ 8..=15 => write!(f, "Unit Exponent {}", exp - 16),
-                _ => write!(f, "Unit Exponent"),
+                exp @ -8..=7 => Some(exp),
+                exp @ 8..=15 => Some(exp - 16),
+                _ => None,
             },
         }
     }
 }
 
+impl Display for UnitExponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.exponent() {
+            Some(exp) => write!(f, "Unit Exponent {exp}"),
+            None => write!(f, "Unit Exponent"),
+        }
+    }
+}
+
 impl Display for Unit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut units = Vec::new();
+        let mut units = [""; 9];
+        let mut len = 0;
+        let mut push = |unit| {
+            units[len] = unit;
+            len += 1;
+        };
         if let [byte, ..] = self.data() {
             let system = byte & 0x0F;
             let length = (byte & 0xF0) >> 4;
             match system {
-                1 => units.push("System: SI Linear"),
-                2 => units.push("System: SI Rotation"),
-                3 => units.push("System: English Linear"),
-                4 => units.push("System: English Rotation"),
-                5..=0xE => units.push("System: Reserved"),
-                0xF => units.push("System: Vendor Defined"),
-                _ => unreachable!(),
+                1 => push("System: SI Linear"),
+                2 => push("System: SI Rotation"),
+                3 => push("System: English Linear"),
+                4 => push("System: English Rotation"),
+                5..=0xE => push("System: Reserved"),
+                0xF => push("System: Vendor Defined"),
+                _ => (),
             }
             match length {
-                1 => units.push("Length: Centimeter"),
-                2 => units.push("Length: Radians"),
-                3 => units.push("Length: Inch"),
-                4 => units.push("Length: Degrees"),
-                5..=0xE => units.push("Length: Reserved"),
-                0xF => units.push("Length: Vendor Defined"),
-                _ => unreachable!(),
+                1 => push("Length: Centimeter"),
+                2 => push("Length: Radians"),
+                3 => push("Length: Inch"),
+                4 => push("Length: Degrees"),
+                5..=0xE => push("Length: Reserved"),
+                0xF => push("Length: Vendor Defined"),
+                _ => (),
             }
         }
         if let [_, byte, ..] = self.data() {
             let mass = byte & 0x0F;
             let time = (byte & 0xF0) >> 4;
             match mass {
-                1 | 2 => units.push("Mass: Gram"),
-                3 | 4 => units.push("Mass: Slug"),
-                5..=0xE => units.push("Mass: Reserved"),
-                0xF => units.push("Mass: Vendor Defined"),
-                _ => unreachable!(),
+                1 | 2 => push("Mass: Gram"),
+                3 | 4 => push("Mass: Slug"),
+                5..=0xE => push("Mass: Reserved"),
+                0xF => push("Mass: Vendor Defined"),
+                _ => (),
             }
             match time {
-                1..=4 => units.push("Time: Seconds"),
-                5..=0xE => units.push("Time: Reserved"),
-                0xF => units.push("Time: Vendor Defined"),
-                _ => unreachable!(),
+                1..=4 => push("Time: Seconds"),
+                5..=0xE => push("Time: Reserved"),
+                0xF => push("Time: Vendor Defined"),
+                _ => (),
             }
         }
         if let [_, _, byte, ..] = self.data() {
             let temperature = byte & 0x0F;
             let current = (byte & 0xF0) >> 4;
             match temperature {
-                1 | 2 => units.push("Temperature: Kelvin"),
-                3 | 4 => units.push("Temperature: Fahrenheit"),
-                5..=0xE => units.push("Temperature: Reserved"),
-                0xF => units.push("Temperature: Vendor Defined"),
-                _ => unreachable!(),
+                1 | 2 => push("Temperature: Kelvin"),
+                3 | 4 => push("Temperature: Fahrenheit"),
+                5..=0xE => push("Temperature: Reserved"),
+                0xF => push("Temperature: Vendor Defined"),
+                _ => (),
             }
             match current {
-                1..=4 => units.push("Current: Ampere"),
-                5..=0xE => units.push("Current: Reserved"),
-                0xF => units.push("Current: Vendor Defined"),
-                _ => unreachable!(),
+                1..=4 => push("Current: Ampere"),
+                5..=0xE => push("Current: Reserved"),
+                0xF => push("Current: Vendor Defined"),
+                _ => (),
             }
         }
         if let [_, _, _, byte, ..] = self.data() {
             let luminous_intensity = byte & 0x0F;
             match luminous_intensity {
-                1..=4 => units.push("Luminous Intensity: Candela"),
-                5..=0xE => units.push("Luminous Intensity: Reserved"),
-                0xF => units.push("Luminous Intensity: Vendor Defined"),
-                _ => unreachable!(),
+                1..=4 => push("Luminous Intensity: Candela"),
+                5..=0xE => push("Luminous Intensity: Reserved"),
+                0xF => push("Luminous Intensity: Vendor Defined"),
+                _ => (),
             }
         }
-        if units.is_empty() {
-            write!(f, "Unit")
-        } else {
-            write!(f, "Unit({})", units.join(", "))
+        match &units[..len] {
+            [] => write!(f, "Unit"),
+            [first, rest @ ..] => {
+                write!(f, "Unit({}", first)?;
+                for unit in rest {
+                    write!(f, ", {}", unit)?;
+                }
+                write!(f, ")")
+            }
         }
     }
 }
 
+/// Describe the net unit of [Unit] and [UnitExponent] combined, e.g.
+/// `"10^-2 cm*s^-2"`.
+///
+/// Unlike the [Display] of [Unit], nibbles 1-6 are read as signed exponents of
+/// the dimensions, named after the system in nibble 0. A missing or invalid
+/// unit exponent counts as 0.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{format_unit_with_exponent, Unit, UnitExponent};
+///
+/// // Centimeter, scaled by 10^-2.
+/// let unit = Unit::new_with(&[0x11]).unwrap();
+/// let exp = UnitExponent::new_with(&[0x0E]).unwrap();
+/// assert_eq!(format_unit_with_exponent(&unit, &exp), "10^-2 cm");
+///
+/// // Acceleration: cm*s^-2.
+/// let unit = Unit::new_with(&[0x11, 0xE0]).unwrap();
+/// let exp = UnitExponent::new_with(&[0x00]).unwrap();
+/// assert_eq!(format_unit_with_exponent(&unit, &exp), "cm*s^-2");
+///
+/// // Degrees of English Rotation.
+/// let unit = Unit::new_with(&[0x14]).unwrap();
+/// assert_eq!(format_unit_with_exponent(&unit, &UnitExponent::default()), "deg");
+///
+/// assert_eq!(format_unit_with_exponent(&Unit::default(), &UnitExponent::default()), "None");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn format_unit_with_exponent(unit: &Unit, exp: &UnitExponent) -> alloc::string::String {
+    use alloc::{format, string::String, vec::Vec};
+
+    let data = crate::__data_to_unsigned(unit.data());
+    let names: [&str; 6] = match data & 0xF {
+        1 => ["cm", "g", "s", "K", "A", "cd"],
+        2 => ["rad", "g", "s", "K", "A", "cd"],
+        3 => ["in", "slug", "s", "F", "A", "cd"],
+        4 => ["deg", "slug", "s", "F", "A", "cd"],
+        _ => [
+            "length",
+            "mass",
+            "time",
+            "temperature",
+            "current",
+            "luminous",
+        ],
+    };
+    let mut dimensions = Vec::new();
+    for (nibble, name) in names.iter().enumerate() {
+        let code = ((data >> ((nibble + 1) * 4)) & 0xF) as i32;
+        match if code < 8 { code } else { code - 16 } {
+            0 => (),
+            1 => dimensions.push(String::from(*name)),
+            power => dimensions.push(format!("{name}^{power}")),
+        }
+    }
+    match (exp.exponent().unwrap_or(0), dimensions.is_empty()) {
+        (0, true) => String::from("None"),
+        (0, false) => dimensions.join("*"),
+        (exp, true) => format!("10^{exp}"),
+        (exp, false) => format!("10^{exp} {}", dimensions.join("*")),
+    }
+}
+
 impl Display for ReportSize {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.data().len() {
             0 => write!(f, "Report Size"),
-            1.. => write!(f, "Report Size ({})", __data_to_unsigned(self.data())),
+            1.. => write!(
+                f,
+                "Report Size ({}{})",
+                __data_to_unsigned(self.data()),
+                __alternate_hex(f.alternate(), self.data())
+            ),
         }
     }
 }
@@ -350,7 +814,19 @@ impl Display for ReportId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.data().len() {
             0 => write!(f, "Report ID"),
-            1.. => write!(f, "Report ID ({})", __data_to_unsigned(self.data())),
+            1 => write!(
+                f,
+                "Report ID ({}{})",
+                self.value(),
+                __alternate_hex(f.alternate(), self.data())
+            ),
+            len => write!(
+                f,
+                "Report ID ({}{}, {} bytes)",
+                self.value(),
+                __alternate_hex(f.alternate(), self.data()),
+                len
+            ),
         }
     }
 }
@@ -359,7 +835,12 @@ impl Display for ReportCount {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.data().len() {
             0 => write!(f, "Report Count"),
-            1.. => write!(f, "Report Count ({})", __data_to_unsigned(self.data())),
+            1.. => write!(
+                f,
+                "Report Count ({}{})",
+                __data_to_unsigned(self.data()),
+                __alternate_hex(f.alternate(), self.data())
+            ),
         }
     }
 }
@@ -0,0 +1,50 @@
+use crate::{parse_all, ReportItem};
+use alloc::vec::Vec;
+use libstd::{io, path::Path};
+
+/// Read a descriptor file and parse it, e.g. the binary
+/// `/sys/class/hidraw/hidrawN/device/report_descriptor` on Linux.
+///
+/// Requires the `std` feature.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::parse_file;
+///
+/// let path = std::env::temp_dir().join("hid_report_parse_file");
+/// std::fs::write(&path, [0x05, 0x0C, 0x09, 0x01]).unwrap();
+/// let items = parse_file(&path).unwrap();
+/// assert_eq!(items[1].to_string(), "Usage (Consumer Control)");
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub fn parse_file<P: AsRef<Path>>(path: P) -> io::Result<Vec<ReportItem>> {
+    Ok(parse_all(&libstd::fs::read(path)?))
+}
+
+/// Stream item bytes to any writer, e.g. a file or a device node.
+///
+/// Unlike [`dump()`](crate::dump), nothing is collected in memory; see also
+/// [`dump_into()`](crate::dump_into) for a fixed buffer.
+///
+/// Requires the `std` feature.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{dump_writer, parse};
+///
+/// let items = parse([0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0xC0]).collect::<Vec<_>>();
+/// let mut writer = Vec::new();
+/// dump_writer(&items, &mut writer).unwrap();
+/// assert_eq!(writer, [0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0xC0]);
+/// ```
+pub fn dump_writer<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+    item_stream: ItemStream,
+    writer: &mut impl io::Write,
+) -> io::Result<()> {
+    for item in item_stream {
+        writer.write_all(item.as_ref())?;
+    }
+    Ok(())
+}
@@ -1,12 +1,25 @@
 #![no_std]
+#![forbid(unsafe_code)]
 #![deny(missing_docs)]
 
 //! Parse USB HID report descriptors and pretty print them.
 //!
+//! # Features
+//!
+//! - `alloc` (default): everything that collects items, bytes or strings, e.g.
+//!   [`dump()`], [`pretty_print()`] and the analysis helpers. Without it, report
+//!   items, the lazy parsers and [Display] still work on targets without a
+//!   global allocator.
+//! - `color` (default): [`pretty_print_colored()`], implies `alloc`.
+//! - `std`: I/O helpers `parse_file()` and `dump_writer()`, implies `alloc`.
+//! - `usage-tables`: name usages from a table generated from the HID Usage
+//!   Tables JSON by `scripts/gen_usage_tables.py`, before falling back to the
+//!   built-in names.
+//!
 //! # Example
 //!
 //! ```
-//! use hid_report::{parse, pretty_print};
+//! use hid_report::parse;
 //!
 //! let bytes = [
 //!     0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0x85, 0x02, 0x19,
@@ -30,7 +43,20 @@
 //! );
 //! assert_eq!(items.next().unwrap().to_string(), "End Collection");
 //! assert_eq!(items.next(), None);
+//! ```
+//!
+//! With the `alloc` feature, items can be pretty printed as a commented byte array:
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use hid_report::{parse, pretty_print};
 //!
+//! # let bytes = [
+//! #     0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0x85, 0x02, 0x19,
+//! #     0x00, 0x2A, 0x3C, 0x02, 0x15, 0x00, 0x26, 0x3C, 0x02,
+//! #     0x95, 0x01, 0x75, 0x10, 0x81, 0x00, 0xC0,
+//! # ];
 //! let items = parse(bytes).collect::<Vec<_>>();
 //!
 //! const EXPECTED: &str = indoc::indoc! {"
@@ -49,32 +75,95 @@
 //! };
 //!
 //! assert_eq!(pretty_print(&items), EXPECTED);
+//! # }
 //! ```
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
 extern crate core as std;
+#[cfg(feature = "std")]
+extern crate std as libstd;
 
+#[cfg(feature = "alloc")]
+mod descriptor;
 mod error;
+#[cfg(feature = "alloc")]
+mod export;
+#[cfg(feature = "alloc")]
+mod field;
 mod global_items;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "alloc")]
+mod lint;
 mod local_items;
 mod macros;
 mod main_items;
+#[cfg(feature = "alloc")]
+mod merge;
+mod names;
+#[cfg(feature = "alloc")]
+mod normalize;
+mod parser;
+pub mod prelude;
+#[cfg(feature = "alloc")]
+mod pretty;
 mod privates;
 mod reserved;
+#[cfg(feature = "alloc")]
+mod split;
+mod state;
+#[cfg(feature = "alloc")]
+mod summary;
+#[cfg(feature = "alloc")]
+mod tree;
+#[cfg(feature = "usage-tables")]
+mod usage_tables;
+#[cfg(feature = "alloc")]
+mod usages;
+pub mod util;
+#[cfg(feature = "alloc")]
+mod webhid;
 
-use alloc::{
-    format,
-    string::{String, ToString},
-    vec::Vec,
-};
-use std::fmt::Display;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use std::{fmt::Display, str::FromStr};
 
+#[cfg(feature = "alloc")]
+pub use descriptor::*;
 pub use error::*;
+#[cfg(feature = "alloc")]
+pub use export::*;
+#[cfg(feature = "alloc")]
+pub use field::*;
 pub use global_items::*;
+#[cfg(feature = "std")]
+pub use io::*;
+#[cfg(feature = "alloc")]
+pub use lint::*;
 pub use local_items::*;
 pub use main_items::*;
+#[cfg(feature = "alloc")]
+pub use merge::*;
+pub use names::*;
+#[cfg(feature = "alloc")]
+pub use normalize::*;
+pub use parser::*;
+#[cfg(feature = "alloc")]
+pub use pretty::*;
 pub(crate) use privates::*;
 pub use reserved::*;
+#[cfg(feature = "alloc")]
+pub use split::*;
+pub use state::*;
+#[cfg(feature = "alloc")]
+pub use summary::*;
+#[cfg(feature = "alloc")]
+pub use tree::*;
+#[cfg(feature = "alloc")]
+pub use usages::*;
+#[cfg(feature = "alloc")]
+pub use webhid::*;
 
 /// Report items enumeration.
 ///
@@ -118,6 +207,48 @@ pub use reserved::*;
 /// | 3 | Reserved |
 ///
 /// bTag: Numeric expression specifying the function of the item.
+///
+/// # Example
+///
+/// Every concrete item converts into a report item:
+///
+/// ```
+/// use hid_report::{ReportItem, ReportSize, UsagePage};
+///
+/// let items: [ReportItem; 2] = [
+///     UsagePage::from_value(0x0C).into(),
+///     ReportSize::from_value(8).into(),
+/// ];
+/// assert_eq!(items[0].as_ref(), [0x05, 0x0C]);
+/// assert_eq!(items[1].as_ref(), [0x75, 0x08]);
+/// ```
+///
+/// And a report item converts back into its concrete item:
+///
+/// ```
+/// use hid_report::{HidError, ReportItem, ReportSize, UsagePage};
+///
+/// let item = ReportItem::new(&[0x75, 0x08]).unwrap();
+/// let report_size = ReportSize::try_from(item.clone()).unwrap();
+/// assert_eq!(report_size.data(), [0x08]);
+/// assert_eq!(UsagePage::try_from(item), Err(HidError::PrefixNotMatch));
+/// ```
+///
+/// Or borrows it, which keeps filter pipelines short:
+///
+/// ```
+/// use hid_report::{parse, ReportItem};
+///
+/// let items = parse([0x05, 0x01, 0x09, 0x30, 0x09, 0x31]).collect::<Vec<_>>();
+/// let usages = items
+///     .iter()
+///     .filter_map(ReportItem::as_usage)
+///     .map(|usage| usage.to_string())
+///     .collect::<Vec<_>>();
+/// assert_eq!(usages, ["Usage (X)", "Usage (Y)"]);
+/// assert!(items[0].as_usage_page().is_some());
+/// assert!(items[0].as_input().is_none());
+/// ```
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ReportItem {
     /// An [Input] item.
@@ -178,6 +309,160 @@ pub enum ReportItem {
     Reserved(Reserved),
 }
 
+/// Kinds of report items, i.e. [ReportItem] variants without their data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ItemKind {
+    /// An [Input] item.
+    Input,
+    /// An [Output] item.
+    Output,
+    /// A [Feature] item.
+    Feature,
+    /// A [Collection] item.
+    Collection,
+    /// An [EndCollection] item.
+    EndCollection,
+    /// An [UsagePage] item.
+    UsagePage,
+    /// A [LogicalMinimum] item.
+    LogicalMinimum,
+    /// A [LogicalMaximum] item.
+    LogicalMaximum,
+    /// A [PhysicalMinimum] item.
+    PhysicalMinimum,
+    /// A [PhysicalMaximum] item.
+    PhysicalMaximum,
+    /// An [UnitExponent] item.
+    UnitExponent,
+    /// An [Unit] item.
+    Unit,
+    /// A [ReportSize] item.
+    ReportSize,
+    /// A [ReportId] item.
+    ReportId,
+    /// A [ReportCount] item.
+    ReportCount,
+    /// A [Push] item.
+    Push,
+    /// A [Pop] item.
+    Pop,
+    /// An [Usage] item.
+    Usage,
+    /// An [UsageMinimum] item.
+    UsageMinimum,
+    /// An [UsageMaximum] item.
+    UsageMaximum,
+    /// A [DesignatorIndex] item.
+    DesignatorIndex,
+    /// A [DesignatorMinimum] item.
+    DesignatorMinimum,
+    /// A [DesignatorMaximum] item.
+    DesignatorMaximum,
+    /// A [StringIndex] item.
+    StringIndex,
+    /// A [StringMinimum] item.
+    StringMinimum,
+    /// A [StringMaximum] item.
+    StringMaximum,
+    /// A [Delimiter] item.
+    Delimiter,
+    /// A [Reserved] item.
+    Reserved,
+}
+
+impl ItemKind {
+    /// Get the name of the kind as used when formatting items, e.g. `"Usage Page"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ItemKind::Input => "Input",
+            ItemKind::Output => "Output",
+            ItemKind::Feature => "Feature",
+            ItemKind::Collection => "Collection",
+            ItemKind::EndCollection => "End Collection",
+            ItemKind::UsagePage => "Usage Page",
+            ItemKind::LogicalMinimum => "Logical Minimum",
+            ItemKind::LogicalMaximum => "Logical Maximum",
+            ItemKind::PhysicalMinimum => "Physical Minimum",
+            ItemKind::PhysicalMaximum => "Physical Maximum",
+            ItemKind::UnitExponent => "Unit Exponent",
+            ItemKind::Unit => "Unit",
+            ItemKind::ReportSize => "Report Size",
+            ItemKind::ReportId => "Report ID",
+            ItemKind::ReportCount => "Report Count",
+            ItemKind::Push => "Push",
+            ItemKind::Pop => "Pop",
+            ItemKind::Usage => "Usage",
+            ItemKind::UsageMinimum => "Usage Minimum",
+            ItemKind::UsageMaximum => "Usage Maximum",
+            ItemKind::DesignatorIndex => "Designator Index",
+            ItemKind::DesignatorMinimum => "Designator Minimum",
+            ItemKind::DesignatorMaximum => "Designator Maximum",
+            ItemKind::StringIndex => "String Index",
+            ItemKind::StringMinimum => "String Minimum",
+            ItemKind::StringMaximum => "String Maximum",
+            ItemKind::Delimiter => "Delimiter",
+            ItemKind::Reserved => "Reserved",
+        }
+    }
+}
+
+/// Types of report items, encoded in bits 2-3 of the prefix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ItemType {
+    /// Main items, e.g. [Input] and [Collection].
+    Main,
+    /// Global items, e.g. [UsagePage] and [ReportSize].
+    Global,
+    /// Local items, e.g. [Usage] and [Delimiter].
+    Local,
+    /// The reserved type `0b11`, used by long items.
+    Reserved,
+}
+
+/// Roles of report items when analyzing a descriptor, see [`ReportItem::category()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ItemCategory {
+    /// Global and local items that configure the parser state.
+    Setup,
+    /// [Input], [Output] and [Feature] items that produce report fields.
+    DataDefining,
+    /// [Collection] and [EndCollection] items that group fields.
+    Structural,
+    /// [Reserved] items.
+    Reserved,
+}
+
+macros::__impls_for_report_items! {
+    Input => as_input,
+    Output => as_output,
+    Feature => as_feature,
+    Collection => as_collection,
+    EndCollection => as_end_collection,
+    UsagePage => as_usage_page,
+    LogicalMinimum => as_logical_minimum,
+    LogicalMaximum => as_logical_maximum,
+    PhysicalMinimum => as_physical_minimum,
+    PhysicalMaximum => as_physical_maximum,
+    UnitExponent => as_unit_exponent,
+    Unit => as_unit,
+    ReportSize => as_report_size,
+    ReportId => as_report_id,
+    ReportCount => as_report_count,
+    Push => as_push,
+    Pop => as_pop,
+    Usage => as_usage,
+    UsageMinimum => as_usage_minimum,
+    UsageMaximum => as_usage_maximum,
+    DesignatorIndex => as_designator_index,
+    DesignatorMinimum => as_designator_minimum,
+    DesignatorMaximum => as_designator_maximum,
+    StringIndex => as_string_index,
+    StringMinimum => as_string_minimum,
+    StringMaximum => as_string_maximum,
+    Delimiter => as_delimiter,
+    Reserved => as_reserved
+}
+
 impl AsRef<[u8]> for ReportItem {
     fn as_ref(&self) -> &[u8] {
         match self {
@@ -213,6 +498,80 @@ impl AsRef<[u8]> for ReportItem {
     }
 }
 
+/// Items are ordered by [type](ReportItem::item_type()), then tag, then data bytes.
+///
+/// *NOTE*: the order of items in a descriptor is meaningful, so sorting is only
+/// intended for comparison tooling (e.g. diffing two setup blocks), never for
+/// re-serialization.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::parse;
+///
+/// let shuffled = [0x95, 0x01, 0x75, 0x08, 0x26, 0xFF, 0x00, 0x15, 0x00, 0x05, 0x01];
+/// let mut items = parse(shuffled).collect::<Vec<_>>();
+/// items.sort();
+/// let items = items.iter().map(|item| item.to_string()).collect::<Vec<_>>();
+/// assert_eq!(
+///     items,
+///     [
+///         "Usage Page (Generic Desktop)",
+///         "Logical Minimum (0)",
+///         "Logical Maximum (255)",
+///         "Report Size (8)",
+///         "Report Count (1)",
+///     ]
+/// );
+/// ```
+impl PartialOrd for ReportItem {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ReportItem {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.item_type()
+            .cmp(&other.item_type())
+            .then_with(|| self.tag().cmp(&other.tag()))
+            .then_with(|| self.data().cmp(other.data()))
+    }
+}
+
+/// The alternate form (`{:#}`) appends the raw data in hex to items with data, which
+/// reveals e.g. reserved bits of main items and the numeric value of a usage page.
+///
+/// Items that vary between the two forms: [Input], [Output], [Feature], [Collection],
+/// [UsagePage], [LogicalMinimum], [LogicalMaximum], [PhysicalMinimum],
+/// [PhysicalMaximum], [ReportSize], [ReportId], [ReportCount], [DesignatorIndex],
+/// [DesignatorMinimum], [DesignatorMaximum], [StringIndex], [StringMinimum],
+/// [StringMaximum] and [Delimiter].
+///
+/// # Example
+///
+/// ```
+/// use hid_report::ReportItem;
+///
+/// let item = ReportItem::new(&[0x26, 0x3C, 0x02]).unwrap();
+/// assert_eq!(format!("{}", item), "Logical Maximum (572)");
+/// assert_eq!(format!("{:#}", item), "Logical Maximum (572 [0x023C])");
+///
+/// let item = ReportItem::new(&[0x15, 0x81]).unwrap();
+/// assert_eq!(format!("{:#}", item), "Logical Minimum (-127 [0x81])");
+///
+/// let item = ReportItem::new(&[0x05, 0x01]).unwrap();
+/// assert_eq!(format!("{:#}", item), "Usage Page (Generic Desktop [0x01])");
+///
+/// let item = ReportItem::new(&[0x81, 0x82]).unwrap();
+/// assert_eq!(
+///     format!("{:#}", item),
+///     "Input (Data, Variable, Absolute, No Wrap, Linear, Preferred State, No Null Position [0x82])"
+/// );
+///
+/// let item = ReportItem::new(&[0xC0]).unwrap();
+/// assert_eq!(format!("{:#}", item), "End Collection");
+/// ```
 impl Display for ReportItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -248,6 +607,59 @@ impl Display for ReportItem {
     }
 }
 
+/// Parse a single item from hex bytes separated by commas or whitespace, with or
+/// without `0x` prefix, e.g. `"0x26, 0x3C, 0x02"`.
+///
+/// See [`parse_usbhid_dump()`] for many items.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{HidError, ReportItem};
+///
+/// let item: ReportItem = "0x26, 0x3c, 0x02".parse().unwrap();
+/// assert_eq!(item.to_string(), "Logical Maximum (572)");
+/// assert_eq!("75 08".parse::<ReportItem>().unwrap().to_string(), "Report Size (8)");
+///
+/// assert_eq!("".parse::<ReportItem>(), Err(HidError::EmptyRawInput));
+/// assert_eq!("0x26, 0xZZ".parse::<ReportItem>(), Err(HidError::InvalidHex));
+/// assert_eq!(
+///     "0x26, 0x3C".parse::<ReportItem>(),
+///     Err(HidError::DataSizeNotMatch { expected: 2, provided: 1 })
+/// );
+/// ```
+impl FromStr for ReportItem {
+    type Err = HidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut raw = [0; 5];
+        let mut len = 0;
+        for token in s
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|token| !token.is_empty())
+        {
+            let digits = token
+                .strip_prefix("0x")
+                .or_else(|| token.strip_prefix("0X"))
+                .unwrap_or(token);
+            if !(1..=2).contains(&digits.len()) || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(HidError::InvalidHex);
+            }
+            if let Some(byte) = raw.get_mut(len) {
+                *byte = u8::from_str_radix(digits, 16).map_err(|_| HidError::InvalidHex)?;
+            }
+            len += 1;
+        }
+        if len > raw.len() {
+            return Err(HidError::DataSizeNotMatch {
+                expected: __data_size(raw[0]),
+                provided: len - 1,
+            });
+        }
+        ReportItem::new(&raw[..len])
+    }
+}
+
 impl ReportItem {
     /// Create a new item from raw byte stream.
     ///
@@ -264,6 +676,26 @@ impl ReportItem {
     /// assert!(matches!(item, ReportItem::LogicalMaximum(_)));
     /// assert_eq!(item.to_string(), "Logical Maximum (572)");
     /// ```
+    ///
+    /// The length of `raw` must match the data size encoded in the prefix exactly,
+    /// one byte short or too long is reported as [`HidError::DataSizeNotMatch`]:
+    ///
+    /// ```
+    /// use hid_report::{HidError, ReportItem};
+    ///
+    /// let raw = [0xFF; 6];
+    /// for (prefix, expected) in [(0x24, 0), (0x25, 1), (0x26, 2), (0x27, 4)] {
+    ///     let mut raw = raw;
+    ///     raw[0] = prefix;
+    ///     assert!(ReportItem::new(&raw[..expected + 1]).is_ok());
+    ///     assert!(ReportItem::new_strict(&raw[..expected + 1]).is_ok());
+    ///     for provided in expected.checked_sub(1).into_iter().chain([expected + 1]) {
+    ///         let error = HidError::DataSizeNotMatch { expected, provided };
+    ///         assert_eq!(ReportItem::new(&raw[..provided + 1]), Err(error.clone()));
+    ///         assert_eq!(ReportItem::new_strict(&raw[..provided + 1]), Err(error));
+    ///     }
+    /// }
+    /// ```
     pub fn new(raw: &[u8]) -> Result<Self, HidError> {
         if raw.is_empty() {
             return Err(HidError::EmptyRawInput);
@@ -275,63 +707,56 @@ impl ReportItem {
                 provided: raw.len() - 1,
             });
         };
-        unsafe {
-            Ok(match raw[0] & 0b1111_1100 {
-                Input::PREFIX => ReportItem::Input(Input::new_unchecked(raw)),
-                Output::PREFIX => ReportItem::Output(Output::new_unchecked(raw)),
-                Feature::PREFIX => ReportItem::Feature(Feature::new_unchecked(raw)),
-                Collection::PREFIX => ReportItem::Collection(Collection::new_unchecked(raw)),
-                EndCollection::PREFIX => {
-                    ReportItem::EndCollection(EndCollection::new_unchecked(raw))
-                }
-                UsagePage::PREFIX => ReportItem::UsagePage(UsagePage::new_unchecked(raw)),
-                LogicalMinimum::PREFIX => {
-                    ReportItem::LogicalMinimum(LogicalMinimum::new_unchecked(raw))
-                }
-                LogicalMaximum::PREFIX => {
-                    ReportItem::LogicalMaximum(LogicalMaximum::new_unchecked(raw))
-                }
-                PhysicalMinimum::PREFIX => {
-                    ReportItem::PhysicalMinimum(PhysicalMinimum::new_unchecked(raw))
-                }
-                PhysicalMaximum::PREFIX => {
-                    ReportItem::PhysicalMaximum(PhysicalMaximum::new_unchecked(raw))
-                }
-                UnitExponent::PREFIX => ReportItem::UnitExponent(UnitExponent::new_unchecked(raw)),
-                Unit::PREFIX => ReportItem::Unit(Unit::new_unchecked(raw)),
-                ReportSize::PREFIX => ReportItem::ReportSize(ReportSize::new_unchecked(raw)),
-                ReportId::PREFIX => ReportItem::ReportId(ReportId::new_unchecked(raw)),
-                ReportCount::PREFIX => ReportItem::ReportCount(ReportCount::new_unchecked(raw)),
-                Push::PREFIX => ReportItem::Push(Push::new_unchecked(raw)),
-                Pop::PREFIX => ReportItem::Pop(Pop::new_unchecked(raw)),
-                Usage::PREFIX => ReportItem::Usage(Usage::new_unchecked(raw)),
-                UsageMinimum::PREFIX => ReportItem::UsageMinimum(UsageMinimum::new_unchecked(raw)),
-                UsageMaximum::PREFIX => ReportItem::UsageMaximum(UsageMaximum::new_unchecked(raw)),
-                DesignatorIndex::PREFIX => {
-                    ReportItem::DesignatorIndex(DesignatorIndex::new_unchecked(raw))
-                }
-                DesignatorMinimum::PREFIX => {
-                    ReportItem::DesignatorMinimum(DesignatorMinimum::new_unchecked(raw))
-                }
-                DesignatorMaximum::PREFIX => {
-                    ReportItem::DesignatorMaximum(DesignatorMaximum::new_unchecked(raw))
-                }
-                StringIndex::PREFIX => ReportItem::StringIndex(StringIndex::new_unchecked(raw)),
-                StringMinimum::PREFIX => {
-                    ReportItem::StringMinimum(StringMinimum::new_unchecked(raw))
-                }
-                StringMaximum::PREFIX => {
-                    ReportItem::StringMaximum(StringMaximum::new_unchecked(raw))
-                }
-                Delimiter::PREFIX => ReportItem::Delimiter(Delimiter::new_unchecked(raw)),
-                _ => ReportItem::Reserved(Reserved::new_unchecked(raw)),
-            })
-        }
+        Ok(match raw[0] & 0b1111_1100 {
+            Input::PREFIX => ReportItem::Input(Input::new_unchecked(raw)),
+            Output::PREFIX => ReportItem::Output(Output::new_unchecked(raw)),
+            Feature::PREFIX => ReportItem::Feature(Feature::new_unchecked(raw)),
+            Collection::PREFIX => ReportItem::Collection(Collection::new_unchecked(raw)),
+            EndCollection::PREFIX => ReportItem::EndCollection(EndCollection::new_unchecked(raw)),
+            UsagePage::PREFIX => ReportItem::UsagePage(UsagePage::new_unchecked(raw)),
+            LogicalMinimum::PREFIX => {
+                ReportItem::LogicalMinimum(LogicalMinimum::new_unchecked(raw))
+            }
+            LogicalMaximum::PREFIX => {
+                ReportItem::LogicalMaximum(LogicalMaximum::new_unchecked(raw))
+            }
+            PhysicalMinimum::PREFIX => {
+                ReportItem::PhysicalMinimum(PhysicalMinimum::new_unchecked(raw))
+            }
+            PhysicalMaximum::PREFIX => {
+                ReportItem::PhysicalMaximum(PhysicalMaximum::new_unchecked(raw))
+            }
+            UnitExponent::PREFIX => ReportItem::UnitExponent(UnitExponent::new_unchecked(raw)),
+            Unit::PREFIX => ReportItem::Unit(Unit::new_unchecked(raw)),
+            ReportSize::PREFIX => ReportItem::ReportSize(ReportSize::new_unchecked(raw)),
+            ReportId::PREFIX => ReportItem::ReportId(ReportId::new_unchecked(raw)),
+            ReportCount::PREFIX => ReportItem::ReportCount(ReportCount::new_unchecked(raw)),
+            Push::PREFIX => ReportItem::Push(Push::new_unchecked(raw)),
+            Pop::PREFIX => ReportItem::Pop(Pop::new_unchecked(raw)),
+            Usage::PREFIX => ReportItem::Usage(Usage::new_unchecked(raw)),
+            UsageMinimum::PREFIX => ReportItem::UsageMinimum(UsageMinimum::new_unchecked(raw)),
+            UsageMaximum::PREFIX => ReportItem::UsageMaximum(UsageMaximum::new_unchecked(raw)),
+            DesignatorIndex::PREFIX => {
+                ReportItem::DesignatorIndex(DesignatorIndex::new_unchecked(raw))
+            }
+            DesignatorMinimum::PREFIX => {
+                ReportItem::DesignatorMinimum(DesignatorMinimum::new_unchecked(raw))
+            }
+            DesignatorMaximum::PREFIX => {
+                ReportItem::DesignatorMaximum(DesignatorMaximum::new_unchecked(raw))
+            }
+            StringIndex::PREFIX => ReportItem::StringIndex(StringIndex::new_unchecked(raw)),
+            StringMinimum::PREFIX => ReportItem::StringMinimum(StringMinimum::new_unchecked(raw)),
+            StringMaximum::PREFIX => ReportItem::StringMaximum(StringMaximum::new_unchecked(raw)),
+            Delimiter::PREFIX => ReportItem::Delimiter(Delimiter::new_unchecked(raw)),
+            _ => ReportItem::Reserved(Reserved::new_unchecked(raw)),
+        })
     }
 
     /// Create a new item from raw byte stream in strict mode.
     ///
-    /// Items that cannot be recognized will be treated as [`HidError::ReservedItem`].
+    /// [Long items](Reserved#long-items) will be treated as [`HidError::LongItem`],
+    /// and other items that cannot be recognized as [`HidError::ReservedItem`].
     pub fn new_strict(raw: &[u8]) -> Result<Self, crate::HidError> {
         if raw.is_empty() {
             return Err(crate::HidError::EmptyRawInput);
@@ -343,58 +768,53 @@ impl ReportItem {
                 provided: raw.len() - 1,
             });
         };
-        unsafe {
-            Ok(match raw[0] & 0b1111_1100 {
-                Input::PREFIX => ReportItem::Input(Input::new_unchecked(raw)),
-                Output::PREFIX => ReportItem::Output(Output::new_unchecked(raw)),
-                Feature::PREFIX => ReportItem::Feature(Feature::new_unchecked(raw)),
-                Collection::PREFIX => ReportItem::Collection(Collection::new_unchecked(raw)),
-                EndCollection::PREFIX => {
-                    ReportItem::EndCollection(EndCollection::new_unchecked(raw))
-                }
-                UsagePage::PREFIX => ReportItem::UsagePage(UsagePage::new_unchecked(raw)),
-                LogicalMinimum::PREFIX => {
-                    ReportItem::LogicalMinimum(LogicalMinimum::new_unchecked(raw))
-                }
-                LogicalMaximum::PREFIX => {
-                    ReportItem::LogicalMaximum(LogicalMaximum::new_unchecked(raw))
-                }
-                PhysicalMinimum::PREFIX => {
-                    ReportItem::PhysicalMinimum(PhysicalMinimum::new_unchecked(raw))
-                }
-                PhysicalMaximum::PREFIX => {
-                    ReportItem::PhysicalMaximum(PhysicalMaximum::new_unchecked(raw))
-                }
-                UnitExponent::PREFIX => ReportItem::UnitExponent(UnitExponent::new_unchecked(raw)),
-                Unit::PREFIX => ReportItem::Unit(Unit::new_unchecked(raw)),
-                ReportSize::PREFIX => ReportItem::ReportSize(ReportSize::new_unchecked(raw)),
-                ReportId::PREFIX => ReportItem::ReportId(ReportId::new_unchecked(raw)),
-                ReportCount::PREFIX => ReportItem::ReportCount(ReportCount::new_unchecked(raw)),
-                Push::PREFIX => ReportItem::Push(Push::new_unchecked(raw)),
-                Pop::PREFIX => ReportItem::Pop(Pop::new_unchecked(raw)),
-                Usage::PREFIX => ReportItem::Usage(Usage::new_unchecked(raw)),
-                UsageMinimum::PREFIX => ReportItem::UsageMinimum(UsageMinimum::new_unchecked(raw)),
-                UsageMaximum::PREFIX => ReportItem::UsageMaximum(UsageMaximum::new_unchecked(raw)),
-                DesignatorIndex::PREFIX => {
-                    ReportItem::DesignatorIndex(DesignatorIndex::new_unchecked(raw))
-                }
-                DesignatorMinimum::PREFIX => {
-                    ReportItem::DesignatorMinimum(DesignatorMinimum::new_unchecked(raw))
-                }
-                DesignatorMaximum::PREFIX => {
-                    ReportItem::DesignatorMaximum(DesignatorMaximum::new_unchecked(raw))
-                }
-                StringIndex::PREFIX => ReportItem::StringIndex(StringIndex::new_unchecked(raw)),
-                StringMinimum::PREFIX => {
-                    ReportItem::StringMinimum(StringMinimum::new_unchecked(raw))
-                }
-                StringMaximum::PREFIX => {
-                    ReportItem::StringMaximum(StringMaximum::new_unchecked(raw))
-                }
-                Delimiter::PREFIX => ReportItem::Delimiter(Delimiter::new_unchecked(raw)),
-                _ => return Err(HidError::ReservedItem(Reserved::new_unchecked(raw))),
-            })
+        if let [Reserved::LONG_ITEM_PREFIX, data_size, tag] = *raw {
+            return Err(HidError::LongItem { data_size, tag });
         }
+        Ok(match raw[0] & 0b1111_1100 {
+            Input::PREFIX => ReportItem::Input(Input::new_unchecked(raw)),
+            Output::PREFIX => ReportItem::Output(Output::new_unchecked(raw)),
+            Feature::PREFIX => ReportItem::Feature(Feature::new_unchecked(raw)),
+            Collection::PREFIX => ReportItem::Collection(Collection::new_unchecked(raw)),
+            EndCollection::PREFIX => ReportItem::EndCollection(EndCollection::new_unchecked(raw)),
+            UsagePage::PREFIX => ReportItem::UsagePage(UsagePage::new_unchecked(raw)),
+            LogicalMinimum::PREFIX => {
+                ReportItem::LogicalMinimum(LogicalMinimum::new_unchecked(raw))
+            }
+            LogicalMaximum::PREFIX => {
+                ReportItem::LogicalMaximum(LogicalMaximum::new_unchecked(raw))
+            }
+            PhysicalMinimum::PREFIX => {
+                ReportItem::PhysicalMinimum(PhysicalMinimum::new_unchecked(raw))
+            }
+            PhysicalMaximum::PREFIX => {
+                ReportItem::PhysicalMaximum(PhysicalMaximum::new_unchecked(raw))
+            }
+            UnitExponent::PREFIX => ReportItem::UnitExponent(UnitExponent::new_unchecked(raw)),
+            Unit::PREFIX => ReportItem::Unit(Unit::new_unchecked(raw)),
+            ReportSize::PREFIX => ReportItem::ReportSize(ReportSize::new_unchecked(raw)),
+            ReportId::PREFIX => ReportItem::ReportId(ReportId::new_unchecked(raw)),
+            ReportCount::PREFIX => ReportItem::ReportCount(ReportCount::new_unchecked(raw)),
+            Push::PREFIX => ReportItem::Push(Push::new_unchecked(raw)),
+            Pop::PREFIX => ReportItem::Pop(Pop::new_unchecked(raw)),
+            Usage::PREFIX => ReportItem::Usage(Usage::new_unchecked(raw)),
+            UsageMinimum::PREFIX => ReportItem::UsageMinimum(UsageMinimum::new_unchecked(raw)),
+            UsageMaximum::PREFIX => ReportItem::UsageMaximum(UsageMaximum::new_unchecked(raw)),
+            DesignatorIndex::PREFIX => {
+                ReportItem::DesignatorIndex(DesignatorIndex::new_unchecked(raw))
+            }
+            DesignatorMinimum::PREFIX => {
+                ReportItem::DesignatorMinimum(DesignatorMinimum::new_unchecked(raw))
+            }
+            DesignatorMaximum::PREFIX => {
+                ReportItem::DesignatorMaximum(DesignatorMaximum::new_unchecked(raw))
+            }
+            StringIndex::PREFIX => ReportItem::StringIndex(StringIndex::new_unchecked(raw)),
+            StringMinimum::PREFIX => ReportItem::StringMinimum(StringMinimum::new_unchecked(raw)),
+            StringMaximum::PREFIX => ReportItem::StringMaximum(StringMaximum::new_unchecked(raw)),
+            Delimiter::PREFIX => ReportItem::Delimiter(Delimiter::new_unchecked(raw)),
+            _ => return Err(HidError::ReservedItem(Reserved::new_unchecked(raw))),
+        })
     }
 
     /// Create a new item from raw byte stream, without checking data length.
@@ -403,10 +823,37 @@ impl ReportItem {
     /// If you want to fail on unknown items, use
     /// [`new_strict_unchecked()`](ReportItem::new_strict_unchecked()) instead.
     ///
-    /// # Safety
+    /// *NOTE*: you should ensure that the raw data is a valid HID report item.
     ///
-    /// You should ensure that the raw data is a valid HID report item.
-    pub unsafe fn new_unchecked(raw: &[u8]) -> Self {
+    /// Data missing from `raw` is treated as zero, so formatting never panics even if
+    /// the prefix claims more data than provided.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::ReportItem;
+    ///
+    /// let bytes = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    /// for prefix in 0..=u8::MAX {
+    ///     for len in 0..bytes.len() {
+    ///         let mut raw = bytes[..len + 1].to_vec();
+    ///         raw[0] = prefix;
+    ///         let item = ReportItem::new_unchecked(&raw);
+    ///         assert!(!item.to_string().is_empty());
+    ///     }
+    /// }
+    ///
+    /// let item = ReportItem::new_unchecked(&[0x65]);
+    /// assert_eq!(item.to_string(), "Unit");
+    ///
+    /// let item = ReportItem::new_unchecked(&[0x82, 0x03]);
+    /// assert_eq!(item.data(), [0x03, 0x00]);
+    /// assert_eq!(
+    ///     item.to_string(),
+    ///     "Input (Constant, Variable, Absolute, No Wrap, Linear, Preferred State, No Null Position, Bit Field)"
+    /// );
+    /// ```
+    pub fn new_unchecked(raw: &[u8]) -> Self {
         match raw[0] & 0b1111_1100 {
             Input::PREFIX => ReportItem::Input(Input::new_unchecked(raw)),
             Output::PREFIX => ReportItem::Output(Output::new_unchecked(raw)),
@@ -455,13 +902,15 @@ impl ReportItem {
 
     /// Create a new item from raw byte stream in strict mode, without checking data length.
     ///
-    /// Items that cannot be recognized will be treated as [`HidError::ReservedItem`].
-    /// Also, this is the only error that may be reported.
-    ///
-    /// # Safety
+    /// [Long items](Reserved#long-items) will be treated as [`HidError::LongItem`],
+    /// and other items that cannot be recognized as [`HidError::ReservedItem`].
+    /// Also, these are the only errors that may be reported.
     ///
-    /// You should ensure that the raw data is a valid HID report item.
-    pub unsafe fn new_strict_unchecked(raw: &[u8]) -> Result<Self, HidError> {
+    /// *NOTE*: you should ensure that the raw data is a valid HID report item.
+    pub fn new_strict_unchecked(raw: &[u8]) -> Result<Self, HidError> {
+        if let [Reserved::LONG_ITEM_PREFIX, data_size, tag, ..] = *raw {
+            return Err(HidError::LongItem { data_size, tag });
+        }
         Ok(match raw[0] & 0b1111_1100 {
             Input::PREFIX => ReportItem::Input(Input::new_unchecked(raw)),
             Output::PREFIX => ReportItem::Output(Output::new_unchecked(raw)),
@@ -508,25 +957,409 @@ impl ReportItem {
         })
     }
 
-    /// Get prefix part of the item. Equivalent to `item.as_ref()[0]`.
-    pub fn prefix(&self) -> u8 {
-        self.as_ref()[0]
+    /// Get the kind of the item.
+    pub fn kind(&self) -> ItemKind {
+        match self {
+            ReportItem::Input(_) => ItemKind::Input,
+            ReportItem::Output(_) => ItemKind::Output,
+            ReportItem::Feature(_) => ItemKind::Feature,
+            ReportItem::Collection(_) => ItemKind::Collection,
+            ReportItem::EndCollection(_) => ItemKind::EndCollection,
+            ReportItem::UsagePage(_) => ItemKind::UsagePage,
+            ReportItem::LogicalMinimum(_) => ItemKind::LogicalMinimum,
+            ReportItem::LogicalMaximum(_) => ItemKind::LogicalMaximum,
+            ReportItem::PhysicalMinimum(_) => ItemKind::PhysicalMinimum,
+            ReportItem::PhysicalMaximum(_) => ItemKind::PhysicalMaximum,
+            ReportItem::UnitExponent(_) => ItemKind::UnitExponent,
+            ReportItem::Unit(_) => ItemKind::Unit,
+            ReportItem::ReportSize(_) => ItemKind::ReportSize,
+            ReportItem::ReportId(_) => ItemKind::ReportId,
+            ReportItem::ReportCount(_) => ItemKind::ReportCount,
+            ReportItem::Push(_) => ItemKind::Push,
+            ReportItem::Pop(_) => ItemKind::Pop,
+            ReportItem::Usage(_) => ItemKind::Usage,
+            ReportItem::UsageMinimum(_) => ItemKind::UsageMinimum,
+            ReportItem::UsageMaximum(_) => ItemKind::UsageMaximum,
+            ReportItem::DesignatorIndex(_) => ItemKind::DesignatorIndex,
+            ReportItem::DesignatorMinimum(_) => ItemKind::DesignatorMinimum,
+            ReportItem::DesignatorMaximum(_) => ItemKind::DesignatorMaximum,
+            ReportItem::StringIndex(_) => ItemKind::StringIndex,
+            ReportItem::StringMinimum(_) => ItemKind::StringMinimum,
+            ReportItem::StringMaximum(_) => ItemKind::StringMaximum,
+            ReportItem::Delimiter(_) => ItemKind::Delimiter,
+            ReportItem::Reserved(_) => ItemKind::Reserved,
+        }
     }
 
-    /// Get data part of the item. Equivalent to `&item.as_ref()[1..]`.
-    pub fn data(&self) -> &[u8] {
-        &self.as_ref()[1..]
+    /// Get the name of the item without its data, e.g. `"Logical Minimum"`, which is
+    /// handy for grouping items in logs or statistics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::parse;
+    ///
+    /// let mut items = parse([0x05, 0x01, 0x85, 0x02, 0x15, 0x81]);
+    /// assert_eq!(items.next().unwrap().kind_name(), "Usage Page");
+    /// assert_eq!(items.next().unwrap().kind_name(), "Report ID");
+    /// assert_eq!(items.next().unwrap().kind_name(), "Logical Minimum");
+    /// ```
+    pub fn kind_name(&self) -> &'static str {
+        self.kind().name()
     }
-}
 
-struct Iter<ByteStreamIter: Iterator<Item = u8>> {
-    byte_stream_iter: ByteStreamIter,
-    usage_page: Option<UsagePage>,
-}
+    /// Format the item with the names of a [NameProvider] instead of the English
+    /// ones used by [Display].
+    pub fn display_with<'a, P: NameProvider + ?Sized>(
+        &'a self,
+        provider: &'a P,
+    ) -> DisplayWith<'a, P> {
+        DisplayWith {
+            item: self,
+            provider,
+        }
+    }
 
-struct StrictIter<ByteStreamIter: Iterator<Item = u8>> {
+    /// Create a new item of a specific kind with specific data, the prefix is picked
+    /// according to the kind.
+    ///
+    /// *NOTE*: data size must be: 0, 1, 2 or 4.
+    ///
+    /// [`Reserved`](ItemKind::Reserved) items cannot be created in this way since
+    /// they have no known prefix, [`HidError::PrefixNotMatch`] will be reported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::{ItemKind, ReportItem};
+    ///
+    /// let item = ReportItem::from_tag(ItemKind::LogicalMaximum, &[0x3c, 0x02]).unwrap();
+    /// assert_eq!(item.as_ref(), [0x26, 0x3c, 0x02]);
+    /// assert_eq!(item.kind(), ItemKind::LogicalMaximum);
+    /// assert!(ReportItem::from_tag(ItemKind::Usage, &[0, 0, 0]).is_err());
+    /// ```
+    pub fn from_tag(kind: ItemKind, data: &[u8]) -> Result<Self, HidError> {
+        Ok(match kind {
+            ItemKind::Input => ReportItem::Input(Input::new_with(data)?),
+            ItemKind::Output => ReportItem::Output(Output::new_with(data)?),
+            ItemKind::Feature => ReportItem::Feature(Feature::new_with(data)?),
+            ItemKind::Collection => ReportItem::Collection(Collection::new_with(data)?),
+            ItemKind::EndCollection => ReportItem::EndCollection(EndCollection::new_with(data)?),
+            ItemKind::UsagePage => ReportItem::UsagePage(UsagePage::new_with(data)?),
+            ItemKind::LogicalMinimum => ReportItem::LogicalMinimum(LogicalMinimum::new_with(data)?),
+            ItemKind::LogicalMaximum => ReportItem::LogicalMaximum(LogicalMaximum::new_with(data)?),
+            ItemKind::PhysicalMinimum => {
+                ReportItem::PhysicalMinimum(PhysicalMinimum::new_with(data)?)
+            }
+            ItemKind::PhysicalMaximum => {
+                ReportItem::PhysicalMaximum(PhysicalMaximum::new_with(data)?)
+            }
+            ItemKind::UnitExponent => ReportItem::UnitExponent(UnitExponent::new_with(data)?),
+            ItemKind::Unit => ReportItem::Unit(Unit::new_with(data)?),
+            ItemKind::ReportSize => ReportItem::ReportSize(ReportSize::new_with(data)?),
+            ItemKind::ReportId => ReportItem::ReportId(ReportId::new_with(data)?),
+            ItemKind::ReportCount => ReportItem::ReportCount(ReportCount::new_with(data)?),
+            ItemKind::Push => ReportItem::Push(Push::new_with(data)?),
+            ItemKind::Pop => ReportItem::Pop(Pop::new_with(data)?),
+            ItemKind::Usage => ReportItem::Usage(Usage::new_with(data)?),
+            ItemKind::UsageMinimum => ReportItem::UsageMinimum(UsageMinimum::new_with(data)?),
+            ItemKind::UsageMaximum => ReportItem::UsageMaximum(UsageMaximum::new_with(data)?),
+            ItemKind::DesignatorIndex => {
+                ReportItem::DesignatorIndex(DesignatorIndex::new_with(data)?)
+            }
+            ItemKind::DesignatorMinimum => {
+                ReportItem::DesignatorMinimum(DesignatorMinimum::new_with(data)?)
+            }
+            ItemKind::DesignatorMaximum => {
+                ReportItem::DesignatorMaximum(DesignatorMaximum::new_with(data)?)
+            }
+            ItemKind::StringIndex => ReportItem::StringIndex(StringIndex::new_with(data)?),
+            ItemKind::StringMinimum => ReportItem::StringMinimum(StringMinimum::new_with(data)?),
+            ItemKind::StringMaximum => ReportItem::StringMaximum(StringMaximum::new_with(data)?),
+            ItemKind::Delimiter => ReportItem::Delimiter(Delimiter::new_with(data)?),
+            ItemKind::Reserved => return Err(HidError::PrefixNotMatch),
+        })
+    }
+
+    /// Get prefix part of the item. Equivalent to `item.as_ref()[0]`.
+    pub fn prefix(&self) -> u8 {
+        self.as_ref()[0]
+    }
+
+    /// Get the type of the item, decoded from its prefix.
+    ///
+    /// Note that a [Reserved] item may still carry a main, global or local type.
+    pub fn item_type(&self) -> ItemType {
+        __item_type(self.prefix())
+    }
+
+    /// Classify the item by its role, built on [`item_type()`](ReportItem::item_type()).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::{parse, ItemCategory};
+    ///
+    /// let categories = parse([0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x81, 0x02, 0xC0, 0xF0])
+    ///     .map(|item| item.category())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     categories,
+    ///     [
+    ///         ItemCategory::Setup,
+    ///         ItemCategory::Setup,
+    ///         ItemCategory::Structural,
+    ///         ItemCategory::DataDefining,
+    ///         ItemCategory::Structural,
+    ///         ItemCategory::Reserved,
+    ///     ]
+    /// );
+    /// ```
+    pub fn category(&self) -> ItemCategory {
+        match (self, self.item_type()) {
+            (ReportItem::Reserved(_), _) | (_, ItemType::Reserved) => ItemCategory::Reserved,
+            (ReportItem::Collection(_) | ReportItem::EndCollection(_), _) => {
+                ItemCategory::Structural
+            }
+            (_, ItemType::Main) => ItemCategory::DataDefining,
+            (_, ItemType::Global | ItemType::Local) => ItemCategory::Setup,
+        }
+    }
+
+    /// Check whether the item is a global or local item configuring the parser state.
+    pub fn is_setup(&self) -> bool {
+        self.category() == ItemCategory::Setup
+    }
+
+    /// Check whether the item is an [Input], [Output] or [Feature] item.
+    pub fn is_data_defining(&self) -> bool {
+        self.category() == ItemCategory::DataDefining
+    }
+
+    /// Get the tag of the item, i.e. bits 4-7 of the prefix, which tells items of
+    /// the same [type](ReportItem::item_type()) apart.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::ReportItem;
+    ///
+    /// let item = ReportItem::new(&[0x75, 0x08]).unwrap();
+    /// assert_eq!(item.tag(), 0b0111);
+    /// ```
+    pub fn tag(&self) -> u8 {
+        (self.prefix() & 0b1111_0000) >> 4
+    }
+
+    /// Decompose the prefix into its tag, type and data size in bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::{ItemType, ReportItem};
+    ///
+    /// let item = ReportItem::new(&[0x26, 0x3C, 0x02]).unwrap();
+    /// assert_eq!(item.prefix_parts(), (0b0010, ItemType::Global, 2));
+    ///
+    /// let item = ReportItem::new(&[0xC0]).unwrap();
+    /// assert_eq!(item.prefix_parts(), (0b1100, ItemType::Main, 0));
+    /// ```
+    pub fn prefix_parts(&self) -> (u8, ItemType, usize) {
+        let prefix = self.prefix();
+        (self.tag(), self.item_type(), __data_size(prefix))
+    }
+
+    /// Get data part of the item. Equivalent to `&item.as_ref()[1..]`.
+    pub fn data(&self) -> &[u8] {
+        &self.as_ref()[1..]
+    }
+
+    /// Set data part of the item, keeping its kind.
+    ///
+    /// *NOTE*: data size must be: 0, 1, 2 or 4.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::{HidError, ReportItem};
+    ///
+    /// let mut item = ReportItem::new(&[0x95, 0x01]).unwrap();
+    /// item.set_data(&[0x00, 0x01]).unwrap();
+    /// assert_eq!(item.as_ref(), [0x96, 0x00, 0x01]);
+    /// assert_eq!(item.to_string(), "Report Count (256)");
+    /// assert_eq!(item.set_data(&[0x00; 3]), Err(HidError::InvalidDataSize));
+    /// ```
+    pub fn set_data(&mut self, data: &[u8]) -> Result<&mut Self, HidError> {
+        match self {
+            ReportItem::Input(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::Output(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::Feature(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::Collection(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::EndCollection(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::UsagePage(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::LogicalMinimum(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::LogicalMaximum(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::PhysicalMinimum(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::PhysicalMaximum(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::UnitExponent(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::Unit(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::ReportSize(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::ReportId(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::ReportCount(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::Push(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::Pop(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::Usage(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::UsageMinimum(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::UsageMaximum(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::DesignatorIndex(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::DesignatorMinimum(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::DesignatorMaximum(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::StringIndex(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::StringMinimum(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::StringMaximum(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::Delimiter(inner) => {
+                inner.set_data(data)?;
+            }
+            ReportItem::Reserved(inner) => {
+                inner.set_data(data)?;
+            }
+        }
+        Ok(self)
+    }
+
+    /// Get mutable data part of the item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::ReportItem;
+    ///
+    /// let mut item = ReportItem::new(&[0x75, 0x08]).unwrap();
+    /// item.data_mut()[0] = 0x10;
+    /// assert_eq!(item.to_string(), "Report Size (16)");
+    /// ```
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        match self {
+            ReportItem::Input(inner) => inner.data_mut(),
+            ReportItem::Output(inner) => inner.data_mut(),
+            ReportItem::Feature(inner) => inner.data_mut(),
+            ReportItem::Collection(inner) => inner.data_mut(),
+            ReportItem::EndCollection(inner) => inner.data_mut(),
+            ReportItem::UsagePage(inner) => inner.data_mut(),
+            ReportItem::LogicalMinimum(inner) => inner.data_mut(),
+            ReportItem::LogicalMaximum(inner) => inner.data_mut(),
+            ReportItem::PhysicalMinimum(inner) => inner.data_mut(),
+            ReportItem::PhysicalMaximum(inner) => inner.data_mut(),
+            ReportItem::UnitExponent(inner) => inner.data_mut(),
+            ReportItem::Unit(inner) => inner.data_mut(),
+            ReportItem::ReportSize(inner) => inner.data_mut(),
+            ReportItem::ReportId(inner) => inner.data_mut(),
+            ReportItem::ReportCount(inner) => inner.data_mut(),
+            ReportItem::Push(inner) => inner.data_mut(),
+            ReportItem::Pop(inner) => inner.data_mut(),
+            ReportItem::Usage(inner) => inner.data_mut(),
+            ReportItem::UsageMinimum(inner) => inner.data_mut(),
+            ReportItem::UsageMaximum(inner) => inner.data_mut(),
+            ReportItem::DesignatorIndex(inner) => inner.data_mut(),
+            ReportItem::DesignatorMinimum(inner) => inner.data_mut(),
+            ReportItem::DesignatorMaximum(inner) => inner.data_mut(),
+            ReportItem::StringIndex(inner) => inner.data_mut(),
+            ReportItem::StringMinimum(inner) => inner.data_mut(),
+            ReportItem::StringMaximum(inner) => inner.data_mut(),
+            ReportItem::Delimiter(inner) => inner.data_mut(),
+            ReportItem::Reserved(inner) => inner.data_mut(),
+        }
+    }
+
+    /// Copy the exact encoding of the item into an owned array, without allocation.
+    ///
+    /// Returns the array and the length of the encoding: only the first `len` bytes
+    /// are meaningful (prefix followed by 0, 1, 2 or 4 data bytes), the rest are zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::ReportItem;
+    ///
+    /// let item = ReportItem::new(&[0x26, 0x3c, 0x02]).unwrap();
+    /// let (bytes, len) = item.to_bytes();
+    /// assert_eq!(len, 3);
+    /// assert_eq!(bytes, [0x26, 0x3c, 0x02, 0x00, 0x00]);
+    /// assert_eq!(&bytes[..len], item.as_ref());
+    /// ```
+    pub fn to_bytes(&self) -> ([u8; 5], usize) {
+        let raw = self.as_ref();
+        let mut bytes = [0; 5];
+        bytes[..raw.len()].copy_from_slice(raw);
+        (bytes, raw.len())
+    }
+}
+
+struct Iter<ByteStreamIter: Iterator<Item = u8>> {
+    byte_stream_iter: ByteStreamIter,
+    state: GlobalState,
+}
+
+struct StrictIter<ByteStreamIter: Iterator<Item = u8>> {
     byte_stream_iter: ByteStreamIter,
-    usage_page: Option<UsagePage>,
+    state: GlobalState,
+}
+
+struct TryIter<ByteStreamIter> {
+    byte_stream_iter: ByteStreamIter,
+    state: GlobalState,
 }
 
 impl<ByteStreamIter: Iterator<Item = u8>> Iterator for Iter<ByteStreamIter> {
@@ -539,24 +1372,22 @@ impl<ByteStreamIter: Iterator<Item = u8>> Iterator for Iter<ByteStreamIter> {
         for i in 0..size {
             storage[i + 1] = self.byte_stream_iter.next()?;
         }
-        let mut item = unsafe { ReportItem::new_unchecked(&storage) };
-        if let ReportItem::UsagePage(usage_page) = &item {
-            self.usage_page = Some(usage_page.clone());
-        }
-        if let Some(usage_page) = &self.usage_page {
-            match &mut item {
-                ReportItem::Usage(usage) => usage.set_usage_page(usage_page.clone()),
-                ReportItem::UsageMinimum(usage_minimum) => {
-                    usage_minimum.set_usage_page(usage_page.clone())
-                }
-                ReportItem::UsageMaximum(usage_maximum) => {
-                    usage_maximum.set_usage_page(usage_page.clone())
-                }
-                _ => (),
-            }
+        if prefix == Reserved::LONG_ITEM_PREFIX {
+            __skip_long_item_data(&mut self.byte_stream_iter, storage[1])?;
         }
+        let mut item = ReportItem::new_unchecked(&storage);
+        self.state.update(&item);
+        __tag_usage_page(&mut item, self.state.usage_page.as_ref());
         Some(item)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Short items take 1 to 5 bytes and long items up to 258 bytes, and a
+        // truncated trailing item is dropped, so the bytes left may yield no
+        // item at all.
+        let (_, upper) = self.byte_stream_iter.size_hint();
+        (0, upper)
+    }
 }
 
 impl<ByteStreamIter: Iterator<Item = u8>> Iterator for StrictIter<ByteStreamIter> {
@@ -569,23 +1400,113 @@ impl<ByteStreamIter: Iterator<Item = u8>> Iterator for StrictIter<ByteStreamIter
         for i in 0..size {
             storage[i + 1] = self.byte_stream_iter.next()?;
         }
-        let mut item = unsafe { ReportItem::new_strict_unchecked(&storage) };
-        if let Ok(ReportItem::UsagePage(usage_page)) = &item {
-            self.usage_page = Some(usage_page.clone());
+        if prefix == Reserved::LONG_ITEM_PREFIX {
+            __skip_long_item_data(&mut self.byte_stream_iter, storage[1])?;
+            return Some(Err(HidError::LongItem {
+                data_size: storage[1],
+                tag: storage[2],
+            }));
         }
-        if let Some(usage_page) = &self.usage_page {
-            match &mut item {
-                Ok(ReportItem::Usage(usage)) => usage.set_usage_page(usage_page.clone()),
-                Ok(ReportItem::UsageMinimum(usage_minimum)) => {
-                    usage_minimum.set_usage_page(usage_page.clone())
-                }
-                Ok(ReportItem::UsageMaximum(usage_maximum)) => {
-                    usage_maximum.set_usage_page(usage_page.clone())
+        let mut item = ReportItem::new_strict_unchecked(&storage);
+        if let Ok(item) = &item {
+            self.state.update(item);
+        }
+        if let Ok(item) = &mut item {
+            __tag_usage_page(item, self.state.usage_page.as_ref());
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Short items take 1 to 5 bytes and long items up to 258 bytes, and a
+        // truncated trailing item is dropped, so the bytes left may yield no
+        // item at all.
+        let (_, upper) = self.byte_stream_iter.size_hint();
+        (0, upper)
+    }
+}
+
+impl<E, ByteStreamIter: Iterator<Item = Result<u8, E>>> Iterator for TryIter<ByteStreamIter> {
+    type Item = Result<ReportItem, ParseError<E>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let prefix = match self.byte_stream_iter.next()? {
+            Ok(prefix) => prefix,
+            Err(error) => return Some(Err(ParseError::Source(error))),
+        };
+        let size = __data_size(prefix);
+        let mut storage = [0u8; 5];
+        storage[0] = prefix;
+        for i in 0..size {
+            storage[i + 1] = match self.byte_stream_iter.next()? {
+                Ok(byte) => byte,
+                Err(error) => return Some(Err(ParseError::Source(error))),
+            };
+        }
+        if prefix == Reserved::LONG_ITEM_PREFIX {
+            for _ in 0..storage[1] {
+                if let Err(error) = self.byte_stream_iter.next()? {
+                    return Some(Err(ParseError::Source(error)));
                 }
-                _ => (),
             }
+            return Some(Err(HidError::LongItem {
+                data_size: storage[1],
+                tag: storage[2],
+            }
+            .into()));
         }
-        Some(item)
+        let mut item = match ReportItem::new_strict_unchecked(&storage) {
+            Ok(item) => item,
+            Err(error) => return Some(Err(error.into())),
+        };
+        self.state.update(&item);
+        __tag_usage_page(&mut item, self.state.usage_page.as_ref());
+        Some(Ok(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Short items take 1 to 5 bytes and long items up to 258 bytes, and a
+        // truncated trailing item is dropped, so the bytes left may yield no
+        // item at all.
+        let (_, upper) = self.byte_stream_iter.size_hint();
+        (0, upper)
+    }
+}
+
+/// Tag usages with the current usage page. Usages with 4 data bytes embed their
+/// own usage page, so they are tagged even if there is no current one.
+pub(crate) fn __tag_usage_page(item: &mut ReportItem, usage_page: Option<&UsagePage>) {
+    let usage_page = match (usage_page, item.data()) {
+        (Some(usage_page), _) => usage_page.clone(),
+        (None, data @ [_, _, _, _]) => UsagePage::from_value(__data_to_unsigned(data) >> 16),
+        (None, _) => return,
+    };
+    match item {
+        ReportItem::Usage(usage) => usage.set_usage_page(usage_page),
+        ReportItem::UsageMinimum(usage_minimum) => usage_minimum.set_usage_page(usage_page),
+        ReportItem::UsageMaximum(usage_maximum) => usage_maximum.set_usage_page(usage_page),
+        _ => (),
+    }
+}
+
+/// Skip the data of a long item, `None` if the stream ends before it.
+fn __skip_long_item_data(
+    byte_stream_iter: &mut impl Iterator<Item = u8>,
+    data_size: u8,
+) -> Option<()> {
+    for _ in 0..data_size {
+        byte_stream_iter.next()?;
+    }
+    Some(())
+}
+
+/// Get the number of bytes an item was parsed from, counting the skipped data of
+/// long items.
+fn __parsed_len(item: &ReportItem) -> usize {
+    match item {
+        ReportItem::Reserved(reserved) => reserved
+            .long_item()
+            .map_or(item.as_ref().len(), |(data_size, _)| 3 + data_size as usize),
+        _ => item.as_ref().len(),
     }
 }
 
@@ -622,29 +1543,439 @@ impl<ByteStreamIter: Iterator<Item = u8>> Iterator for StrictIter<ByteStreamIter
 /// assert_eq!(items.next().unwrap().to_string(), "End Collection");
 /// assert_eq!(items.next(), None);
 /// ```
+///
+/// A trailing item truncated by the end of the byte stream is dropped, so dumping
+/// the parsed items always gives back a prefix of the input, unless it contains
+/// [long items](Reserved#long-items) whose data is skipped:
+///
+/// ```
+/// use hid_report::{parse, Reserved};
+///
+/// // A seeded xorshift keeps the random input deterministic.
+/// let mut state = 0x2545_F491_u32;
+/// let mut random = move || {
+///     state ^= state << 13;
+///     state ^= state >> 17;
+///     state ^= state << 5;
+///     state
+/// };
+/// for _ in 0..1000 {
+///     let len = random() as usize % 64;
+///     let bytes = (0..len).map(|_| random() as u8).collect::<Vec<_>>();
+///     let items = parse(bytes.iter().copied()).collect::<Vec<_>>();
+///     if items.iter().any(|item| item.prefix() == Reserved::LONG_ITEM_PREFIX) {
+///         continue;
+///     }
+///     let dumped = items.iter().flat_map(|item| item.as_ref().to_vec()).collect::<Vec<_>>();
+///     assert!(bytes.starts_with(&dumped));
+///     let rest = &bytes[dumped.len()..];
+///     let item_len = match rest {
+///         [] => continue,
+///         [Reserved::LONG_ITEM_PREFIX, data_size, ..] => 3 + *data_size as usize,
+///         [prefix, ..] => [1, 2, 3, 5][(prefix & 0b11) as usize],
+///     };
+///     assert!(rest.len() < item_len);
+/// }
+/// ```
 pub fn parse<ByteStream: IntoIterator<Item = u8>>(
     byte_stream: ByteStream,
 ) -> impl Iterator<Item = ReportItem> {
     Iter {
         byte_stream_iter: byte_stream.into_iter(),
-        usage_page: None,
+        state: GlobalState::new(),
+    }
+}
+
+/// Parse a byte slice into a report item iterator.
+///
+/// Equivalent to `parse(bytes.iter().copied())`, for descriptors that are only
+/// available by reference.
+///
+/// Like every parser, its [`size_hint()`](Iterator::size_hint()) bounds the item
+/// count by the byte count, so collecting can pre-allocate.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::parse_slice;
+///
+/// let descriptor = vec![0x05, 0x0C, 0x09, 0x01];
+/// let mut items = parse_slice(&descriptor);
+/// assert_eq!(items.size_hint(), (0, Some(4)));
+/// assert_eq!(items.next().unwrap().to_string(), "Usage Page (Consumer)");
+/// assert_eq!(items.next().unwrap().to_string(), "Usage (Consumer Control)");
+/// assert_eq!(items.next(), None);
+/// ```
+pub fn parse_slice(bytes: &[u8]) -> impl Iterator<Item = ReportItem> + '_ {
+    parse(bytes.iter().copied())
+}
+
+/// Parse a byte slice into a report item iterator, pairing each item with the
+/// bytes it was parsed from, e.g. for an annotated hex viewer.
+///
+/// The byte slices borrow from `bytes` without copying, so they live as long as
+/// the input buffer rather than the iterator. A trailing item truncated by the end
+/// of the buffer is dropped, like [`parse()`] does.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::parse_with_bytes;
+///
+/// let descriptor = [0x05, 0x0C, 0x0A, 0x3C, 0x02, 0xC0];
+/// let items = parse_with_bytes(&descriptor).collect::<Vec<_>>();
+/// assert_eq!(items.len(), 3);
+/// assert_eq!(items[1].0, [0x0A, 0x3C, 0x02]);
+/// assert_eq!(items[1].1.to_string(), "Usage (AC Format)");
+/// assert!(items.iter().all(|(raw, item)| *raw == item.as_ref()));
+/// ```
+pub fn parse_with_bytes(bytes: &[u8]) -> impl Iterator<Item = (&[u8], ReportItem)> {
+    parse_slice(bytes).scan(0, move |offset, item| {
+        let start = *offset;
+        *offset += __parsed_len(&item);
+        Some((&bytes[start..*offset], item))
+    })
+}
+
+/// Parse a byte slice into a [Vec] of report items.
+///
+/// Equivalent to `parse_slice(bytes).collect::<Vec<_>>()`.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::parse_all;
+///
+/// let items = parse_all(&[0x05, 0x0C, 0x09, 0x01]);
+/// assert_eq!(items.len(), 2);
+/// assert_eq!(items[1].to_string(), "Usage (Consumer Control)");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parse_all(bytes: &[u8]) -> Vec<ReportItem> {
+    parse_slice(bytes).collect()
+}
+
+/// Parse a byte slice into a [Vec] of report items paired with their byte offsets,
+/// for random access and backward scanning.
+///
+/// Items have variable length, so parsing itself can only go forward; index the
+/// returned [Vec] to go back, see also [`preceding_usage_page()`].
+///
+/// # Example
+///
+/// ```
+/// use hid_report::parse_indexed_vec;
+///
+/// let items = parse_indexed_vec(&[0x05, 0x0C, 0x0A, 0x3C, 0x02, 0xC0]);
+/// let offsets = items.iter().rev().map(|(offset, _)| *offset).collect::<Vec<_>>();
+/// assert_eq!(offsets, [5, 2, 0]);
+/// assert_eq!(items[1].1.to_string(), "Usage (AC Format)");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parse_indexed_vec(bytes: &[u8]) -> Vec<(usize, ReportItem)> {
+    parse_slice(bytes)
+        .scan(0, |offset, item| {
+            let start = *offset;
+            *offset += __parsed_len(&item);
+            Some((start, item))
+        })
+        .collect()
+}
+
+/// Parse a byte slice like [`parse_all()`], while collecting the problems that
+/// [`parse_strict()`] would stop at, each with the byte offset of its item.
+///
+/// Unknown items are kept as [`Reserved`](ReportItem::Reserved) and reported as
+/// [`HidError::ReservedItem`], [long items](Reserved#long-items) as
+/// [`HidError::LongItem`], and a trailing item truncated by the end of the slice as
+/// [`HidError::DataSizeNotMatch`].
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse_collecting, HidError, Reserved};
+///
+/// let bytes = [0x05, 0x0C, 0xF0, 0xFE, 0x01, 0x00, 0xAA, 0xC0, 0x26, 0x3C];
+/// let (items, errors) = parse_collecting(&bytes);
+/// assert_eq!(items.len(), 4);
+/// assert_eq!(
+///     errors,
+///     [
+///         (2, HidError::ReservedItem(Reserved::new(&[0xF0]).unwrap())),
+///         (3, HidError::LongItem { data_size: 1, tag: 0x00 }),
+///         (8, HidError::DataSizeNotMatch { expected: 2, provided: 1 }),
+///     ]
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parse_collecting(bytes: &[u8]) -> (Vec<ReportItem>, Vec<(usize, HidError)>) {
+    let mut items = Vec::new();
+    let mut errors = Vec::new();
+    let mut end = 0;
+    for (offset, item) in parse_indexed_vec(bytes) {
+        end = offset + __parsed_len(&item);
+        if let ReportItem::Reserved(reserved) = &item {
+            errors.push((
+                offset,
+                match reserved.long_item() {
+                    Some((data_size, tag)) => HidError::LongItem { data_size, tag },
+                    None => HidError::ReservedItem(reserved.clone()),
+                },
+            ));
+        }
+        items.push(item);
+    }
+    if let Some(&prefix) = bytes.get(end) {
+        let expected = match prefix {
+            Reserved::LONG_ITEM_PREFIX if bytes.len() > end + 1 => 2 + bytes[end + 1] as usize,
+            _ => __data_size(prefix),
+        };
+        errors.push((
+            end,
+            HidError::DataSizeNotMatch {
+                expected,
+                provided: bytes.len() - end - 1,
+            },
+        ));
     }
+    (items, errors)
+}
+
+/// Parse a byte stream into a report item iterator that yields at most `max_items`
+/// items, to bound the work spent on untrusted descriptors.
+///
+/// Like [`parse()`], bytes are only consumed as the iterator advances. If the byte
+/// stream holds more items, [`HidError::ItemLimitExceeded`] is yielded once in place
+/// of the first excess item, and the iterator ends; callers who only want to stop
+/// can simply discard it.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse_limited, HidError};
+///
+/// let bytes = [0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0xC0];
+/// let mut items = parse_limited(bytes, 2);
+/// assert_eq!(items.next().unwrap().unwrap().to_string(), "Usage Page (Consumer)");
+/// assert_eq!(items.next().unwrap().unwrap().to_string(), "Usage (Consumer Control)");
+/// assert_eq!(items.next(), Some(Err(HidError::ItemLimitExceeded)));
+/// assert_eq!(items.next(), None);
+///
+/// assert_eq!(parse_limited(bytes, 4).filter(Result::is_err).count(), 0);
+/// ```
+pub fn parse_limited<ByteStream: IntoIterator<Item = u8>>(
+    byte_stream: ByteStream,
+    max_items: usize,
+) -> impl Iterator<Item = Result<ReportItem, HidError>> {
+    let mut items = parse(byte_stream);
+    let mut count = 0;
+    std::iter::from_fn(move || {
+        if count > max_items {
+            return None;
+        }
+        let item = items.next()?;
+        count += 1;
+        if count > max_items {
+            Some(Err(HidError::ItemLimitExceeded))
+        } else {
+            Some(Ok(item))
+        }
+    })
+}
+
+/// Parse a byte stream into a report item iterator, resolving usage names with
+/// a [UsageResolver] before falling back to the built-in usage tables.
+///
+/// This lets descriptors with vendor defined pages render meaningful names.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse_with_resolver, UsageResolver};
+///
+/// struct Vendor;
+///
+/// impl UsageResolver for Vendor {
+///     fn name(&self, usage_page: u32, usage: u32) -> Option<String> {
+///         match (usage_page, usage) {
+///             (0xFF00, 0x01) => Some("Vendor Control".to_string()),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let bytes = [0x06, 0x00, 0xFF, 0x09, 0x01, 0x09, 0x02];
+/// let mut items = parse_with_resolver(bytes, &Vendor);
+/// assert_eq!(items.next().unwrap().to_string(), "Usage Page (Vendor Defined)");
+/// assert_eq!(items.next().unwrap().to_string(), "Usage (Vendor Control)");
+/// assert_eq!(items.next().unwrap().to_string(), "Usage");
+/// assert_eq!(items.next(), None);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parse_with_resolver<'r, ByteStream: IntoIterator<Item = u8> + 'r>(
+    byte_stream: ByteStream,
+    resolver: &'r dyn UsageResolver,
+) -> impl Iterator<Item = ReportItem> + 'r {
+    parse(byte_stream).map(move |mut item| {
+        match &mut item {
+            ReportItem::Usage(usage) => usage.resolve_with(resolver),
+            ReportItem::UsageMinimum(usage_minimum) => usage_minimum.resolve_with(resolver),
+            ReportItem::UsageMaximum(usage_maximum) => usage_maximum.resolve_with(resolver),
+            _ => (),
+        }
+        item
+    })
+}
+
+/// Parse the textual output of `usbhid-dump` into a report item iterator.
+///
+/// Only whitespace separated tokens of exactly two hex digits are taken as
+/// descriptor bytes, so header lines, offset columns (e.g. `0010:`) and line
+/// wrapping are skipped.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::parse_usbhid_dump;
+///
+/// let text = "\
+/// 001:004:000:DESCRIPTOR         1700000000.123456
+///  05 0C 09 01
+///  A1 01 C0
+/// ";
+/// let mut items = parse_usbhid_dump(text);
+/// assert_eq!(items.next().unwrap().to_string(), "Usage Page (Consumer)");
+/// assert_eq!(items.next().unwrap().to_string(), "Usage (Consumer Control)");
+/// assert_eq!(items.next().unwrap().to_string(), "Collection (Application)");
+/// assert_eq!(items.next().unwrap().to_string(), "End Collection");
+/// assert_eq!(items.next(), None);
+/// ```
+pub fn parse_usbhid_dump(text: &str) -> impl Iterator<Item = ReportItem> + '_ {
+    parse(text.split_whitespace().filter_map(|token| {
+        if token.len() == 2 && token.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            u8::from_str_radix(token, 16).ok()
+        } else {
+            None
+        }
+    }))
 }
 
 /// Parse a byte stream into a report item iterator in strict mode.
 ///
-/// Items that cannot be recognized will be treated as [`HidError::ReservedItem`].
-/// Also, this is the only error that may be reported.
+/// [Long items](Reserved#long-items) will be treated as [`HidError::LongItem`],
+/// and other items that cannot be recognized as [`HidError::ReservedItem`].
+/// Also, these are the only errors that may be reported.
 pub fn parse_strict<ByteStream: IntoIterator<Item = u8>>(
     byte_stream: ByteStream,
 ) -> impl Iterator<Item = Result<ReportItem, HidError>> {
     StrictIter {
         byte_stream_iter: byte_stream.into_iter(),
-        usage_page: None,
+        state: GlobalState::new(),
+    }
+}
+
+/// Parse a byte slice into a report item iterator in strict mode.
+///
+/// Equivalent to `parse_strict(bytes.iter().copied())`, see [`parse_strict()`].
+pub fn parse_strict_slice(bytes: &[u8]) -> impl Iterator<Item = Result<ReportItem, HidError>> + '_ {
+    parse_strict(bytes.iter().copied())
+}
+
+/// Parse a byte slice into a [Vec] of report items in strict mode.
+///
+/// Stops at the first error, see [`parse_strict()`].
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse_all_strict, HidError};
+///
+/// let items = parse_all_strict(&[0x05, 0x0C, 0x09, 0x01]).unwrap();
+/// assert_eq!(items.len(), 2);
+///
+/// let error = parse_all_strict(&[0x05, 0x0C, 0xF0, 0x09, 0x01]).unwrap_err();
+/// assert!(matches!(error, HidError::ReservedItem(_)));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parse_all_strict(bytes: &[u8]) -> Result<Vec<ReportItem>, HidError> {
+    parse_strict_slice(bytes).collect()
+}
+
+/// Parse a fallible byte stream (e.g. bytes read from I/O) into a report item
+/// iterator in strict mode.
+///
+/// Errors of the byte source are reported as [`ParseError::Source`], while items
+/// that cannot be recognized are reported as [`ParseError::Hid`], see [`parse_strict()`].
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse_try, HidError, ParseError};
+///
+/// let bytes = [Ok(0x05), Ok(0x0C), Ok(0xF0), Err("disconnected")];
+/// let mut items = parse_try(bytes);
+/// assert_eq!(items.next().unwrap().unwrap().to_string(), "Usage Page (Consumer)");
+/// assert!(matches!(items.next(), Some(Err(ParseError::Hid(HidError::ReservedItem(_))))));
+/// assert_eq!(items.next(), Some(Err(ParseError::Source("disconnected"))));
+/// assert_eq!(items.next(), None);
+/// ```
+pub fn parse_try<E, ByteStream: IntoIterator<Item = Result<u8, E>>>(
+    byte_stream: ByteStream,
+) -> impl Iterator<Item = Result<ReportItem, ParseError<E>>> {
+    TryIter {
+        byte_stream_iter: byte_stream.into_iter(),
+        state: GlobalState::new(),
     }
 }
 
 /// Dump items into a byte stream.
+///
+/// # Example
+///
+/// Items round-trip through [`dump()`] and [`parse()`], including data-less items
+/// created by [Default], which are formatted as their bare names.
+///
+/// ```
+/// use hid_report::*;
+///
+/// let defaults = [
+///     (ReportItem::Input(Input::default()), "Input"),
+///     (ReportItem::Output(Output::default()), "Output"),
+///     (ReportItem::Feature(Feature::default()), "Feature"),
+///     (ReportItem::Collection(Collection::default()), "Collection"),
+///     (ReportItem::EndCollection(EndCollection::default()), "End Collection"),
+///     (ReportItem::UsagePage(UsagePage::default()), "Usage Page"),
+///     (ReportItem::LogicalMinimum(LogicalMinimum::default()), "Logical Minimum"),
+///     (ReportItem::LogicalMaximum(LogicalMaximum::default()), "Logical Maximum"),
+///     (ReportItem::PhysicalMinimum(PhysicalMinimum::default()), "Physical Minimum"),
+///     (ReportItem::PhysicalMaximum(PhysicalMaximum::default()), "Physical Maximum"),
+///     (ReportItem::UnitExponent(UnitExponent::default()), "Unit Exponent"),
+///     (ReportItem::Unit(Unit::default()), "Unit"),
+///     (ReportItem::ReportSize(ReportSize::default()), "Report Size"),
+///     (ReportItem::ReportId(ReportId::default()), "Report ID"),
+///     (ReportItem::ReportCount(ReportCount::default()), "Report Count"),
+///     (ReportItem::Push(Push::default()), "Push"),
+///     (ReportItem::Pop(Pop::default()), "Pop"),
+///     (ReportItem::Usage(Usage::default()), "Usage"),
+///     (ReportItem::UsageMinimum(UsageMinimum::default()), "Usage Minimum"),
+///     (ReportItem::UsageMaximum(UsageMaximum::default()), "Usage Maximum"),
+///     (ReportItem::DesignatorIndex(DesignatorIndex::default()), "Designator Index"),
+///     (ReportItem::DesignatorMinimum(DesignatorMinimum::default()), "Designator Minimum"),
+///     (ReportItem::DesignatorMaximum(DesignatorMaximum::default()), "Designator Maximum"),
+///     (ReportItem::StringIndex(StringIndex::default()), "String Index"),
+///     (ReportItem::StringMinimum(StringMinimum::default()), "String Minimum"),
+///     (ReportItem::StringMaximum(StringMaximum::default()), "String Maximum"),
+///     (ReportItem::Delimiter(Delimiter::default()), "Delimiter"),
+/// ];
+/// for (item, name) in defaults {
+///     assert!(item.data().is_empty());
+///     assert_eq!(item.to_string(), name);
+///     let bytes = dump([&item]);
+///     assert_eq!(bytes, [item.prefix()]);
+///     assert_eq!(parse(bytes).collect::<Vec<_>>(), [item]);
+/// }
+/// ```
+#[cfg(feature = "alloc")]
 pub fn dump<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
     item_stream: ItemStream,
 ) -> Vec<u8> {
@@ -655,63 +1986,181 @@ pub fn dump<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
     v
 }
 
-/// Print items to string in a pretty way.
+/// Dump items into a fixed-capacity buffer without allocation, e.g. a static
+/// buffer in firmware, and return the number of bytes written.
+///
+/// The buffer is left untouched if it can't hold all items, see [`byte_len()`].
 ///
 /// # Example
 ///
 /// ```
-/// use hid_report::{parse, pretty_print};
+/// use hid_report::{dump_into, parse, HidError};
+///
+/// let items = parse([0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0xC0]).collect::<Vec<_>>();
+/// let mut buffer = [0; 8];
+/// assert_eq!(dump_into(&items, &mut buffer), Ok(7));
+/// assert_eq!(buffer[..7], [0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0xC0]);
+///
+/// let mut buffer = [0; 4];
+/// assert_eq!(
+///     dump_into(&items, &mut buffer),
+///     Err(HidError::BufferTooSmall { required: 7, provided: 4 })
+/// );
+/// assert_eq!(buffer, [0; 4]);
+/// ```
+pub fn dump_into<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+    item_stream: ItemStream,
+    buffer: &mut [u8],
+) -> Result<usize, HidError>
+where
+    ItemStream::IntoIter: Clone,
+{
+    let items = item_stream.into_iter();
+    let required = byte_len(items.clone());
+    if required > buffer.len() {
+        return Err(HidError::BufferTooSmall {
+            required,
+            provided: buffer.len(),
+        });
+    }
+    let mut offset = 0;
+    for item in items {
+        let raw = item.as_ref();
+        buffer[offset..offset + raw.len()].copy_from_slice(raw);
+        offset += raw.len();
+    }
+    Ok(offset)
+}
+
+/// Get the byte length of items as dumped, without allocation.
+///
+/// This is the value firmware must report as `wDescriptorLength` of the report
+/// descriptor in its HID descriptor.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{byte_len, parse};
+///
+/// let items = parse([0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0xC0]).collect::<Vec<_>>();
+/// assert_eq!(byte_len(&items), 7);
+/// #[cfg(feature = "alloc")]
+/// assert_eq!(byte_len(&items), hid_report::dump(&items).len());
+/// ```
+pub fn byte_len<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+    item_stream: ItemStream,
+) -> usize {
+    item_stream
+        .into_iter()
+        .map(|item| item.as_ref().len())
+        .sum()
+}
+
+/// Re-encode every numeric item with the smallest valid data size, preserving values.
+///
+/// [Logical Minimum](LogicalMinimum), [Logical Maximum](LogicalMaximum),
+/// [Physical Minimum](PhysicalMinimum) and [Physical Maximum](PhysicalMaximum)
+/// are treated as signed, other numeric items as unsigned. Items without data,
+/// 4-byte usages (whose high 16 bits are the usage page) and non-numeric items
+/// are kept as is.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{dump, minimize, parse};
 ///
 /// let bytes = [
-///     0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0x85, 0x02, 0x19,
-///     0x00, 0x2A, 0x3C, 0x02, 0x15, 0x00, 0x26, 0x3C, 0x02,
-///     0x95, 0x01, 0x75, 0x10, 0x81, 0x00, 0xC0,
+///     0x07, 0x0C, 0x00, 0x00, 0x00, 0x0A, 0x01, 0x00, 0xA1, 0x01, 0x17,
+///     0xFF, 0xFF, 0xFF, 0xFF, 0x27, 0x3C, 0x02, 0x00, 0x00, 0x96, 0x01,
+///     0x00, 0x75, 0x10, 0x81, 0x00, 0xC0,
 /// ];
 /// let items = parse(bytes).collect::<Vec<_>>();
-///
-/// const EXPECTED: &str = indoc::indoc! {"
-///     0x05, 0x0C        // Usage Page (Consumer)
-///     0x09, 0x01        // Usage (Consumer Control)
-///     0xA1, 0x01        //   Collection (Application)
-///     0x85, 0x02        //   Report ID (2)
-///     0x19, 0x00        //   Usage Minimum (Undefined)
-///     0x2A, 0x3C, 0x02  //   Usage Maximum (AC Format)
-///     0x15, 0x00        //   Logical Minimum (0)
-///     0x26, 0x3C, 0x02  //   Logical Maximum (572)
-///     0x95, 0x01        //   Report Count (1)
-///     0x75, 0x10        //   Report Size (16)
-///     0x81, 0x00        //   Input (Data, Array, Absolute, No Wrap, Linear, Preferred State, No Null Position)
-///     0xC0              // End Collection"
-/// };
-///
-/// assert_eq!(pretty_print(&items), EXPECTED);
+/// let minimized = minimize(&items);
+/// assert_eq!(
+///     dump(&minimized),
+///     [
+///         0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0x15, 0xFF, 0x26, 0x3C, 0x02,
+///         0x95, 0x01, 0x75, 0x10, 0x81, 0x00, 0xC0,
+///     ]
+/// );
+/// assert!(dump(&minimized).len() < bytes.len());
 /// ```
-pub fn pretty_print<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+#[cfg(feature = "alloc")]
+pub fn minimize<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
     item_stream: ItemStream,
-) -> String {
-    let mut max_len = 0;
-    let mut tmp = Vec::new();
-    let mut tab: usize = 0;
-    for item in item_stream {
-        match item {
-            ReportItem::Collection(_) | ReportItem::Push(_) => tab += 1,
-            ReportItem::EndCollection(_) | ReportItem::Pop(_) => tab = tab.saturating_sub(1),
-            _ => (),
-        }
-        max_len = std::cmp::max(max_len, item.as_ref().len());
-        tmp.push((
-            item.as_ref()
-                .iter()
-                .map(|byte| format!("{:#04X}", byte))
-                .collect::<Vec<_>>()
-                .join(", "),
-            item.to_string(),
-            tab * 2 + 1,
-        ));
-    }
-    let width_of_raw = max_len * 6;
-    tmp.into_iter()
-        .map(|(raw, comment, tab)| format!("{:<width_of_raw$}//{:<tab$}{}", raw, ' ', comment))
-        .collect::<Vec<_>>()
-        .join("\n")
+) -> Vec<ReportItem> {
+    item_stream
+        .into_iter()
+        .map(|item| {
+            if item.data().is_empty() {
+                return item.clone();
+            }
+            let signed = __data_to_signed(item.data());
+            let unsigned = __data_to_unsigned(item.data());
+            match item {
+                ReportItem::LogicalMinimum(_) => {
+                    ReportItem::LogicalMinimum(LogicalMinimum::from_value(signed))
+                }
+                ReportItem::LogicalMaximum(_) => {
+                    ReportItem::LogicalMaximum(LogicalMaximum::from_value(signed))
+                }
+                ReportItem::PhysicalMinimum(_) => {
+                    ReportItem::PhysicalMinimum(PhysicalMinimum::from_value(signed))
+                }
+                ReportItem::PhysicalMaximum(_) => {
+                    ReportItem::PhysicalMaximum(PhysicalMaximum::from_value(signed))
+                }
+                ReportItem::UsagePage(_) => ReportItem::UsagePage(UsagePage::from_value(unsigned)),
+                ReportItem::Unit(_) => ReportItem::Unit(Unit::from_value(unsigned)),
+                ReportItem::ReportSize(_) => {
+                    ReportItem::ReportSize(ReportSize::from_value(unsigned))
+                }
+                ReportItem::ReportId(_) => ReportItem::ReportId(ReportId::from_value(unsigned)),
+                ReportItem::ReportCount(_) => {
+                    ReportItem::ReportCount(ReportCount::from_value(unsigned))
+                }
+                ReportItem::Usage(usage) if usage.data().len() < 4 => {
+                    let mut minimized = Usage::from_value(unsigned);
+                    if let Some(usage_page) = usage.usage_page() {
+                        minimized.set_usage_page(usage_page.clone());
+                    }
+                    ReportItem::Usage(minimized)
+                }
+                ReportItem::UsageMinimum(usage) if usage.data().len() < 4 => {
+                    let mut minimized = UsageMinimum::from_value(unsigned);
+                    if let Some(usage_page) = usage.usage_page() {
+                        minimized.set_usage_page(usage_page.clone());
+                    }
+                    ReportItem::UsageMinimum(minimized)
+                }
+                ReportItem::UsageMaximum(usage) if usage.data().len() < 4 => {
+                    let mut minimized = UsageMaximum::from_value(unsigned);
+                    if let Some(usage_page) = usage.usage_page() {
+                        minimized.set_usage_page(usage_page.clone());
+                    }
+                    ReportItem::UsageMaximum(minimized)
+                }
+                ReportItem::DesignatorIndex(_) => {
+                    ReportItem::DesignatorIndex(DesignatorIndex::from_value(unsigned))
+                }
+                ReportItem::DesignatorMinimum(_) => {
+                    ReportItem::DesignatorMinimum(DesignatorMinimum::from_value(unsigned))
+                }
+                ReportItem::DesignatorMaximum(_) => {
+                    ReportItem::DesignatorMaximum(DesignatorMaximum::from_value(unsigned))
+                }
+                ReportItem::StringIndex(_) => {
+                    ReportItem::StringIndex(StringIndex::from_value(unsigned))
+                }
+                ReportItem::StringMinimum(_) => {
+                    ReportItem::StringMinimum(StringMinimum::from_value(unsigned))
+                }
+                ReportItem::StringMaximum(_) => {
+                    ReportItem::StringMaximum(StringMaximum::from_value(unsigned))
+                }
+                ReportItem::Delimiter(_) => ReportItem::Delimiter(Delimiter::from_value(unsigned)),
+                _ => item.clone(),
+            }
+        })
+        .collect()
 }
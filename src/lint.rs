@@ -0,0 +1,359 @@
+use crate::{__data_to_signed, __data_to_unsigned, Descriptor, ItemKind, ReportItem};
+use alloc::vec::Vec;
+
+/// Severity of a [Diagnostic].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The descriptor works, but could be cleaner.
+    Info,
+    /// The descriptor is suspicious and may not work as intended.
+    Warning,
+    /// The descriptor is ill-defined.
+    Error,
+}
+
+/// Problems found by [`lint()`].
+///
+/// Indices refer to the position of the item in the linted items.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// A global item is set again with the same value before any main item
+    /// consumed it, so it can be removed.
+    RedundantGlobal {
+        /// Index of the redundant item.
+        index: usize,
+    },
+    /// A main item spans more bits (`Report Size * Report Count`) than
+    /// [`LintOptions::max_field_bits`], which usually indicates a byte order or
+    /// encoding mistake.
+    OversizedField {
+        /// Index of the main item.
+        index: usize,
+        /// Computed bit count.
+        bits: u64,
+    },
+    /// A main or global item carries a data size that is unusual for its tag,
+    /// e.g. an [End Collection](crate::EndCollection) with data bytes.
+    UnusualDataSize {
+        /// Index of the item.
+        index: usize,
+        /// Expected data size in bytes.
+        expected: usize,
+        /// Actual data size in bytes.
+        provided: usize,
+    },
+    /// [Push](crate::Push) items are left without matching [Pop](crate::Pop) at
+    /// the end of the descriptor, leaking global state.
+    UnclosedPush {
+        /// Number of unclosed pushes.
+        count: usize,
+    },
+    /// A main item is declared before both [Report Size](crate::ReportSize) and
+    /// [Report Count](crate::ReportCount) are set, so its fields are ill-defined.
+    MissingSizeOrCount {
+        /// Index of the main item.
+        index: usize,
+    },
+    /// A local minimum item ([Usage Minimum](crate::UsageMinimum),
+    /// [Designator Minimum](crate::DesignatorMinimum) or
+    /// [String Minimum](crate::StringMinimum)) isn't followed by its maximum before
+    /// the local items are consumed, or a maximum isn't preceded by its minimum.
+    UnpairedRange {
+        /// Index of the unpaired item.
+        index: usize,
+        /// Kind of the unpaired item.
+        kind: ItemKind,
+    },
+    /// A [Report ID](crate::ReportId) is 0, which is reserved, or is encoded in more
+    /// than the single byte prefixed to reports, whose further bytes report based
+    /// helpers like [`split_by_report()`](crate::split_by_report()) ignore.
+    InvalidReportId {
+        /// Index of the Report ID item.
+        index: usize,
+        /// Decoded value of all data bytes.
+        value: u32,
+    },
+    /// A local maximum item is less than its minimum.
+    InvertedRange {
+        /// Index of the maximum item.
+        index: usize,
+        /// Kind of the maximum item.
+        kind: ItemKind,
+    },
+}
+
+impl Diagnostic {
+    /// Get the severity of the diagnostic.
+    pub fn severity(&self) -> Severity {
+        match self {
+            Diagnostic::RedundantGlobal { .. } => Severity::Info,
+            Diagnostic::OversizedField { .. }
+            | Diagnostic::UnusualDataSize { .. }
+            | Diagnostic::UnclosedPush { .. } => Severity::Warning,
+            Diagnostic::MissingSizeOrCount { .. }
+            | Diagnostic::InvalidReportId { .. }
+            | Diagnostic::UnpairedRange { .. }
+            | Diagnostic::InvertedRange { .. } => Severity::Error,
+        }
+    }
+}
+
+/// Options of [`lint_with()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintOptions {
+    /// Maximum plausible bit count of a single main item, 4096 by default.
+    pub max_field_bits: u64,
+}
+
+impl Default for LintOptions {
+    fn default() -> Self {
+        Self {
+            max_field_bits: 4096,
+        }
+    }
+}
+
+/// Check items for common mistakes of report descriptors, with default [LintOptions].
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{lint, parse, Diagnostic, ItemKind, Severity};
+///
+/// let bytes = [
+///     0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x75, 0x08, 0x95, 0x01,
+///     0x75, 0x08, 0x81, 0x02, 0x75, 0x08, 0x81, 0x02, 0xC0,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// let diagnostics = lint(&items);
+/// assert_eq!(diagnostics, [Diagnostic::RedundantGlobal { index: 5 }]);
+/// assert_eq!(diagnostics[0].severity(), Severity::Info);
+///
+/// let items = parse([0xA1, 0x01, 0xC1, 0x00]).collect::<Vec<_>>();
+/// let diagnostics = lint(&items);
+/// assert_eq!(
+///     diagnostics,
+///     [Diagnostic::UnusualDataSize { index: 1, expected: 0, provided: 1 }]
+/// );
+/// assert_eq!(diagnostics[0].severity(), Severity::Warning);
+///
+/// let items = parse([0xA4, 0x75, 0x08, 0xA4, 0xB4]).collect::<Vec<_>>();
+/// assert_eq!(lint(&items), [Diagnostic::UnclosedPush { count: 1 }]);
+///
+/// // Report ID encoded in 2 bytes.
+/// let items = parse([0x86, 0x02, 0x01]).collect::<Vec<_>>();
+/// assert_eq!(lint(&items), [Diagnostic::InvalidReportId { index: 0, value: 0x0102 }]);
+/// assert_eq!(
+///     lint(&parse([0x86, 0x02, 0x00]).collect::<Vec<_>>()),
+///     [Diagnostic::InvalidReportId { index: 0, value: 2 }]
+/// );
+/// assert!(lint(&parse([0x85, 0x02]).collect::<Vec<_>>()).is_empty());
+///
+/// // Report Count is popped before the second Input.
+/// let bytes = [0x75, 0x08, 0xA4, 0x95, 0x01, 0x81, 0x02, 0xB4, 0x81, 0x02];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// let diagnostics = lint(&items);
+/// assert_eq!(diagnostics, [Diagnostic::MissingSizeOrCount { index: 5 }]);
+/// assert_eq!(diagnostics[0].severity(), Severity::Error);
+///
+/// // Usage Maximum without Usage Minimum, String Maximum less than String Minimum.
+/// let bytes = [0x75, 0x08, 0x95, 0x01, 0x29, 0x03, 0x89, 0x05, 0x99, 0x02, 0x81, 0x02];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// assert_eq!(
+///     lint(&items),
+///     [
+///         Diagnostic::UnpairedRange { index: 2, kind: ItemKind::UsageMaximum },
+///         Diagnostic::InvertedRange { index: 4, kind: ItemKind::StringMaximum },
+///     ]
+/// );
+/// ```
+pub fn lint(items: &[ReportItem]) -> Vec<Diagnostic> {
+    lint_with(items, &LintOptions::default())
+}
+
+/// Check items for common mistakes of report descriptors.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{lint_with, parse, Diagnostic, LintOptions};
+///
+/// let bytes = [0x75, 0x08, 0x96, 0x00, 0x02, 0x81, 0x02];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// let options = LintOptions { max_field_bits: 1024 };
+/// assert_eq!(
+///     lint_with(&items, &options),
+///     [Diagnostic::OversizedField { index: 2, bits: 4096 }]
+/// );
+/// ```
+pub fn lint_with(items: &[ReportItem], options: &LintOptions) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut report_size = None;
+    let mut report_count = None;
+    let mut stack = Vec::new();
+    // Last value of each global tag, and whether it's still unconsumed.
+    let mut globals: [Option<(i64, bool)>; 16] = [None; 16];
+    // Pending usage, designator and string minimums: index, kind and value.
+    let mut minimums: [Option<(usize, ItemKind, u32)>; 3] = [None; 3];
+    for (index, item) in items.iter().enumerate() {
+        __check_range(&mut diagnostics, &mut minimums, index, item);
+        if let Some(expected) = __expected_data_size(item) {
+            let provided = item.data().len();
+            if provided != expected {
+                diagnostics.push(Diagnostic::UnusualDataSize {
+                    index,
+                    expected,
+                    provided,
+                });
+            }
+        }
+        if let Some(value) = __global_value(item) {
+            let tag = item.tag() as usize;
+            if globals[tag] == Some((value, true)) {
+                diagnostics.push(Diagnostic::RedundantGlobal { index });
+            }
+            globals[tag] = Some((value, true));
+        }
+        match item {
+            ReportItem::Input(_) | ReportItem::Output(_) | ReportItem::Feature(_) => {
+                match (report_size, report_count) {
+                    (Some(report_size), Some(report_count)) => {
+                        let bits = report_size as u64 * report_count as u64;
+                        if bits > options.max_field_bits {
+                            diagnostics.push(Diagnostic::OversizedField { index, bits });
+                        }
+                    }
+                    _ => diagnostics.push(Diagnostic::MissingSizeOrCount { index }),
+                }
+                __consume_globals(&mut globals);
+            }
+            ReportItem::Collection(_) | ReportItem::EndCollection(_) => {
+                __consume_globals(&mut globals)
+            }
+            ReportItem::ReportId(inner) if inner.value() == 0 || inner.data().len() > 1 => {
+                let value = __data_to_unsigned(inner.data());
+                diagnostics.push(Diagnostic::InvalidReportId { index, value });
+            }
+            ReportItem::ReportSize(inner) => report_size = Some(__data_to_unsigned(inner.data())),
+            ReportItem::ReportCount(inner) => report_count = Some(__data_to_unsigned(inner.data())),
+            ReportItem::Push(_) => stack.push((report_size, report_count)),
+            ReportItem::Pop(_) => {
+                if let Some((size, count)) = stack.pop() {
+                    report_size = size;
+                    report_count = count;
+                }
+                globals = [None; 16];
+            }
+            _ => (),
+        }
+    }
+    __flush_ranges(&mut diagnostics, &mut minimums);
+    if !stack.is_empty() {
+        diagnostics.push(Diagnostic::UnclosedPush { count: stack.len() });
+    }
+    diagnostics
+}
+
+/// Parse bytes and [`lint()`] the items in one call.
+///
+/// The parsed descriptor is returned either way, so that callers can still
+/// inspect it when any diagnostic is found.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse_validated, Diagnostic};
+///
+/// let descriptor = parse_validated(&[0x75, 0x08, 0x95, 0x01, 0x81, 0x02]).unwrap();
+/// assert_eq!(descriptor.len(), 3);
+///
+/// let (descriptor, diagnostics) = parse_validated(&[0x81, 0x02]).unwrap_err();
+/// assert_eq!(descriptor.len(), 1);
+/// assert_eq!(diagnostics, [Diagnostic::MissingSizeOrCount { index: 0 }]);
+/// ```
+pub fn parse_validated(bytes: &[u8]) -> Result<Descriptor, (Descriptor, Vec<Diagnostic>)> {
+    let descriptor = Descriptor::parse(bytes.iter().copied());
+    let diagnostics = lint(descriptor.as_slice());
+    if diagnostics.is_empty() {
+        Ok(descriptor)
+    } else {
+        Err((descriptor, diagnostics))
+    }
+}
+
+fn __check_range(
+    diagnostics: &mut Vec<Diagnostic>,
+    minimums: &mut [Option<(usize, ItemKind, u32)>; 3],
+    index: usize,
+    item: &ReportItem,
+) {
+    let value = __data_to_unsigned(item.data());
+    let (category, is_minimum) = match item {
+        ReportItem::UsageMinimum(_) => (0, true),
+        ReportItem::UsageMaximum(_) => (0, false),
+        ReportItem::DesignatorMinimum(_) => (1, true),
+        ReportItem::DesignatorMaximum(_) => (1, false),
+        ReportItem::StringMinimum(_) => (2, true),
+        ReportItem::StringMaximum(_) => (2, false),
+        ReportItem::Input(_)
+        | ReportItem::Output(_)
+        | ReportItem::Feature(_)
+        | ReportItem::Collection(_)
+        | ReportItem::EndCollection(_) => return __flush_ranges(diagnostics, minimums),
+        _ => return,
+    };
+    let kind = item.kind();
+    if is_minimum {
+        if let Some((index, kind, _)) = minimums[category].replace((index, kind, value)) {
+            diagnostics.push(Diagnostic::UnpairedRange { index, kind });
+        }
+    } else {
+        match minimums[category].take() {
+            Some((_, _, minimum)) if minimum > value => {
+                diagnostics.push(Diagnostic::InvertedRange { index, kind })
+            }
+            Some(_) => (),
+            None => diagnostics.push(Diagnostic::UnpairedRange { index, kind }),
+        }
+    }
+}
+
+fn __flush_ranges(
+    diagnostics: &mut Vec<Diagnostic>,
+    minimums: &mut [Option<(usize, ItemKind, u32)>; 3],
+) {
+    for (index, kind, _) in minimums.iter_mut().filter_map(Option::take) {
+        diagnostics.push(Diagnostic::UnpairedRange { index, kind });
+    }
+}
+
+fn __consume_globals(globals: &mut [Option<(i64, bool)>; 16]) {
+    for (_, unconsumed) in globals.iter_mut().flatten() {
+        *unconsumed = false;
+    }
+}
+
+fn __global_value(item: &ReportItem) -> Option<i64> {
+    match item {
+        ReportItem::LogicalMinimum(_)
+        | ReportItem::LogicalMaximum(_)
+        | ReportItem::PhysicalMinimum(_)
+        | ReportItem::PhysicalMaximum(_)
+        | ReportItem::UnitExponent(_) => Some(__data_to_signed(item.data()) as i64),
+        ReportItem::UsagePage(_)
+        | ReportItem::Unit(_)
+        | ReportItem::ReportSize(_)
+        | ReportItem::ReportId(_)
+        | ReportItem::ReportCount(_) => Some(__data_to_unsigned(item.data()) as i64),
+        _ => None,
+    }
+}
+
+fn __expected_data_size(item: &ReportItem) -> Option<usize> {
+    match item {
+        // Report IDs of another size are reported as InvalidReportId instead.
+        ReportItem::Collection(_) => Some(1),
+        ReportItem::EndCollection(_) | ReportItem::Push(_) | ReportItem::Pop(_) => Some(0),
+        _ => None,
+    }
+}
@@ -1,5 +1,10 @@
-use crate::{UsagePage, __data_size, __data_to_unsigned, __set_data_size, macros::*};
-use alloc::{borrow::Cow, format};
+use crate::{
+    __alternate_hex, __data_size, __data_to_unsigned, __set_data_size, macros::*, UsagePage,
+};
+#[cfg(feature = "alloc")]
+use crate::{__usage_page_name, UsageResolver};
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
 use std::{
     cmp::{Eq, PartialEq},
     fmt::Display,
@@ -31,6 +36,11 @@ __impls_for_short_items! {
     Delimiter: 0b1010_1000;
 }
 
+__impls_for_numeric_items! {
+    unsigned: DesignatorIndex, DesignatorMinimum, DesignatorMaximum,
+        StringIndex, StringMinimum, StringMaximum, Delimiter
+}
+
 /// Usage index for an item usage; represents a
 /// suggested usage for the item or collection.
 ///
@@ -42,10 +52,47 @@ __impls_for_short_items! {
 ///
 /// Depends on the value of [UsagePage](crate::UsagePage).
 /// See [HID Usage Tables FOR Universal Serial Bus](https://usb.org/sites/default/files/hut1_5.pdf).
+///
+/// # Example
+///
+/// ```
+/// use hid_report::parse;
+///
+/// let bytes = [0x05, 0x0D, 0x09, 0x22, 0x09, 0x42, 0x09, 0x51, 0x09, 0xC7];
+/// let mut items = parse(bytes).skip(1);
+/// assert_eq!(items.next().unwrap().to_string(), "Usage (Finger)");
+/// assert_eq!(items.next().unwrap().to_string(), "Usage (Tip Switch)");
+/// assert_eq!(items.next().unwrap().to_string(), "Usage (Contact Identifier)");
+/// assert_eq!(items.next().unwrap().to_string(), "Usage (0x00C7)");
+///
+/// // Usage with 4 data bytes embeds its own usage page in the high 16 bits.
+/// let bytes = [0x05, 0x0D, 0x0B, 0x01, 0x00, 0x0C, 0x00, 0x09, 0x22];
+/// let mut items = parse(bytes).skip(1);
+/// assert_eq!(items.next().unwrap().to_string(), "Usage (Consumer Control)");
+/// assert_eq!(items.next().unwrap().to_string(), "Usage (Finger)");
+///
+/// // Even if no Usage Page is declared.
+/// let mut items = parse([0x0B, 0x01, 0x00, 0x0C, 0x00, 0x09, 0x22]);
+/// assert_eq!(items.next().unwrap().to_string(), "Usage (Consumer Control)");
+/// assert_eq!(items.next().unwrap().to_string(), "Usage");
+///
+/// // Usage IDs of the Unicode page are code points.
+/// let bytes = [0x05, 0x10, 0x09, 0x41, 0x0A, 0xAC, 0x20, 0x09, 0x0A];
+/// let mut items = parse(bytes).skip(1);
+/// assert_eq!(items.next().unwrap().to_string(), "Usage (U+0041 'A')");
+/// assert_eq!(items.next().unwrap().to_string(), "Usage (U+20AC '€')");
+/// assert_eq!(items.next().unwrap().to_string(), "Usage (U+000A)");
+///
+/// // Usage IDs of the Ordinal page are instance numbers.
+/// let bytes = [0x05, 0x0A, 0x09, 0x03];
+/// let mut items = parse(bytes).skip(1);
+/// assert_eq!(items.next().unwrap().to_string(), "Usage (Instance 3)");
+/// ```
 #[derive(Clone, Debug)]
 pub struct Usage {
     raw: [u8; 5],
     usage_page: Option<UsagePage>,
+    name: Option<ResolvedName>,
 }
 
 /// Defines the starting usage associated with an array or bitmap.
@@ -54,10 +101,24 @@ pub struct Usage {
 ///
 /// Depends on the value of [UsagePage](crate::UsagePage).
 /// See [HID Usage Tables FOR Universal Serial Bus](https://usb.org/sites/default/files/hut1_5.pdf).
+///
+/// # Example
+///
+/// ```
+/// use hid_report::parse;
+///
+/// let bytes = [0x05, 0x08, 0x19, 0x01, 0x29, 0x05, 0x19, 0x58, 0x29, 0xFF];
+/// let mut items = parse(bytes).skip(1);
+/// assert_eq!(items.next().unwrap().to_string(), "Usage Minimum (Num Lock)");
+/// assert_eq!(items.next().unwrap().to_string(), "Usage Maximum (Kana)");
+/// assert_eq!(items.next().unwrap().to_string(), "Usage Minimum (0x0058)");
+/// assert_eq!(items.next().unwrap().to_string(), "Usage Maximum (0x00FF)");
+/// ```
 #[derive(Clone, Debug)]
 pub struct UsageMinimum {
     raw: [u8; 5],
     usage_page: Option<UsagePage>,
+    name: Option<ResolvedName>,
 }
 /// Defines the ending usage associated with an array or bitmap.
 ///
@@ -69,6 +130,7 @@ pub struct UsageMinimum {
 pub struct UsageMaximum {
     raw: [u8; 5],
     usage_page: Option<UsagePage>,
+    name: Option<ResolvedName>,
 }
 
 impl AsRef<[u8]> for Usage {
@@ -83,6 +145,7 @@ impl Default for Usage {
         Self {
             raw: [Self::PREFIX, 0, 0, 0, 0],
             usage_page: None,
+            name: None,
         }
     }
 }
@@ -112,20 +175,20 @@ impl Usage {
         Ok(Self {
             raw: storage,
             usage_page: None,
+            name: None,
         })
     }
 
     /// Create an item *WITHOUT* prefix check.
     ///
-    /// # Safety
-    ///
-    /// Must ensure that the prefix part is correct.
-    pub unsafe fn new_unchecked(raw: &[u8]) -> Self {
+    /// *NOTE*: must ensure that the prefix part is correct.
+    pub fn new_unchecked(raw: &[u8]) -> Self {
         let mut storage = [0; 5];
         storage[..raw.len()].copy_from_slice(raw);
         Self {
             raw: storage,
             usage_page: None,
+            name: None,
         }
     }
 
@@ -143,11 +206,17 @@ impl Usage {
     /// If you want more detailed content printed when formatting,
     /// you need to set the related usage page.
     ///
+    /// If the item carries 4 data bytes, the usage page embedded in the high 16 bits
+    /// takes precedence over `usage_page`.
+    ///
     /// # Equality
     ///
     /// Equality between two Usage items ignores usage page.
+    ///
+    /// *NOTE*: the name resolved by [`resolve_with()`](Usage::resolve_with()) is discarded.
     pub fn set_usage_page(&mut self, usage_page: UsagePage) {
-        self.usage_page = Some(usage_page);
+        self.usage_page = Some(__embedded_usage_page(self.data()).unwrap_or(usage_page));
+        self.name = None;
     }
 
     /// Get usage page.
@@ -155,6 +224,46 @@ impl Usage {
         self.usage_page.as_ref()
     }
 
+    /// Resolve the usage name with a [UsageResolver], which takes precedence over the
+    /// built-in usage tables when formatting.
+    ///
+    /// Does nothing if usage page is not set.
+    #[cfg(feature = "alloc")]
+    pub fn resolve_with(&mut self, resolver: &dyn UsageResolver) {
+        if let Some(usage_page) = &self.usage_page {
+            self.name = resolver.name(
+                __data_to_unsigned(usage_page.data()),
+                __usage_id(self.data()),
+            );
+        }
+    }
+
+    /// Format the item like [Display] does, but prefix the usage with the name of
+    /// its usage page, which helps with descriptors switching pages frequently.
+    ///
+    /// Falls back to the [Display] output if the usage page is not set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::{Usage, UsagePage};
+    ///
+    /// let mut usage = Usage::new(&[0x09, 0x30]).unwrap();
+    /// assert_eq!(usage.display_with_page(), "Usage");
+    /// usage.set_usage_page(UsagePage::new(&[0x05, 0x01]).unwrap());
+    /// assert_eq!(usage.to_string(), "Usage (X)");
+    /// assert_eq!(usage.display_with_page(), "Usage (Generic Desktop: X)");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn display_with_page(&self) -> String {
+        __display_with_page(
+            "Usage",
+            self.data(),
+            self.usage_page.as_ref(),
+            self.name.as_deref(),
+        )
+    }
+
     /// Create an item with specific data.
     ///
     /// *NOTE*: data size must be: 0, 1, 2 or 4.
@@ -162,6 +271,7 @@ impl Usage {
         let mut item = Self {
             raw: [0; 5],
             usage_page: None,
+            name: None,
         };
         item.raw[0] = 0b0000_1000;
         __set_data_size(&mut item.raw[0], data)?;
@@ -205,6 +315,7 @@ impl Default for UsageMinimum {
         Self {
             raw: [Self::PREFIX, 0, 0, 0, 0],
             usage_page: None,
+            name: None,
         }
     }
 }
@@ -234,20 +345,20 @@ impl UsageMinimum {
         Ok(Self {
             raw: storage,
             usage_page: None,
+            name: None,
         })
     }
 
     /// Create an item *WITHOUT* prefix check.
     ///
-    /// # Safety
-    ///
-    /// Must ensure that the prefix part is correct.
-    pub unsafe fn new_unchecked(raw: &[u8]) -> Self {
+    /// *NOTE*: must ensure that the prefix part is correct.
+    pub fn new_unchecked(raw: &[u8]) -> Self {
         let mut storage = [0; 5];
         storage[..raw.len()].copy_from_slice(raw);
         Self {
             raw: storage,
             usage_page: None,
+            name: None,
         }
     }
 
@@ -265,11 +376,17 @@ impl UsageMinimum {
     /// If you want more detailed content printed when formatting,
     /// you need to set the related usage page.
     ///
+    /// If the item carries 4 data bytes, the usage page embedded in the high 16 bits
+    /// takes precedence over `usage_page`.
+    ///
     /// # Equality
     ///
     /// Equality between two UsageMinimum items ignores usage page.
+    ///
+    /// *NOTE*: the name resolved by [`resolve_with()`](UsageMinimum::resolve_with()) is discarded.
     pub fn set_usage_page(&mut self, usage_page: UsagePage) {
-        self.usage_page = Some(usage_page);
+        self.usage_page = Some(__embedded_usage_page(self.data()).unwrap_or(usage_page));
+        self.name = None;
     }
 
     /// Get usage page.
@@ -277,6 +394,34 @@ impl UsageMinimum {
         self.usage_page.as_ref()
     }
 
+    /// Resolve the usage name with a [UsageResolver], which takes precedence over the
+    /// built-in usage tables when formatting.
+    ///
+    /// Does nothing if usage page is not set.
+    #[cfg(feature = "alloc")]
+    pub fn resolve_with(&mut self, resolver: &dyn UsageResolver) {
+        if let Some(usage_page) = &self.usage_page {
+            self.name = resolver.name(
+                __data_to_unsigned(usage_page.data()),
+                __usage_id(self.data()),
+            );
+        }
+    }
+
+    /// Format the item like [Display] does, but prefix the usage with the name of
+    /// its usage page, which helps with descriptors switching pages frequently.
+    ///
+    /// Falls back to the [Display] output if the usage page is not set.
+    #[cfg(feature = "alloc")]
+    pub fn display_with_page(&self) -> String {
+        __display_with_page(
+            "Usage Minimum",
+            self.data(),
+            self.usage_page.as_ref(),
+            self.name.as_deref(),
+        )
+    }
+
     /// Create an item with specific data.
     ///
     /// *NOTE*: data size must be: 0, 1, 2 or 4.
@@ -284,6 +429,7 @@ impl UsageMinimum {
         let mut item = Self {
             raw: [0; 5],
             usage_page: None,
+            name: None,
         };
         item.raw[0] = 0b0001_1000;
         __set_data_size(&mut item.raw[0], data)?;
@@ -327,6 +473,7 @@ impl Default for UsageMaximum {
         Self {
             raw: [Self::PREFIX, 0, 0, 0, 0],
             usage_page: None,
+            name: None,
         }
     }
 }
@@ -356,20 +503,20 @@ impl UsageMaximum {
         Ok(Self {
             raw: storage,
             usage_page: None,
+            name: None,
         })
     }
 
     /// Create an item *WITHOUT* prefix check.
     ///
-    /// # Safety
-    ///
-    /// Must ensure that the prefix part is correct.
-    pub unsafe fn new_unchecked(raw: &[u8]) -> Self {
+    /// *NOTE*: must ensure that the prefix part is correct.
+    pub fn new_unchecked(raw: &[u8]) -> Self {
         let mut storage = [0; 5];
         storage[..raw.len()].copy_from_slice(raw);
         Self {
             raw: storage,
             usage_page: None,
+            name: None,
         }
     }
 
@@ -387,11 +534,17 @@ impl UsageMaximum {
     /// If you want more detailed content printed when formatting,
     /// you need to set the related usage page.
     ///
+    /// If the item carries 4 data bytes, the usage page embedded in the high 16 bits
+    /// takes precedence over `usage_page`.
+    ///
     /// # Equality
     ///
     /// Equality between two UsageMaximum items ignores usage page.
+    ///
+    /// *NOTE*: the name resolved by [`resolve_with()`](UsageMaximum::resolve_with()) is discarded.
     pub fn set_usage_page(&mut self, usage_page: UsagePage) {
-        self.usage_page = Some(usage_page);
+        self.usage_page = Some(__embedded_usage_page(self.data()).unwrap_or(usage_page));
+        self.name = None;
     }
 
     /// Get usage page.
@@ -399,6 +552,34 @@ impl UsageMaximum {
         self.usage_page.as_ref()
     }
 
+    /// Resolve the usage name with a [UsageResolver], which takes precedence over the
+    /// built-in usage tables when formatting.
+    ///
+    /// Does nothing if usage page is not set.
+    #[cfg(feature = "alloc")]
+    pub fn resolve_with(&mut self, resolver: &dyn UsageResolver) {
+        if let Some(usage_page) = &self.usage_page {
+            self.name = resolver.name(
+                __data_to_unsigned(usage_page.data()),
+                __usage_id(self.data()),
+            );
+        }
+    }
+
+    /// Format the item like [Display] does, but prefix the usage with the name of
+    /// its usage page, which helps with descriptors switching pages frequently.
+    ///
+    /// Falls back to the [Display] output if the usage page is not set.
+    #[cfg(feature = "alloc")]
+    pub fn display_with_page(&self) -> String {
+        __display_with_page(
+            "Usage Maximum",
+            self.data(),
+            self.usage_page.as_ref(),
+            self.name.as_deref(),
+        )
+    }
+
     /// Create an item with specific data.
     ///
     /// *NOTE*: data size must be: 0, 1, 2 or 4.
@@ -406,6 +587,7 @@ impl UsageMaximum {
         let mut item = Self {
             raw: [0; 5],
             usage_page: None,
+            name: None,
         };
         item.raw[0] = 0b0010_1000;
         crate::__set_data_size(&mut item.raw[0], data)?;
@@ -437,10 +619,72 @@ impl PartialEq for UsageMaximum {
 
 impl Eq for UsageMaximum {}
 
-fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
+__impls_for_numeric_items! {
+    unsigned: Usage, UsageMinimum, UsageMaximum
+}
+
+/// Name resolved by a [UsageResolver](crate::UsageResolver), which is never set
+/// without the `alloc` feature.
+#[cfg(feature = "alloc")]
+type ResolvedName = String;
+#[cfg(not(feature = "alloc"))]
+type ResolvedName = &'static str;
+
+#[cfg(feature = "alloc")]
+fn __resolved_name(name: &Option<ResolvedName>) -> Option<&str> {
+    name.as_deref()
+}
+
+#[cfg(not(feature = "alloc"))]
+fn __resolved_name(name: &Option<ResolvedName>) -> Option<&str> {
+    *name
+}
+
+/// Name of a usage, formatted lazily so that no allocation is needed.
+enum UsageName<'a> {
+    Named(&'a str),
+    Hex(u32),
+    Button(u32),
+    Instance(u32),
+    Enum(u32),
+    Unicode(u32),
+}
+
+impl UsageName<'_> {
+    fn is_empty(&self) -> bool {
+        matches!(self, UsageName::Named(""))
+    }
+}
+
+impl Display for UsageName<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UsageName::Named(name) => write!(f, "{}", name),
+            UsageName::Hex(usage) => write!(f, "{:#06X}", usage),
+            UsageName::Button(usage) => write!(f, "Button {}", usage),
+            UsageName::Instance(usage) => write!(f, "Instance {}", usage),
+            UsageName::Enum(usage) => write!(f, "Enum {}", usage),
+            UsageName::Unicode(usage) => match char::from_u32(*usage) {
+                Some(c) if !c.is_control() => write!(f, "U+{:04X} '{}'", usage, c),
+                _ => write!(f, "U+{:04X}", usage),
+            },
+        }
+    }
+}
+
+fn __usage_format_helper(usage: u32, usage_page: u32) -> UsageName<'static> {
+    #[cfg(feature = "usage-tables")]
+    if usage_page <= 0xFFFF && usage <= 0xFFFF {
+        use crate::usage_tables::USAGE_NAMES;
+        if let Ok(index) =
+            USAGE_NAMES.binary_search_by_key(&(usage_page << 16 | usage), |&(usage, _)| usage)
+        {
+            return UsageName::Named(USAGE_NAMES[index].1);
+        }
+    }
     match usage_page {
         // Generic Desktop
-        0x01 => Cow::Borrowed(match usage {
+        0x01 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Pointer",
             0x02 => "Mouse",
@@ -559,7 +803,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Simulation Controls
-        0x02 => Cow::Borrowed(match usage {
+        0x02 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Flight Simulation Device",
             0x02 => "Automobile Simulation Device",
@@ -615,7 +859,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // VR Controls
-        0x03 => Cow::Borrowed(match usage {
+        0x03 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Belt",
             0x02 => "Body Suit",
@@ -632,7 +876,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Sport Controls
-        0x04 => Cow::Borrowed(match usage {
+        0x04 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Baseball Bat",
             0x02 => "Golf Club",
@@ -671,7 +915,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Game Controls
-        0x05 => Cow::Borrowed(match usage {
+        0x05 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "3D Game Controller",
             0x02 => "Pinball Device",
@@ -705,7 +949,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Generic Device Controls
-        0x06 => Cow::Borrowed(match usage {
+        0x06 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Background/Nonuser Controls",
             0x20 => "Battery Strength",
@@ -734,7 +978,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Keyboard/Keypad
-        0x07 => Cow::Borrowed(match usage {
+        0x07 => UsageName::Named(match usage {
             0x01 => "Keyboard ErrorRollOver",
             0x02 => "Keyboard POSTFail",
             0x03 => "Keyboard ErrorUndefined",
@@ -956,7 +1200,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // LED
-        0x08 => Cow::Borrowed(match usage {
+        0x08 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Num Lock",
             0x02 => "Caps Lock",
@@ -1054,26 +1298,26 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             0x66 => "Player 6",
             0x67 => "Player 7",
             0x68 => "Player 8",
-            _ => "Reserved",
+            _ => return UsageName::Hex(usage),
         }),
         // Button
         0x09 => match usage {
-            0x00 => Cow::Borrowed("No Button Pressed"),
-            _ => Cow::Owned(format!("Button {}", usage)),
+            0x00 => UsageName::Named("No Button Pressed"),
+            _ => UsageName::Button(usage),
         },
         // Ordinal
         0x0A => match usage {
-            0x00 => Cow::Borrowed("Reserved"),
-            _ => Cow::Owned(format!("Instance {}", usage)),
+            0x00 => UsageName::Named("Reserved"),
+            _ => UsageName::Instance(usage),
         },
         // Telephony Device
-        0x0B => Cow::Borrowed(match usage {
+        0x0B => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Phone",
             0x02 => "Answering Machine",
             0x03 => "Message Controls",
             0x04 => "Handset",
-            0x05 => "Headset CL/CA 14.1",
+            0x05 => "Headset",
             0x06 => "Telephony Key Pad",
             0x07 => "Programmable Button",
             0x20 => "Hook Switch",
@@ -1086,7 +1330,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             0x27 => "Park",
             0x28 => "Forward Calls",
             0x29 => "Alternate Function",
-            0x2A => "Line OSC/NAry 14.3",
+            0x2A => "Line",
             0x2B => "Speaker Phone",
             0x2C => "Conference",
             0x2D => "Ring Enable",
@@ -1171,7 +1415,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Consumer
-        0x0C => Cow::Borrowed(match usage {
+        0x0C => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Consumer Control",
             0x02 => "Numeric Key Pad",
@@ -1226,7 +1470,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             0x79 => "Keyboard Brightness Increment",
             0x7A => "Keyboard Brightness Decrement",
             0x7B => "Keyboard Backlight Set Level",
-            0x7C => "Keyboard Backlight OOC",
+            0x7C => "Keyboard Backlight",
             0x7D => "Keyboard Backlight Set Minimum",
             0x7E => "Keyboard Backlight Set Maximum",
             0x7F => "Keyboard Backlight Auto",
@@ -1626,7 +1870,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Digitizers
-        0x0D => Cow::Borrowed(match usage {
+        0x0D => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Digitizer",
             0x02 => "Pen",
@@ -1643,7 +1887,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             0x0D => "Free Space Wand",
             0x0E => "Device Configuration",
             0x0F => "Capacitive Heat Map Digitizer",
-            0x20 => "Stylus [55] CA/CL 16.2",
+            0x20 => "Stylus",
             0x21 => "Puck",
             0x22 => "Finger",
             0x23 => "Device settings",
@@ -1676,7 +1920,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             0x49 => "Height",
             0x51 => "Contact Identifier",
             0x52 => "Device Mode",
-            0x53 => "Device Identifier [7] DV/SV 16.7",
+            0x53 => "Device Identifier",
             0x54 => "Contact Count",
             0x55 => "Contact Count Maximum",
             0x56 => "Scan Time",
@@ -1722,13 +1966,13 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             0x90 => "Transducer Software Info",
             0x91 => "Transducer Vendor Id",
             0x92 => "Transducer Product Id",
-            0x93 => "Device Supported Protocols [36] NAry/CL 16.3.1",
-            0x94 => "Transducer Supported Protocols [36] NAry/CL 16.3.1",
+            0x93 => "Device Supported Protocols",
+            0x94 => "Transducer Supported Protocols",
             0x95 => "No Protocol",
             0x96 => "Wacom AES Protocol",
             0x97 => "USI Protocol",
             0x98 => "Microsoft Pen Protocol",
-            0xA0 => "Supported Report Rates [36] SV/CL 16.3.1",
+            0xA0 => "Supported Report Rates",
             0xA1 => "Report Rate",
             0xA2 => "Transducer Connected",
             0xA3 => "Switch Disabled",
@@ -1736,12 +1980,12 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             0xA5 => "Transducer Switches",
             0xA6 => "Transducer Index Selector",
             0xB0 => "Button Press Threshold",
-            _ => "Reserved",
+            _ => return UsageName::Hex(usage),
         }),
         // Haptics
-        0x0E => Cow::Borrowed(match usage {
+        0x0E => UsageName::Named(match usage {
             0x00 => "Undefined",
-            0x01 => "Simple Haptic Controller CA/CL 17.1",
+            0x01 => "Simple Haptic Controller",
             0x10 => "Waveform List",
             0x11 => "Duration List",
             0x20 => "Auto Trigger",
@@ -1773,7 +2017,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Physical Input Device
-        0x0F => Cow::Borrowed(match usage {
+        0x0F => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Physical Input Device",
             0x20 => "Normal",
@@ -1804,12 +2048,12 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             0x57 => "Direction",
             0x58 => "Type Specific Block Offset",
             0x59 => "Block Type",
-            0x5A => "Set Envelope Report CL/SV 18.5",
+            0x5A => "Set Envelope Report",
             0x5B => "Attack Level",
             0x5C => "Attack Time",
             0x5D => "Fade Level",
             0x5E => "Fade Time",
-            0x5F => "Set Condition Report CL/SV 18.6",
+            0x5F => "Set Condition Report",
             0x60 => "Center-Point Offset",
             0x61 => "Positive Coefficient",
             0x62 => "Negative Coefficient",
@@ -1821,16 +2065,16 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             0x68 => "Custom-Force Data Report",
             0x69 => "Custom-Force Data",
             0x6A => "Custom-Force Vendor Defined Data",
-            0x6B => "Set Custom-Force Report CL/SV 18.10",
+            0x6B => "Set Custom-Force Report",
             0x6C => "Custom-Force Data Offset",
             0x6D => "Sample Count",
-            0x6E => "Set Periodic Report CL/SV 18.7",
+            0x6E => "Set Periodic Report",
             0x6F => "Offset",
             0x70 => "Magnitude",
             0x71 => "Phase",
             0x72 => "Period",
-            0x73 => "Set Constant-Force Report CL/SV 18.8",
-            0x74 => "Set Ramp-Force Report CL/SV 18.9",
+            0x73 => "Set Constant-Force Report",
+            0x74 => "Set Ramp-Force Report",
             0x75 => "Ramp Start",
             0x76 => "Ramp End",
             0x77 => "Effect Operation Report",
@@ -1883,12 +2127,9 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Unicode
-        0x10 => Cow::Owned(format!(
-            "\"{}\"",
-            char::from_u32(usage).unwrap_or('\u{FFFD}')
-        )),
+        0x10 => UsageName::Unicode(usage),
         // SoC
-        0x11 => Cow::Borrowed(match usage {
+        0x11 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "SocControl",
             0x02 => "FirmwareTransfer",
@@ -1903,7 +2144,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Eye and Head Trackers
-        0x12 => Cow::Borrowed(match usage {
+        0x12 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Eye Tracker",
             0x02 => "Head Tracker",
@@ -1942,7 +2183,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Auxiliary Display
-        0x14 => Cow::Borrowed(match usage {
+        0x14 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Alphanumeric Display",
             0x02 => "Auxiliary Display",
@@ -2022,7 +2263,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Sensors
-        0x20 => Cow::Borrowed(match usage {
+        0x20 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Sensor",
             0x10 => "Biometric",
@@ -2700,7 +2941,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Medical Instrument
-        0x40 => Cow::Borrowed(match usage {
+        0x40 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Medical Ultrasound",
             0x20 => "VCR/Acquisition",
@@ -2734,7 +2975,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Braille Display
-        0x41 => Cow::Borrowed(match usage {
+        0x41 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Braille Display",
             0x02 => "Braille Row",
@@ -2782,7 +3023,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Lighting And Illumination
-        0x59 => Cow::Borrowed(match usage {
+        0x59 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "LampArray",
             0x02 => "LampArrayAttributesReport",
@@ -2820,7 +3061,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Monitor
-        0x80 => Cow::Borrowed(match usage {
+        0x80 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Monitor Control",
             0x02 => "EDID Information",
@@ -2830,11 +3071,11 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
         }),
         // Monitor Enumerated
         0x81 => match usage {
-            0x00 => Cow::Borrowed("Reserved"),
-            _ => Cow::Owned(format!("Enum {}", usage)),
+            0x00 => UsageName::Named("Reserved"),
+            _ => UsageName::Enum(usage),
         },
         // VESA Virtual Controls
-        0x82 => Cow::Borrowed(match usage {
+        0x82 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Degauss",
             0x10 => "Brightness",
@@ -2884,7 +3125,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Power
-        0x84 => Cow::Borrowed(match usage {
+        0x84 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "iName",
             0x02 => "Present Status",
@@ -2966,7 +3207,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Battery System
-        0x85 => Cow::Borrowed(match usage {
+        0x85 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Smart Battery Battery Mode",
             0x02 => "Smart Battery Battery Status",
@@ -3061,8 +3302,8 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             0xF3 => "Level 3",
             _ => "Reserved",
         }),
-        // Bar Code Scanner
-        0x8C => Cow::Borrowed(match usage {
+        // Barcode Scanner
+        0x8C => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Barcode Badge Reader",
             0x02 => "Barcode Scanner",
@@ -3260,8 +3501,8 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             0x121 => "VeriCode",
             _ => "Reserved",
         }),
-        // Scale
-        0x8D => Cow::Borrowed(match usage {
+        // Scales
+        0x8D => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "Scales",
             0x20 => "Scale Device",
@@ -3311,8 +3552,8 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             0x81 => "Enforced Zero Return",
             _ => "Reserved",
         }),
-        // Magnetic Stripe Reading
-        0x8E => Cow::Borrowed(match usage {
+        // Magnetic Stripe Reader
+        0x8E => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "MSR Device Read-Only",
             0x11 => "Track 1 Length",
@@ -3327,14 +3568,14 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Camera Control
-        0x90 => Cow::Borrowed(match usage {
+        0x90 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x20 => "Camera Auto-focus",
             0x21 => "Camera Shutter",
             _ => "Reserved",
         }),
         // Arcade
-        0x91 => Cow::Borrowed(match usage {
+        0x91 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "General Purpose IO Card",
             0x02 => "Coin Door",
@@ -3366,7 +3607,7 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // Gaming Device
-        0x92 => Cow::Borrowed(match usage {
+        0x92 => UsageName::Named(match usage {
             0x40 => "ACK",
             0x41 => "Enable",
             0x42 => "Disable",
@@ -3382,14 +3623,49 @@ fn __usage_format_helper(usage: u32, usage_page: u32) -> Cow<'static, str> {
             _ => "Reserved",
         }),
         // FIDO Alliance
-        0xF1D0 => Cow::Borrowed(match usage {
+        0xF1D0 => UsageName::Named(match usage {
             0x00 => "Undefined",
             0x01 => "U2F Authenticator Device",
             0x20 => "Input Report Data",
             0x21 => "Output Report Data",
             _ => "Reserved",
         }),
-        _ => Cow::Borrowed(""),
+        _ => UsageName::Named(""),
+    }
+}
+
+fn __usage_id(data: &[u8]) -> u32 {
+    __data_to_unsigned(data) & 0xFFFF
+}
+
+fn __embedded_usage_page(data: &[u8]) -> Option<UsagePage> {
+    match data.len() {
+        4 => Some(UsagePage::from_value(__data_to_unsigned(data) >> 16)),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn __display_with_page(
+    name: &str,
+    data: &[u8],
+    usage_page: Option<&UsagePage>,
+    resolved: Option<&str>,
+) -> String {
+    match usage_page {
+        Some(usage_page) => {
+            let usage_page = __data_to_unsigned(usage_page.data());
+            let usage = match resolved {
+                Some(resolved) => UsageName::Named(resolved),
+                None => __usage_format_helper(__usage_id(data), usage_page),
+            };
+            if usage.is_empty() {
+                format!("{} ({})", name, __usage_page_name(usage_page))
+            } else {
+                format!("{} ({}: {})", name, __usage_page_name(usage_page), usage)
+            }
+        }
+        None => String::from(name),
     }
 }
 
@@ -3397,10 +3673,13 @@ impl Display for Usage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self.usage_page {
             Some(usage_page) => {
-                let usage = __usage_format_helper(
-                    __data_to_unsigned(self.data()),
-                    __data_to_unsigned(usage_page.data()),
-                );
+                let usage = match __resolved_name(&self.name) {
+                    Some(name) => UsageName::Named(name),
+                    None => __usage_format_helper(
+                        __usage_id(self.data()),
+                        __data_to_unsigned(usage_page.data()),
+                    ),
+                };
                 if usage.is_empty() {
                     write!(f, "Usage")
                 } else {
@@ -3416,10 +3695,13 @@ impl Display for UsageMinimum {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self.usage_page {
             Some(usage_page) => {
-                let usage = __usage_format_helper(
-                    __data_to_unsigned(self.data()),
-                    __data_to_unsigned(usage_page.data()),
-                );
+                let usage = match __resolved_name(&self.name) {
+                    Some(name) => UsageName::Named(name),
+                    None => __usage_format_helper(
+                        __usage_id(self.data()),
+                        __data_to_unsigned(usage_page.data()),
+                    ),
+                };
                 if usage.is_empty() {
                     write!(f, "Usage Minimum")
                 } else {
@@ -3435,10 +3717,13 @@ impl Display for UsageMaximum {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self.usage_page {
             Some(usage_page) => {
-                let usage = __usage_format_helper(
-                    __data_to_unsigned(self.data()),
-                    __data_to_unsigned(usage_page.data()),
-                );
+                let usage = match __resolved_name(&self.name) {
+                    Some(name) => UsageName::Named(name),
+                    None => __usage_format_helper(
+                        __usage_id(self.data()),
+                        __data_to_unsigned(usage_page.data()),
+                    ),
+                };
                 if usage.is_empty() {
                     write!(f, "Usage Maximum")
                 } else {
@@ -3454,7 +3739,12 @@ impl Display for DesignatorIndex {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.data().len() {
             0 => write!(f, "Designator Index"),
-            1.. => write!(f, "Designator Index ({})", __data_to_unsigned(self.data())),
+            1.. => write!(
+                f,
+                "Designator Index ({}{})",
+                __data_to_unsigned(self.data()),
+                __alternate_hex(f.alternate(), self.data())
+            ),
         }
     }
 }
@@ -3465,8 +3755,9 @@ impl Display for DesignatorMinimum {
             0 => write!(f, "Designator Minimum"),
             1.. => write!(
                 f,
-                "Designator Minimum ({})",
-                __data_to_unsigned(self.data())
+                "Designator Minimum ({}{})",
+                __data_to_unsigned(self.data()),
+                __alternate_hex(f.alternate(), self.data())
             ),
         }
     }
@@ -3478,8 +3769,9 @@ impl Display for DesignatorMaximum {
             0 => write!(f, "Designator Maximum"),
             1.. => write!(
                 f,
-                "Designator Maximum ({})",
-                __data_to_unsigned(self.data())
+                "Designator Maximum ({}{})",
+                __data_to_unsigned(self.data()),
+                __alternate_hex(f.alternate(), self.data())
             ),
         }
     }
@@ -3489,7 +3781,12 @@ impl Display for StringIndex {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.data().len() {
             0 => write!(f, "String Index"),
-            1.. => write!(f, "String Index ({})", __data_to_unsigned(self.data())),
+            1.. => write!(
+                f,
+                "String Index ({}{})",
+                __data_to_unsigned(self.data()),
+                __alternate_hex(f.alternate(), self.data())
+            ),
         }
     }
 }
@@ -3498,7 +3795,12 @@ impl Display for StringMinimum {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.data().len() {
             0 => write!(f, "String Minimum"),
-            1.. => write!(f, "String Minimum ({})", __data_to_unsigned(self.data())),
+            1.. => write!(
+                f,
+                "String Minimum ({}{})",
+                __data_to_unsigned(self.data()),
+                __alternate_hex(f.alternate(), self.data())
+            ),
         }
     }
 }
@@ -3507,7 +3809,12 @@ impl Display for StringMaximum {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.data().len() {
             0 => write!(f, "String Maximum"),
-            1.. => write!(f, "String Maximum ({})", __data_to_unsigned(self.data())),
+            1.. => write!(
+                f,
+                "String Maximum ({}{})",
+                __data_to_unsigned(self.data()),
+                __alternate_hex(f.alternate(), self.data())
+            ),
         }
     }
 }
@@ -3516,7 +3823,12 @@ impl Display for Delimiter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.data().len() {
             0 => write!(f, "Delimiter"),
-            1.. => write!(f, "Delimiter ({})", __data_to_unsigned(self.data())),
+            1.. => write!(
+                f,
+                "Delimiter ({}{})",
+                __data_to_unsigned(self.data()),
+                __alternate_hex(f.alternate(), self.data())
+            ),
         }
     }
 }
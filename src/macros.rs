@@ -22,10 +22,16 @@ macro_rules! __impls_for_short_items {
             /// The "size" part is set to `00` in this constant value.
             pub const PREFIX: u8 = $prefix;
 
+            /// Check whether a raw prefix byte belongs to this item, ignoring its
+            /// "size" part.
+            pub const fn matches_prefix(prefix: u8) -> bool {
+                prefix & 0b1111_1100 == Self::PREFIX
+            }
+
             /// Create an item with prefix check.
             pub fn new(raw: &[u8]) -> Result<Self, crate::HidError> {
                 if raw.is_empty() { return Err(crate::HidError::EmptyRawInput) };
-                if raw[0] & 0b1111_1100 != Self::PREFIX {
+                if !Self::matches_prefix(raw[0]) {
                     return Err(crate::HidError::PrefixNotMatch);
                 }
                 let expected = crate::__data_size(raw[0]);
@@ -42,10 +48,8 @@ macro_rules! __impls_for_short_items {
 
             /// Create an item *WITHOUT* prefix check.
             ///
-            /// # Safety
-            ///
-            /// Must ensure that the prefix part is correct.
-            pub unsafe fn new_unchecked(raw: &[u8]) -> Self {
+            /// *NOTE*: must ensure that the prefix part is correct.
+            pub fn new_unchecked(raw: &[u8]) -> Self {
                 let mut storage = [0; 5];
                 storage[..raw.len()].copy_from_slice(raw);
                 Self(storage)
@@ -95,6 +99,94 @@ macro_rules! __impls_for_short_items {
     }
 }
 
+macro_rules! __impls_for_numeric_items {
+    (signed: $($item:ident),*) => {
+        $(
+            impl $item {
+                /// Create an item with the minimal data size that can represent
+                /// the signed `value`.
+                ///
+                /// *NOTE*: `0` is encoded in 1 byte rather than 0 bytes.
+                pub fn from_value(value: i32) -> Self {
+                    let (data, size) = crate::__signed_to_data(value);
+                    Self::new_with(&data[..size]).unwrap()
+                }
+
+                /// Get the data as a signed value, 0 if there is no data.
+                pub fn value(&self) -> i32 {
+                    crate::__data_to_signed(self.data())
+                }
+
+                /// Set data to the minimal data size that can represent the signed
+                /// `value`, like [`from_value()`](Self::from_value()) does.
+                pub fn set_value_signed(&mut self, value: i32) -> &mut Self {
+                    let (data, size) = crate::__signed_to_data(value);
+                    self.set_data(&data[..size]).unwrap()
+                }
+            }
+        )*
+    };
+    (unsigned: $($item:ident),*) => {
+        $(
+            impl $item {
+                /// Create an item with the minimal data size that can represent
+                /// the unsigned `value`.
+                ///
+                /// *NOTE*: `0` is encoded in 1 byte rather than 0 bytes.
+                pub fn from_value(value: u32) -> Self {
+                    let (data, size) = crate::__unsigned_to_data(value);
+                    Self::new_with(&data[..size]).unwrap()
+                }
+
+                /// Get the data as an unsigned value, 0 if there is no data.
+                pub fn value(&self) -> u32 {
+                    crate::__data_to_unsigned(self.data())
+                }
+
+                /// Set data to the minimal data size that can represent the unsigned
+                /// `value`, like [`from_value()`](Self::from_value()) does.
+                pub fn set_value_unsigned(&mut self, value: u32) -> &mut Self {
+                    let (data, size) = crate::__unsigned_to_data(value);
+                    self.set_data(&data[..size]).unwrap()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! __impls_for_report_items {
+    ($($item:ident => $as_item:ident),*) => {
+        $(
+            impl crate::ReportItem {
+                #[doc = concat!("Borrow the inner [", stringify!($item), "](crate::", stringify!($item), "), if the item is one.")]
+                pub fn $as_item(&self) -> Option<&crate::$item> {
+                    match self {
+                        crate::ReportItem::$item(inner) => Some(inner),
+                        _ => None,
+                    }
+                }
+            }
+
+            impl From<crate::$item> for crate::ReportItem {
+                fn from(item: crate::$item) -> Self {
+                    crate::ReportItem::$item(item)
+                }
+            }
+
+            impl TryFrom<crate::ReportItem> for crate::$item {
+                type Error = crate::HidError;
+
+                fn try_from(item: crate::ReportItem) -> Result<Self, Self::Error> {
+                    match item {
+                        crate::ReportItem::$item(inner) => Ok(inner),
+                        _ => Err(crate::HidError::PrefixNotMatch),
+                    }
+                }
+            }
+        )*
+    };
+}
+
 macro_rules! __matches_bit {
     ($field:expr, $pos:literal, $zero:literal, $one:literal) => {
         match $field & (1 << $pos) {
@@ -104,5 +196,7 @@ macro_rules! __matches_bit {
     };
 }
 
+pub(crate) use __impls_for_numeric_items;
+pub(crate) use __impls_for_report_items;
 pub(crate) use __impls_for_short_items;
 pub(crate) use __matches_bit;
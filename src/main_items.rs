@@ -1,4 +1,4 @@
-use crate::macros::*;
+use crate::{__alternate_hex, macros::*};
 use std::fmt::Display;
 
 __impls_for_short_items! {
@@ -20,6 +20,19 @@ __impls_for_short_items! {
     /// * Bit 7: Reserved
     /// * Bit 8: Bit Field(0) | Buffered Bytes(1)
     /// * Bit 31-9: Reserved
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::{Input, Output};
+    ///
+    /// // Any data size of the same tag and type matches.
+    /// assert!(Input::matches_prefix(0x80));
+    /// assert!(Input::matches_prefix(0x81));
+    /// assert!(Input::matches_prefix(0x83));
+    /// assert!(!Input::matches_prefix(0x90));
+    /// assert!(Output::matches_prefix(0x91));
+    /// ```
     Input: 0b1000_0000;
     /// Refers to the data to one or more similar controls on a device
     /// such as setting the position of a single axis or a group of levers (variable data).
@@ -79,30 +92,32 @@ __impls_for_short_items! {
 
 impl Display for Input {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.data().len() {
-            0 => write!(f, "Input"),
-            1 => write!(
+        match self.data() {
+            [] => write!(f, "Input"),
+            [a] => write!(
                 f,
-                "Input ({}, {}, {}, {}, {}, {}, {})",
-                __matches_bit!(self.data()[0], 0, "Data", "Constant"),
-                __matches_bit!(self.data()[0], 1, "Array", "Variable"),
-                __matches_bit!(self.data()[0], 2, "Absolute", "Relative"),
-                __matches_bit!(self.data()[0], 3, "No Wrap", "Wrap"),
-                __matches_bit!(self.data()[0], 4, "Linear", "Non Linear"),
-                __matches_bit!(self.data()[0], 5, "Preferred State", "No Preferred"),
-                __matches_bit!(self.data()[0], 6, "No Null Position", "Null State"),
+                "Input ({}, {}, {}, {}, {}, {}, {}{})",
+                __matches_bit!(*a, 0, "Data", "Constant"),
+                __matches_bit!(*a, 1, "Array", "Variable"),
+                __matches_bit!(*a, 2, "Absolute", "Relative"),
+                __matches_bit!(*a, 3, "No Wrap", "Wrap"),
+                __matches_bit!(*a, 4, "Linear", "Non Linear"),
+                __matches_bit!(*a, 5, "Preferred State", "No Preferred"),
+                __matches_bit!(*a, 6, "No Null Position", "Null State"),
+                __alternate_hex(f.alternate(), self.data()),
             ),
-            2.. => write!(
+            [a, b, ..] => write!(
                 f,
-                "Input ({}, {}, {}, {}, {}, {}, {}, {})",
-                __matches_bit!(self.data()[0], 0, "Data", "Constant"),
-                __matches_bit!(self.data()[0], 1, "Array", "Variable"),
-                __matches_bit!(self.data()[0], 2, "Absolute", "Relative"),
-                __matches_bit!(self.data()[0], 3, "No Wrap", "Wrap"),
-                __matches_bit!(self.data()[0], 4, "Linear", "Non Linear"),
-                __matches_bit!(self.data()[0], 5, "Preferred State", "No Preferred"),
-                __matches_bit!(self.data()[0], 6, "No Null Position", "Null State"),
-                __matches_bit!(self.data()[1], 0, "Bit Field", "Buffered Bytes"),
+                "Input ({}, {}, {}, {}, {}, {}, {}, {}{})",
+                __matches_bit!(*a, 0, "Data", "Constant"),
+                __matches_bit!(*a, 1, "Array", "Variable"),
+                __matches_bit!(*a, 2, "Absolute", "Relative"),
+                __matches_bit!(*a, 3, "No Wrap", "Wrap"),
+                __matches_bit!(*a, 4, "Linear", "Non Linear"),
+                __matches_bit!(*a, 5, "Preferred State", "No Preferred"),
+                __matches_bit!(*a, 6, "No Null Position", "Null State"),
+                __matches_bit!(*b, 0, "Bit Field", "Buffered Bytes"),
+                __alternate_hex(f.alternate(), self.data()),
             ),
         }
     }
@@ -110,32 +125,34 @@ impl Display for Input {
 
 impl Display for Output {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.data().len() {
-            0 => write!(f, "Output"),
-            1 => write!(
+        match self.data() {
+            [] => write!(f, "Output"),
+            [a] => write!(
                 f,
-                "Output ({}, {}, {}, {}, {}, {}, {}, {})",
-                __matches_bit!(self.data()[0], 0, "Data", "Constant"),
-                __matches_bit!(self.data()[0], 1, "Array", "Variable"),
-                __matches_bit!(self.data()[0], 2, "Absolute", "Relative"),
-                __matches_bit!(self.data()[0], 3, "No Wrap", "Wrap"),
-                __matches_bit!(self.data()[0], 4, "Linear", "Non Linear"),
-                __matches_bit!(self.data()[0], 5, "Preferred State", "No Preferred"),
-                __matches_bit!(self.data()[0], 6, "No Null Position", "Null State"),
-                __matches_bit!(self.data()[0], 7, "Non Volatile", "Volatile"),
+                "Output ({}, {}, {}, {}, {}, {}, {}, {}{})",
+                __matches_bit!(*a, 0, "Data", "Constant"),
+                __matches_bit!(*a, 1, "Array", "Variable"),
+                __matches_bit!(*a, 2, "Absolute", "Relative"),
+                __matches_bit!(*a, 3, "No Wrap", "Wrap"),
+                __matches_bit!(*a, 4, "Linear", "Non Linear"),
+                __matches_bit!(*a, 5, "Preferred State", "No Preferred"),
+                __matches_bit!(*a, 6, "No Null Position", "Null State"),
+                __matches_bit!(*a, 7, "Non Volatile", "Volatile"),
+                __alternate_hex(f.alternate(), self.data()),
             ),
-            2.. => write!(
+            [a, b, ..] => write!(
                 f,
-                "Output ({}, {}, {}, {}, {}, {}, {}, {}, {})",
-                __matches_bit!(self.data()[0], 0, "Data", "Constant"),
-                __matches_bit!(self.data()[0], 1, "Array", "Variable"),
-                __matches_bit!(self.data()[0], 2, "Absolute", "Relative"),
-                __matches_bit!(self.data()[0], 3, "No Wrap", "Wrap"),
-                __matches_bit!(self.data()[0], 4, "Linear", "Non Linear"),
-                __matches_bit!(self.data()[0], 5, "Preferred State", "No Preferred"),
-                __matches_bit!(self.data()[0], 6, "No Null Position", "Null State"),
-                __matches_bit!(self.data()[0], 7, "Non Volatile", "Volatile"),
-                __matches_bit!(self.data()[1], 0, "Bit Field", "Buffered Bytes"),
+                "Output ({}, {}, {}, {}, {}, {}, {}, {}, {}{})",
+                __matches_bit!(*a, 0, "Data", "Constant"),
+                __matches_bit!(*a, 1, "Array", "Variable"),
+                __matches_bit!(*a, 2, "Absolute", "Relative"),
+                __matches_bit!(*a, 3, "No Wrap", "Wrap"),
+                __matches_bit!(*a, 4, "Linear", "Non Linear"),
+                __matches_bit!(*a, 5, "Preferred State", "No Preferred"),
+                __matches_bit!(*a, 6, "No Null Position", "Null State"),
+                __matches_bit!(*a, 7, "Non Volatile", "Volatile"),
+                __matches_bit!(*b, 0, "Bit Field", "Buffered Bytes"),
+                __alternate_hex(f.alternate(), self.data()),
             ),
         }
     }
@@ -143,32 +160,34 @@ impl Display for Output {
 
 impl Display for Feature {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.data().len() {
-            0 => write!(f, "Feature"),
-            1 => write!(
+        match self.data() {
+            [] => write!(f, "Feature"),
+            [a] => write!(
                 f,
-                "Feature ({}, {}, {}, {}, {}, {}, {}, {})",
-                __matches_bit!(self.data()[0], 0, "Data", "Constant"),
-                __matches_bit!(self.data()[0], 1, "Array", "Variable"),
-                __matches_bit!(self.data()[0], 2, "Absolute", "Relative"),
-                __matches_bit!(self.data()[0], 3, "No Wrap", "Wrap"),
-                __matches_bit!(self.data()[0], 4, "Linear", "Non Linear"),
-                __matches_bit!(self.data()[0], 5, "Preferred State", "No Preferred"),
-                __matches_bit!(self.data()[0], 6, "No Null Position", "Null State"),
-                __matches_bit!(self.data()[0], 7, "Non Volatile", "Volatile"),
+                "Feature ({}, {}, {}, {}, {}, {}, {}, {}{})",
+                __matches_bit!(*a, 0, "Data", "Constant"),
+                __matches_bit!(*a, 1, "Array", "Variable"),
+                __matches_bit!(*a, 2, "Absolute", "Relative"),
+                __matches_bit!(*a, 3, "No Wrap", "Wrap"),
+                __matches_bit!(*a, 4, "Linear", "Non Linear"),
+                __matches_bit!(*a, 5, "Preferred State", "No Preferred"),
+                __matches_bit!(*a, 6, "No Null Position", "Null State"),
+                __matches_bit!(*a, 7, "Non Volatile", "Volatile"),
+                __alternate_hex(f.alternate(), self.data()),
             ),
-            2.. => write!(
+            [a, b, ..] => write!(
                 f,
-                "Feature ({}, {}, {}, {}, {}, {}, {}, {}, {})",
-                __matches_bit!(self.data()[0], 0, "Data", "Constant"),
-                __matches_bit!(self.data()[0], 1, "Array", "Variable"),
-                __matches_bit!(self.data()[0], 2, "Absolute", "Relative"),
-                __matches_bit!(self.data()[0], 3, "No Wrap", "Wrap"),
-                __matches_bit!(self.data()[0], 4, "Linear", "Non Linear"),
-                __matches_bit!(self.data()[0], 5, "Preferred State", "No Preferred"),
-                __matches_bit!(self.data()[0], 6, "No Null Position", "Null State"),
-                __matches_bit!(self.data()[0], 7, "Non Volatile", "Volatile"),
-                __matches_bit!(self.data()[1], 0, "Bit Field", "Buffered Bytes"),
+                "Feature ({}, {}, {}, {}, {}, {}, {}, {}, {}{})",
+                __matches_bit!(*a, 0, "Data", "Constant"),
+                __matches_bit!(*a, 1, "Array", "Variable"),
+                __matches_bit!(*a, 2, "Absolute", "Relative"),
+                __matches_bit!(*a, 3, "No Wrap", "Wrap"),
+                __matches_bit!(*a, 4, "Linear", "Non Linear"),
+                __matches_bit!(*a, 5, "Preferred State", "No Preferred"),
+                __matches_bit!(*a, 6, "No Null Position", "Null State"),
+                __matches_bit!(*a, 7, "Non Volatile", "Volatile"),
+                __matches_bit!(*b, 0, "Bit Field", "Buffered Bytes"),
+                __alternate_hex(f.alternate(), self.data()),
             ),
         }
     }
@@ -176,27 +195,91 @@ impl Display for Feature {
 
 impl Display for Collection {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.data().len() {
-            0 => write!(f, "Collection"),
-            1.. => write!(
+        match self.data() {
+            [] => write!(f, "Collection"),
+            [_, ..] => write!(
                 f,
-                "Collection ({})",
-                match self.data()[0] {
-                    0 => "Physical",
-                    1 => "Application",
-                    2 => "Logical",
-                    3 => "Report",
-                    4 => "Named Array",
-                    5 => "Usage Switch",
-                    6 => "Usage Modifier",
-                    7..=0x7f => "Reserved",
-                    0x80..=0xff => "Vendor Defined",
-                }
+                "Collection ({}{})",
+                self.collection_type(),
+                __alternate_hex(f.alternate(), self.data())
             ),
         }
     }
 }
 
+/// Types of [Collection]s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CollectionType {
+    /// A set of data items that represent data points collected at one
+    /// geometric point.
+    Physical,
+    /// A group of main items that might be familiar to applications, e.g. mouse
+    /// or keyboard.
+    Application,
+    /// Interrelated data items, e.g. the contents of a buffer and its byte count.
+    Logical,
+    /// Wraps all the fields in a report.
+    Report,
+    /// An array of selector usages.
+    NamedArray,
+    /// Modifies the purpose or function of the usages it contains.
+    UsageSwitch,
+    /// Modifies the meaning of the usage it is attached to.
+    UsageModifier,
+    /// Reserved types `0x07-0x7F`.
+    Reserved(u8),
+    /// Vendor defined types `0x80-0xFF`.
+    VendorDefined(u8),
+}
+
+impl From<u8> for CollectionType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => CollectionType::Physical,
+            1 => CollectionType::Application,
+            2 => CollectionType::Logical,
+            3 => CollectionType::Report,
+            4 => CollectionType::NamedArray,
+            5 => CollectionType::UsageSwitch,
+            6 => CollectionType::UsageModifier,
+            7..=0x7f => CollectionType::Reserved(value),
+            0x80..=0xff => CollectionType::VendorDefined(value),
+        }
+    }
+}
+
+impl CollectionType {
+    /// Get the name of the type as used when formatting items, e.g. `"Named Array"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CollectionType::Physical => "Physical",
+            CollectionType::Application => "Application",
+            CollectionType::Logical => "Logical",
+            CollectionType::Report => "Report",
+            CollectionType::NamedArray => "Named Array",
+            CollectionType::UsageSwitch => "Usage Switch",
+            CollectionType::UsageModifier => "Usage Modifier",
+            CollectionType::Reserved(_) => "Reserved",
+            CollectionType::VendorDefined(_) => "Vendor Defined",
+        }
+    }
+}
+
+impl Display for CollectionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Collection {
+    /// Get the type of the collection.
+    ///
+    /// A collection without data is a [`Physical`](CollectionType::Physical) one.
+    pub fn collection_type(&self) -> CollectionType {
+        CollectionType::from(self.data().first().copied().unwrap_or(0))
+    }
+}
+
 impl Display for EndCollection {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "End Collection")
@@ -0,0 +1,96 @@
+use crate::{Pop, Push, ReportId, ReportItem};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+/// Concatenate report descriptors into one, e.g. to assemble a composite device
+/// from per-function descriptors.
+///
+/// Each descriptor is wrapped in [Push](crate::Push) / [Pop](crate::Pop) items, so
+/// its global items don't leak into the next one.
+///
+/// # Report IDs
+///
+/// Report IDs must be unique in the merged descriptor:
+///
+/// - A report ID is kept if no earlier descriptor declares it.
+/// - Otherwise it is renumbered to the smallest ID that no descriptor declares
+///   and that isn't assigned yet, so renumbering never causes new collisions.
+/// - When merging several descriptors, a descriptor without any report ID gets
+///   a new one in the same way, declared right after its Push item, since
+///   either all reports or none of a descriptor must be prefixed with an ID.
+///
+/// # Panics
+///
+/// Panics if more than 255 report IDs are needed.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{dump, merge, parse};
+///
+/// let keyboard = parse([0x85, 0x01, 0x75, 0x08, 0x95, 0x01, 0x81, 0x00]).collect::<Vec<_>>();
+/// let consumer = parse([0x85, 0x01, 0x75, 0x10, 0x81, 0x00]).collect::<Vec<_>>();
+/// let vendor = parse([0x75, 0x08, 0x95, 0x02, 0x81, 0x02]).collect::<Vec<_>>();
+/// let merged = merge(&[&keyboard, &consumer, &vendor]);
+/// assert_eq!(
+///     dump(&merged),
+///     [
+///         0xA4, 0x85, 0x01, 0x75, 0x08, 0x95, 0x01, 0x81, 0x00, 0xB4,
+///         0xA4, 0x85, 0x02, 0x75, 0x10, 0x81, 0x00, 0xB4,
+///         0xA4, 0x85, 0x03, 0x75, 0x08, 0x95, 0x02, 0x81, 0x02, 0xB4,
+///     ]
+/// );
+/// ```
+pub fn merge(descriptors: &[&[ReportItem]]) -> Vec<ReportItem> {
+    let declared = descriptors
+        .iter()
+        .flat_map(|items| items.iter())
+        .filter_map(__report_id)
+        .collect::<BTreeSet<_>>();
+    let mut merged = Vec::new();
+    let mut claimed = BTreeSet::new();
+    for items in descriptors {
+        merged.push(Push::default().into());
+        let own = items
+            .iter()
+            .filter_map(__report_id)
+            .collect::<BTreeSet<_>>();
+        if own.is_empty() && descriptors.len() > 1 {
+            let report_id = __fresh_report_id(&declared, &mut claimed);
+            merged.push(ReportId::from_value(report_id).into());
+        }
+        let mut renumbered = BTreeMap::new();
+        for report_id in own {
+            let new_id = if claimed.insert(report_id) {
+                report_id
+            } else {
+                __fresh_report_id(&declared, &mut claimed)
+            };
+            renumbered.insert(report_id, new_id);
+        }
+        merged.extend(items.iter().map(|item| match __report_id(item) {
+            Some(report_id) => ReportId::from_value(renumbered[&report_id]).into(),
+            None => item.clone(),
+        }));
+        merged.push(Pop::default().into());
+    }
+    merged
+}
+
+fn __report_id(item: &ReportItem) -> Option<u32> {
+    match item {
+        ReportItem::ReportId(inner) => Some(inner.value()),
+        _ => None,
+    }
+}
+
+fn __fresh_report_id(declared: &BTreeSet<u32>, claimed: &mut BTreeSet<u32>) -> u32 {
+    let report_id = (1..=u8::MAX as u32)
+        .find(|id| !declared.contains(id) && !claimed.contains(id))
+        .or_else(|| (1..=u8::MAX as u32).find(|id| !claimed.contains(id)))
+        .expect("too many report IDs");
+    claimed.insert(report_id);
+    report_id
+}
@@ -0,0 +1,154 @@
+use crate::{
+    __alternate_hex, __data_to_unsigned, __usage_page_name, CollectionType, ItemKind, ItemType,
+    ReportItem,
+};
+use std::fmt::{Display, Write};
+
+/// Names used when formatting items, so that tooling can swap in translations.
+///
+/// Every method defaults to the English name used by [Display], see [English].
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, ItemKind, NameProvider};
+///
+/// struct French;
+///
+/// impl NameProvider for French {
+///     fn item_kind_name(&self, kind: ItemKind) -> &str {
+///         match kind {
+///             ItemKind::UsagePage => "Page d'usage",
+///             ItemKind::Collection => "Collection",
+///             ItemKind::ReportSize => "Taille du rapport",
+///             _ => kind.name(),
+///         }
+///     }
+///
+///     fn usage_page_name(&self, usage_page: u32) -> &str {
+///         match usage_page {
+///             0x01 => "Bureau générique",
+///             _ => "Inconnue",
+///         }
+///     }
+/// }
+///
+/// let items = parse([0x05, 0x01, 0xA1, 0x01, 0x75, 0x08]).collect::<Vec<_>>();
+/// assert_eq!(items[0].display_with(&French).to_string(), "Page d'usage (Bureau générique)");
+/// assert_eq!(format!("{:#}", items[0].display_with(&French)), "Page d'usage (Bureau générique [0x01])");
+/// assert_eq!(items[1].display_with(&French).to_string(), "Collection (Application)");
+/// assert_eq!(items[2].display_with(&French).to_string(), "Taille du rapport (8)");
+/// ```
+pub trait NameProvider {
+    /// Get the name of an item kind, e.g. `"Usage Page"`.
+    fn item_kind_name(&self, kind: ItemKind) -> &str {
+        kind.name()
+    }
+
+    /// Get the name of a usage page, e.g. `"Generic Desktop"`.
+    fn usage_page_name(&self, usage_page: u32) -> &str {
+        __usage_page_name(usage_page)
+    }
+
+    /// Get the name of a collection type, e.g. `"Application"`.
+    fn collection_type_name(&self, collection_type: CollectionType) -> &str {
+        collection_type.name()
+    }
+}
+
+/// The English names used by [Display].
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, English};
+///
+/// let bytes = [
+///     0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x75, 0x08, 0x81, 0x02, 0xC0, 0xF4,
+///     0xFE, 0x02, 0xF0, 0xAA, 0xBB, 0xF0,
+/// ];
+/// for item in parse(bytes) {
+///     assert_eq!(item.display_with(&English).to_string(), item.to_string());
+///     assert_eq!(format!("{:#}", item.display_with(&English)), format!("{:#}", item));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct English;
+
+impl NameProvider for English {}
+
+/// Formats a [ReportItem] with the names of a [NameProvider], see
+/// [`ReportItem::display_with()`].
+pub struct DisplayWith<'a, P: NameProvider + ?Sized> {
+    pub(crate) item: &'a ReportItem,
+    pub(crate) provider: &'a P,
+}
+
+impl<P: NameProvider + ?Sized> Display for DisplayWith<'_, P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = self.item.kind();
+        let data = self.item.data();
+        if let ReportItem::Reserved(inner) = self.item {
+            // Long items aren't named after their kind.
+            if inner.long_item().is_some() {
+                return write!(f, "{}", inner);
+            }
+        }
+        write!(f, "{}", self.provider.item_kind_name(kind))?;
+        match (self.item, data) {
+            (ReportItem::UsagePage(_), [_, ..]) => write!(
+                f,
+                " ({}{})",
+                self.provider.usage_page_name(__data_to_unsigned(data)),
+                __alternate_hex(f.alternate(), data)
+            ),
+            (ReportItem::Collection(inner), [_, ..]) => write!(
+                f,
+                " ({}{})",
+                self.provider.collection_type_name(inner.collection_type()),
+                __alternate_hex(f.alternate(), data)
+            ),
+            (ReportItem::Reserved(inner), _) => {
+                let item_type = match inner.item_type() {
+                    ItemType::Main => "Main",
+                    ItemType::Global => "Global",
+                    ItemType::Local => "Local",
+                    ItemType::Reserved => "Reserved",
+                };
+                write!(f, " ({} tag 0x{:X})", item_type, inner.tag())
+            }
+            // Other items only differ in the kind name, so reuse their Display,
+            // which starts with the English kind name.
+            (item, _) => {
+                let alternate = f.alternate();
+                let mut writer = SkipPrefix {
+                    f,
+                    skip: kind.name().len(),
+                };
+                match alternate {
+                    true => write!(writer, "{:#}", item),
+                    false => write!(writer, "{}", item),
+                }
+            }
+        }
+    }
+}
+
+/// Drops the first `skip` bytes written, i.e. the English kind name.
+struct SkipPrefix<'a, 'b> {
+    f: &'a mut std::fmt::Formatter<'b>,
+    skip: usize,
+}
+
+impl Write for SkipPrefix<'_, '_> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        if self.skip >= s.len() {
+            self.skip -= s.len();
+            Ok(())
+        } else {
+            let rest = &s[self.skip..];
+            self.skip = 0;
+            self.f.write_str(rest)
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{
+    __data_to_signed, __data_to_unsigned, __extended_usage, __signed_to_data, __unsigned_to_data,
+    Collection, Delimiter, DesignatorIndex, DesignatorMaximum, DesignatorMinimum, EndCollection,
+    Feature, GlobalState, Input, LogicalMaximum, LogicalMinimum, Output, PhysicalMaximum,
+    PhysicalMinimum, ReportCount, ReportId, ReportItem, ReportSize, StringIndex, StringMaximum,
+    StringMinimum, Unit, UnitExponent, Usage, UsageMaximum, UsageMinimum, UsagePage,
+};
+use alloc::vec::Vec;
+
+/// Resolved values of the global items emitted so far. Items missing from the
+/// original descriptor count as 0, like [`field_map()`](crate::field_map()) does,
+/// except for the report ID and the physical range.
+#[derive(Default)]
+struct Emitted {
+    report_id: Option<u32>,
+    logical_minimum: i32,
+    logical_maximum: i32,
+    physical_minimum: Option<i32>,
+    physical_maximum: Option<i32>,
+    unit_exponent: i32,
+    unit: u32,
+    report_size: u32,
+    report_count: u32,
+}
+
+impl Emitted {
+    fn sync_globals(&mut self, items: &mut Vec<ReportItem>, state: &GlobalState) {
+        let report_id = state.report_id.as_ref().map(ReportId::value);
+        if let Some(value) = report_id.filter(|_| report_id != self.report_id) {
+            self.report_id = report_id;
+            items.push(ReportId::from_value(value).into());
+        }
+        let logical_minimum = state
+            .logical_minimum
+            .as_ref()
+            .map_or(0, |item| __data_to_signed(item.data()));
+        __sync(items, &mut self.logical_minimum, logical_minimum, |value| {
+            LogicalMinimum::from_value(value).into()
+        });
+        let logical_maximum = state
+            .logical_maximum
+            .as_ref()
+            .map_or(0, |item| __data_to_signed(item.data()));
+        __sync(items, &mut self.logical_maximum, logical_maximum, |value| {
+            LogicalMaximum::from_value(value).into()
+        });
+        let physical_minimum = state
+            .physical_minimum
+            .as_ref()
+            .map(|item| __data_to_signed(item.data()));
+        __sync(
+            items,
+            &mut self.physical_minimum,
+            physical_minimum,
+            |value| {
+                value
+                    .map_or_else(PhysicalMinimum::default, PhysicalMinimum::from_value)
+                    .into()
+            },
+        );
+        let physical_maximum = state
+            .physical_maximum
+            .as_ref()
+            .map(|item| __data_to_signed(item.data()));
+        __sync(
+            items,
+            &mut self.physical_maximum,
+            physical_maximum,
+            |value| {
+                value
+                    .map_or_else(PhysicalMaximum::default, PhysicalMaximum::from_value)
+                    .into()
+            },
+        );
+        // Exponents out of the 4-bit range keep their value, which can't collide
+        // with a decoded exponent.
+        let unit_exponent = state.unit_exponent.as_ref().map_or(0, |item| {
+            item.exponent()
+                .unwrap_or_else(|| __data_to_signed(item.data()))
+        });
+        __sync(items, &mut self.unit_exponent, unit_exponent, |value| {
+            let (data, size) = match value {
+                -8..=7 => ([value as u8 & 0x0F, 0, 0, 0], 1),
+                _ => __signed_to_data(value),
+            };
+            UnitExponent::new_with(&data[..size]).unwrap().into()
+        });
+        let unit = state
+            .unit
+            .as_ref()
+            .map_or(0, |item| __data_to_unsigned(item.data()));
+        __sync(items, &mut self.unit, unit, |value| {
+            Unit::from_value(value).into()
+        });
+        let report_size = state
+            .report_size
+            .as_ref()
+            .map_or(0, |item| __data_to_unsigned(item.data()));
+        __sync(items, &mut self.report_size, report_size, |value| {
+            ReportSize::from_value(value).into()
+        });
+        let report_count = state
+            .report_count
+            .as_ref()
+            .map_or(0, |item| __data_to_unsigned(item.data()));
+        __sync(items, &mut self.report_count, report_count, |value| {
+            ReportCount::from_value(value).into()
+        });
+    }
+}
+
+fn __sync<T: PartialEq + Copy>(
+    items: &mut Vec<ReportItem>,
+    emitted: &mut T,
+    current: T,
+    item: impl FnOnce(T) -> ReportItem,
+) {
+    if *emitted != current {
+        *emitted = current;
+        items.push(item(current));
+    }
+}
+
+/// Re-emit a descriptor in a canonical form, e.g. before diffing or hashing
+/// descriptors.
+///
+/// * Every item uses its minimal data size, and usages are declared by their 16-bit
+///   usage ID after a [Usage Page](UsagePage) item rather than as extended usages.
+/// * Global items are declared right before the [Input], [Output] or [Feature]
+///   item they apply to, in a fixed order, and only if their value changes.
+///   [Push](crate::Push) and [Pop](crate::Pop) items are resolved away.
+/// * Local items keep their order, and only those declaring a main item are kept.
+///
+/// The result is [semantically equal](crate::semantically_equal()) to the
+/// original descriptor, and descriptors differing only in the ways above normalize
+/// to identical bytes.
+///
+/// A [Pop](crate::Pop) back to a state without [Report ID](ReportId) can't be
+/// expressed without Push and Pop, so the report ID stays declared.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{dump, normalize, parse, semantically_equal};
+///
+/// // Globals in another order, a redundant Report Size, a 4-byte Logical Maximum,
+/// // and an extended usage inside Push and Pop.
+/// let a = parse([
+///     0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x75, 0x08, 0x95, 0x01, 0x15, 0x00,
+///     0x27, 0x7F, 0x00, 0x00, 0x00, 0xA4, 0x75, 0x08, 0x0B, 0x30, 0x00, 0x01, 0x00,
+///     0x81, 0x02, 0xB4, 0xC0,
+/// ])
+/// .collect::<Vec<_>>();
+/// let b = parse([
+///     0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x25, 0x7F, 0x75, 0x08, 0x95, 0x01,
+///     0x09, 0x30, 0x81, 0x02, 0xC0,
+/// ])
+/// .collect::<Vec<_>>();
+/// assert_eq!(dump(&normalize(&a)), dump(&normalize(&b)));
+/// assert_eq!(
+///     dump(&normalize(&a)),
+///     [
+///         0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x25, 0x7F, 0x75, 0x08, 0x95, 0x01,
+///         0x09, 0x30, 0x81, 0x02, 0xC0,
+///     ]
+/// );
+/// assert!(semantically_equal(&a, &normalize(&a)));
+///
+/// // Unit Exponent (-2) encoded as a full signed byte becomes a nibble.
+/// let a = parse([0x55, 0xFE, 0x75, 0x08, 0x95, 0x01, 0x81, 0x02]).collect::<Vec<_>>();
+/// assert_eq!(dump(&normalize(&a)), [0x55, 0x0E, 0x75, 0x08, 0x95, 0x01, 0x81, 0x02]);
+/// assert!(semantically_equal(&a, &normalize(&a)));
+/// ```
+pub fn normalize(items: &[ReportItem]) -> Vec<ReportItem> {
+    let mut normalized = Vec::new();
+    let mut state = GlobalState::new();
+    let mut emitted = Emitted::default();
+    let mut usage_page = None;
+    // Pending local items, and the usage page in effect after them.
+    let mut locals = Vec::new();
+    let mut locals_usage_page = None;
+    for item in items {
+        let data = __data_to_unsigned(item.data());
+        let (bytes, size) = __unsigned_to_data(data);
+        match item {
+            ReportItem::Input(_) | ReportItem::Output(_) | ReportItem::Feature(_) => {
+                emitted.sync_globals(&mut normalized, &state);
+                normalized.append(&mut locals);
+                usage_page = locals_usage_page;
+                normalized.push(match item {
+                    ReportItem::Input(_) => Input::new_with(&bytes[..size]).unwrap().into(),
+                    ReportItem::Output(_) => Output::new_with(&bytes[..size]).unwrap().into(),
+                    _ => Feature::new_with(&bytes[..size]).unwrap().into(),
+                });
+            }
+            ReportItem::Collection(inner) => {
+                normalized.append(&mut locals);
+                usage_page = locals_usage_page;
+                let collection_type = inner.data().first().copied().unwrap_or(0);
+                normalized.push(Collection::new_with(&[collection_type]).unwrap().into());
+            }
+            ReportItem::EndCollection(_) => {
+                locals.clear();
+                locals_usage_page = usage_page;
+                normalized.push(EndCollection::default().into());
+            }
+            ReportItem::Usage(_) | ReportItem::UsageMinimum(_) | ReportItem::UsageMaximum(_) => {
+                let usage = __extended_usage(item.data(), &state);
+                let page = UsagePage::from_value(usage >> 16);
+                if locals_usage_page != Some(usage >> 16) {
+                    locals_usage_page = Some(usage >> 16);
+                    locals.push(page.clone().into());
+                }
+                let id = usage & 0xFFFF;
+                locals.push(match item {
+                    ReportItem::Usage(_) => {
+                        let mut usage = Usage::from_value(id);
+                        usage.set_usage_page(page);
+                        usage.into()
+                    }
+                    ReportItem::UsageMinimum(_) => {
+                        let mut usage = UsageMinimum::from_value(id);
+                        usage.set_usage_page(page);
+                        usage.into()
+                    }
+                    _ => {
+                        let mut usage = UsageMaximum::from_value(id);
+                        usage.set_usage_page(page);
+                        usage.into()
+                    }
+                });
+            }
+            ReportItem::DesignatorIndex(_) => locals.push(DesignatorIndex::from_value(data).into()),
+            ReportItem::DesignatorMinimum(_) => {
+                locals.push(DesignatorMinimum::from_value(data).into())
+            }
+            ReportItem::DesignatorMaximum(_) => {
+                locals.push(DesignatorMaximum::from_value(data).into())
+            }
+            ReportItem::StringIndex(_) => locals.push(StringIndex::from_value(data).into()),
+            ReportItem::StringMinimum(_) => locals.push(StringMinimum::from_value(data).into()),
+            ReportItem::StringMaximum(_) => locals.push(StringMaximum::from_value(data).into()),
+            ReportItem::Delimiter(_) => locals.push(Delimiter::from_value(data).into()),
+            ReportItem::Reserved(_) => normalized.push(item.clone()),
+            _ => state.update(item),
+        }
+    }
+    normalized
+}
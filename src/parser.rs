@@ -0,0 +1,76 @@
+use crate::{__data_size, __tag_usage_page, GlobalState, ReportItem, Reserved};
+
+/// A push-model parser, fed one byte at a time, e.g. as bytes arrive from an
+/// endpoint.
+///
+/// Like [`parse()`](crate::parse()), unrecognized items are yielded as
+/// [`Reserved`](ReportItem::Reserved), and usages are tagged with the current
+/// [Usage Page](crate::UsagePage). The data of [long items](Reserved#long-items)
+/// is skipped once their placeholder is yielded.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::Parser;
+///
+/// let mut parser = Parser::new();
+/// assert_eq!(parser.push_byte(0x05), None);
+/// assert_eq!(parser.pending(), [0x05]);
+/// let item = parser.push_byte(0x0C).unwrap();
+/// assert_eq!(item.to_string(), "Usage Page (Consumer)");
+/// assert!(parser.pending().is_empty());
+///
+/// let items = [0x09, 0x01, 0xC0]
+///     .into_iter()
+///     .filter_map(|byte| parser.push_byte(byte))
+///     .map(|item| item.to_string())
+///     .collect::<Vec<_>>();
+/// assert_eq!(items, ["Usage (Consumer Control)", "End Collection"]);
+///
+/// let items = [0xFE, 0x02, 0xF0, 0xC0, 0xC0, 0xC0]
+///     .into_iter()
+///     .filter_map(|byte| parser.push_byte(byte))
+///     .map(|item| item.to_string())
+///     .collect::<Vec<_>>();
+/// assert_eq!(items, ["Long Item (tag 0xF0, 2 bytes)", "End Collection"]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Parser {
+    buffer: [u8; 5],
+    len: usize,
+    skip: u8,
+    state: GlobalState,
+}
+
+impl Parser {
+    /// Create a parser expecting the first byte of an item.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed a byte, and get the item it completes, if any.
+    pub fn push_byte(&mut self, byte: u8) -> Option<ReportItem> {
+        if self.skip > 0 {
+            self.skip -= 1;
+            return None;
+        }
+        self.buffer[self.len] = byte;
+        self.len += 1;
+        if self.len < __data_size(self.buffer[0]) + 1 {
+            return None;
+        }
+        let mut item = ReportItem::new_unchecked(&self.buffer[..self.len]);
+        self.len = 0;
+        if self.buffer[0] == Reserved::LONG_ITEM_PREFIX {
+            self.skip = self.buffer[1];
+        }
+        self.state.update(&item);
+        __tag_usage_page(&mut item, self.state.usage_page.as_ref());
+        Some(item)
+    }
+
+    /// Get the bytes of the incomplete item buffered so far.
+    pub fn pending(&self) -> &[u8] {
+        &self.buffer[..self.len]
+    }
+}
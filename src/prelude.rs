@@ -0,0 +1,19 @@
+//! The most commonly used items, to be glob imported.
+//!
+//! # Example
+//!
+//! ```
+//! use hid_report::prelude::*;
+//!
+//! let items = parse([0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0xC0]).collect::<Vec<_>>();
+//! assert!(matches!(items[2], ReportItem::Collection(_)));
+//! #[cfg(feature = "alloc")]
+//! {
+//!     assert!(lint(&items).is_empty());
+//!     assert_eq!(dump(&items), [0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0xC0]);
+//! }
+//! ```
+
+#[cfg(feature = "alloc")]
+pub use crate::{dump, lint, pretty_print, Diagnostic, Severity};
+pub use crate::{parse, parse_slice, parse_strict, HidError, ItemKind, ReportItem};
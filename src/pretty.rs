@@ -0,0 +1,410 @@
+use crate::{HidError, ReportItem};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Print items to string in a pretty way.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, pretty_print};
+///
+/// let bytes = [
+///     0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0x85, 0x02, 0x19,
+///     0x00, 0x2A, 0x3C, 0x02, 0x15, 0x00, 0x26, 0x3C, 0x02,
+///     0x95, 0x01, 0x75, 0x10, 0x81, 0x00, 0xC0,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+///
+/// const EXPECTED: &str = indoc::indoc! {"
+///     0x05, 0x0C        // Usage Page (Consumer)
+///     0x09, 0x01        // Usage (Consumer Control)
+///     0xA1, 0x01        //   Collection (Application)
+///     0x85, 0x02        //   Report ID (2)
+///     0x19, 0x00        //   Usage Minimum (Undefined)
+///     0x2A, 0x3C, 0x02  //   Usage Maximum (AC Format)
+///     0x15, 0x00        //   Logical Minimum (0)
+///     0x26, 0x3C, 0x02  //   Logical Maximum (572)
+///     0x95, 0x01        //   Report Count (1)
+///     0x75, 0x10        //   Report Size (16)
+///     0x81, 0x00        //   Input (Data, Array, Absolute, No Wrap, Linear, Preferred State, No Null Position)
+///     0xC0              // End Collection"
+/// };
+///
+/// assert_eq!(pretty_print(&items), EXPECTED);
+/// ```
+///
+/// Degenerate descriptors are printed as is: an empty descriptor yields an empty
+/// string, and unbalanced [End Collection](crate::EndCollection) or [Pop](crate::Pop)
+/// items never indent below the first column.
+///
+/// ```
+/// use hid_report::{parse, pretty_print};
+///
+/// assert_eq!(pretty_print(&[]), "");
+///
+/// let items = parse([0xC0]).collect::<Vec<_>>();
+/// assert_eq!(pretty_print(&items), "0xC0  // End Collection");
+///
+/// let items = parse([0xA4]).collect::<Vec<_>>();
+/// assert_eq!(pretty_print(&items), "0xA4  //   Push");
+///
+/// let items = parse([0xC0, 0xA1, 0x00, 0xC0]).collect::<Vec<_>>();
+/// const EXPECTED: &str = indoc::indoc! {"
+///     0xC0        // End Collection
+///     0xA1, 0x00  //   Collection (Physical)
+///     0xC0        // End Collection"
+/// };
+/// assert_eq!(pretty_print(&items), EXPECTED);
+/// ```
+pub fn pretty_print<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+    item_stream: ItemStream,
+) -> String {
+    pretty_print_with(item_stream, &PrettyOptions::default())
+}
+
+/// Print items to string like [`pretty_print()`], but fail on unbalanced
+/// [Collection](crate::Collection) / [End Collection](crate::EndCollection) or
+/// [Push](crate::Push) / [Pop](crate::Pop) items instead of printing a misaligned
+/// layout.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, pretty_print, pretty_print_checked, HidError};
+///
+/// let items = parse([0xA1, 0x01, 0xC0]).collect::<Vec<_>>();
+/// assert_eq!(pretty_print_checked(&items), Ok(pretty_print(&items)));
+///
+/// let items = parse([0xA1, 0x01, 0xC0, 0xC0]).collect::<Vec<_>>();
+/// assert_eq!(pretty_print_checked(&items), Err(HidError::UnbalancedItem { index: 2 }));
+///
+/// let items = parse([0xA4, 0xA1, 0x01, 0xB4]).collect::<Vec<_>>();
+/// assert_eq!(pretty_print_checked(&items), Err(HidError::UnbalancedItem { index: 1 }));
+/// ```
+pub fn pretty_print_checked<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+    item_stream: ItemStream,
+) -> Result<String, HidError>
+where
+    ItemStream::IntoIter: Clone,
+{
+    let items = item_stream.into_iter();
+    let mut collections = Vec::new();
+    let mut pushes = Vec::new();
+    for (index, item) in items.clone().enumerate() {
+        let closed = match item {
+            ReportItem::Collection(_) => {
+                collections.push(index);
+                continue;
+            }
+            ReportItem::Push(_) => {
+                pushes.push(index);
+                continue;
+            }
+            ReportItem::EndCollection(_) => collections.pop(),
+            ReportItem::Pop(_) => pushes.pop(),
+            _ => continue,
+        };
+        if closed.is_none() {
+            return Err(HidError::UnbalancedItem { index });
+        }
+    }
+    match std::cmp::max(collections.last(), pushes.last()) {
+        Some(&index) => Err(HidError::UnbalancedItem { index }),
+        None => Ok(pretty_print(items)),
+    }
+}
+
+/// Print items on a single line, e.g. to embed a descriptor in a log line.
+///
+/// Items are separated by spaces, and the items of a [Collection](crate::Collection)
+/// are wrapped in braces instead of an [End Collection](crate::EndCollection).
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, to_compact_string};
+///
+/// let bytes = [0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0x85, 0x02, 0x09, 0xE9, 0xC0];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// assert_eq!(
+///     to_compact_string(&items),
+///     "Usage Page (Consumer) Usage (Consumer Control) Collection (Application) { \
+///      Report ID (2) Usage (Volume Increment) }"
+/// );
+/// ```
+pub fn to_compact_string<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+    item_stream: ItemStream,
+) -> String {
+    item_stream
+        .into_iter()
+        .map(|item| match item {
+            ReportItem::Collection(_) => format!("{} {{", item),
+            ReportItem::EndCollection(_) => "}".to_string(),
+            _ => item.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Unit of indentation of [`pretty_print_with()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndentUnit {
+    /// The given number of spaces per level.
+    Spaces(usize),
+    /// A tab character per level.
+    Tab,
+}
+
+impl Default for IndentUnit {
+    fn default() -> Self {
+        IndentUnit::Spaces(2)
+    }
+}
+
+/// Options of [`pretty_print_with()`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PrettyOptions {
+    /// Whether to prefix each line with the byte offset of the item in the
+    /// descriptor, for correlating with a hex dump, `false` by default.
+    pub offsets: bool,
+    /// Unit of each indent level, 2 spaces by default.
+    pub indent_unit: IndentUnit,
+    /// Whether to indent the whole line rather than only the comment, `false` by
+    /// default.
+    pub indent_line: bool,
+}
+
+/// Print items to string in a pretty way, see [`pretty_print()`].
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, pretty_print_with, IndentUnit, PrettyOptions};
+///
+/// let items = parse([0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0xC0]).collect::<Vec<_>>();
+/// let options = PrettyOptions {
+///     offsets: true,
+///     ..Default::default()
+/// };
+///
+/// const EXPECTED: &str = indoc::indoc! {"
+///     0x0000: 0x05, 0x0C  // Usage Page (Consumer)
+///     0x0002: 0x09, 0x01  // Usage (Consumer Control)
+///     0x0004: 0xA1, 0x01  //   Collection (Application)
+///     0x0006: 0xC0        // End Collection"
+/// };
+///
+/// assert_eq!(pretty_print_with(&items, &options), EXPECTED);
+///
+/// let options = PrettyOptions {
+///     indent_unit: IndentUnit::Tab,
+///     indent_line: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     pretty_print_with(&items, &options),
+///     "0x05, 0x0C  // Usage Page (Consumer)\n\
+///      0x09, 0x01  // Usage (Consumer Control)\n\
+///      \t0xA1, 0x01  // Collection (Application)\n\
+///      0xC0        // End Collection"
+/// );
+/// ```
+pub fn pretty_print_with<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+    item_stream: ItemStream,
+    options: &PrettyOptions,
+) -> String {
+    let mut lines = pretty_lines(item_stream);
+    if lines.is_empty() {
+        return String::new();
+    }
+    if options.offsets {
+        for line in &mut lines {
+            line.raw = format!("{:#06X}: {}", line.offset, line.raw);
+        }
+    }
+    let width_of_raw = lines
+        .iter()
+        .map(|line| line.raw.len() + 2)
+        .max()
+        .unwrap_or(0);
+    __pretty_join(lines, width_of_raw, options)
+}
+
+/// Print items to string in a pretty way, with comments starting at a fixed column.
+///
+/// Unlike [`pretty_print()`], the comment column does not depend on the longest item,
+/// so adding or growing an item doesn't re-align unrelated lines. This keeps golden
+/// files of snapshot tests stable.
+///
+/// Lines whose bytes don't fit before `comment_col` push their comment to the right,
+/// keeping a two-space gap.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, pretty_print_fixed};
+///
+/// let bytes = [0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0xC0];
+/// let items = parse(bytes).collect::<Vec<_>>();
+///
+/// const EXPECTED: &str = indoc::indoc! {"
+///     0x05, 0x01            // Usage Page (Generic Desktop)
+///     0x09, 0x02            // Usage (Mouse)
+///     0xA1, 0x01            //   Collection (Application)
+///     0xC0                  // End Collection"
+/// };
+///
+/// assert_eq!(pretty_print_fixed(&items, 22), EXPECTED);
+/// ```
+pub fn pretty_print_fixed<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+    item_stream: ItemStream,
+    comment_col: usize,
+) -> String {
+    __pretty_join(
+        pretty_lines(item_stream),
+        comment_col,
+        &PrettyOptions::default(),
+    )
+}
+
+/// Print items to string in a pretty way, highlighted with ANSI colors for terminals.
+///
+/// The layout is identical to [`pretty_print()`]: the byte column is dimmed, comments
+/// of main items are blue, global items green, local items yellow and reserved items red.
+///
+/// Requires the `color` feature, which is enabled by default.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, pretty_print_colored};
+///
+/// let items = parse([0x05, 0x0C, 0xC0]).collect::<Vec<_>>();
+/// assert_eq!(
+///     pretty_print_colored(&items),
+///     "\x1b[2m0x05, 0x0C  \x1b[0m// \x1b[32mUsage Page (Consumer)\x1b[0m\n\
+///      \x1b[2m0xC0        \x1b[0m// \x1b[34mEnd Collection\x1b[0m"
+/// );
+/// ```
+#[cfg(feature = "color")]
+pub fn pretty_print_colored<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+    item_stream: ItemStream,
+) -> String {
+    use crate::ItemType;
+
+    let items = item_stream.into_iter().collect::<Vec<_>>();
+    let lines = pretty_lines(items.iter().copied());
+    let width_of_raw = lines
+        .iter()
+        .map(|line| line.raw.len() + 2)
+        .max()
+        .unwrap_or(0);
+    lines
+        .into_iter()
+        .zip(items)
+        .map(|(line, item)| {
+            let color = match item {
+                ReportItem::Reserved(_) => "\x1b[31m",
+                _ => match item.item_type() {
+                    ItemType::Main => "\x1b[34m",
+                    ItemType::Global => "\x1b[32m",
+                    ItemType::Local => "\x1b[33m",
+                    ItemType::Reserved => "\x1b[31m",
+                },
+            };
+            let tab = line.indent * 2 + 1;
+            format!(
+                "\x1b[2m{:<width_of_raw$}\x1b[0m//{:<tab$}{}{}\x1b[0m",
+                line.raw, ' ', color, line.comment
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A line of [`pretty_print()`] output in structured form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrettyLine {
+    /// Raw bytes of the item, e.g. `0x26, 0x3C, 0x02`.
+    pub raw: String,
+    /// Comment describing the item, e.g. `Logical Maximum (572)`.
+    pub comment: String,
+    /// Indent level of the comment, increased by [Collection](crate::Collection) and [Push](crate::Push) items.
+    pub indent: usize,
+    /// Byte offset of the item in the descriptor.
+    pub offset: usize,
+}
+
+/// Split items into pretty printed lines, for tools that need per-line access
+/// (e.g. to attach byte offsets in a UI) without re-parsing [`pretty_print()`] output.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, pretty_lines, PrettyLine};
+///
+/// let bytes = [0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0x26, 0x3C, 0x02, 0xC0];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// let lines = pretty_lines(&items);
+/// assert_eq!(
+///     lines[3],
+///     PrettyLine {
+///         raw: "0x26, 0x3C, 0x02".to_string(),
+///         comment: "Logical Maximum (572)".to_string(),
+///         indent: 1,
+///         offset: 6,
+///     }
+/// );
+/// assert_eq!(lines[4].indent, 0);
+/// assert_eq!(lines[4].offset, 9);
+/// ```
+pub fn pretty_lines<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+    item_stream: ItemStream,
+) -> Vec<PrettyLine> {
+    let mut lines = Vec::new();
+    let mut indent: usize = 0;
+    let mut offset = 0;
+    for item in item_stream {
+        match item {
+            ReportItem::Collection(_) | ReportItem::Push(_) => indent += 1,
+            ReportItem::EndCollection(_) | ReportItem::Pop(_) => indent = indent.saturating_sub(1),
+            _ => (),
+        }
+        lines.push(PrettyLine {
+            raw: item
+                .as_ref()
+                .iter()
+                .map(|byte| format!("{:#04X}", byte))
+                .collect::<Vec<_>>()
+                .join(", "),
+            comment: item.to_string(),
+            indent,
+            offset,
+        });
+        offset += item.as_ref().len();
+    }
+    lines
+}
+
+fn __pretty_join(lines: Vec<PrettyLine>, comment_col: usize, options: &PrettyOptions) -> String {
+    lines
+        .into_iter()
+        .map(|line| {
+            let width_of_raw = std::cmp::max(comment_col, line.raw.len() + 2);
+            let indent = match options.indent_unit {
+                IndentUnit::Spaces(count) => " ".repeat(count * line.indent),
+                IndentUnit::Tab => "\t".repeat(line.indent),
+            };
+            if options.indent_line {
+                format!("{}{:<width_of_raw$}// {}", indent, line.raw, line.comment)
+            } else {
+                format!("{:<width_of_raw$}// {}{}", line.raw, indent, line.comment)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -1,4 +1,5 @@
-use crate::HidError;
+use crate::{HidError, ItemType};
+use std::fmt::Display;
 
 pub(crate) fn __set_data_size(prefix: &mut u8, data: &[u8]) -> Result<(), HidError> {
     *prefix &= !0b11;
@@ -22,6 +23,15 @@ pub(crate) fn __data_size(prefix: u8) -> usize {
     }
 }
 
+pub(crate) fn __item_type(prefix: u8) -> ItemType {
+    match (prefix >> 2) & 0b11 {
+        0b00 => ItemType::Main,
+        0b01 => ItemType::Global,
+        0b10 => ItemType::Local,
+        _ => ItemType::Reserved,
+    }
+}
+
 pub(crate) fn __data_to_signed(data: &[u8]) -> i32 {
     match data {
         [] => 0,
@@ -39,3 +49,65 @@ pub(crate) fn __data_to_unsigned(data: &[u8]) -> u32 {
         [a, b, c, d, ..] => u32::from_le_bytes([*a, *b, *c, *d]),
     }
 }
+
+pub(crate) fn __signed_to_data(value: i32) -> ([u8; 4], usize) {
+    let size = if i8::try_from(value).is_ok() {
+        1
+    } else if i16::try_from(value).is_ok() {
+        2
+    } else {
+        4
+    };
+    (value.to_le_bytes(), size)
+}
+
+pub(crate) fn __unsigned_to_data(value: u32) -> ([u8; 4], usize) {
+    let size = if u8::try_from(value).is_ok() {
+        1
+    } else if u16::try_from(value).is_ok() {
+        2
+    } else {
+        4
+    };
+    (value.to_le_bytes(), size)
+}
+
+/// Suffix of the alternate (`{:#}`) form of items, i.e. raw data in hex.
+pub(crate) fn __alternate_hex(alternate: bool, data: &[u8]) -> AlternateHex<'_> {
+    AlternateHex { alternate, data }
+}
+
+pub(crate) struct AlternateHex<'a> {
+    alternate: bool,
+    data: &'a [u8],
+}
+
+impl Display for AlternateHex<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.alternate && !self.data.is_empty() {
+            write!(
+                f,
+                " [{:#0width$X}]",
+                __data_to_unsigned(self.data),
+                width = self.data.len() * 2 + 2
+            )
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Extended usage of a usage item, i.e. the usage page in the high 16 bits.
+#[cfg(feature = "alloc")]
+pub(crate) fn __extended_usage(data: &[u8], state: &crate::GlobalState) -> u32 {
+    match data.len() {
+        4 => __data_to_unsigned(data),
+        _ => {
+            let usage_page = state
+                .usage_page
+                .as_ref()
+                .map_or(0, |item| __data_to_unsigned(item.data()));
+            usage_page << 16 | __data_to_unsigned(data)
+        }
+    }
+}
@@ -1,8 +1,47 @@
 use std::fmt::Display;
 
-use crate::__data_size;
+use crate::{__data_size, __item_type, ItemType};
 
 /// Items that are reserved for future use.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{ItemType, Reserved};
+///
+/// let item = Reserved::new(&[0xF0]).unwrap();
+/// assert_eq!(item.tag(), 0xF);
+/// assert_eq!(item.item_type(), ItemType::Main);
+/// assert_eq!(item.to_string(), "Reserved (Main tag 0xF)");
+///
+/// let item = Reserved::new(&[0xFD, 0x00]).unwrap();
+/// assert_eq!(item.to_string(), "Reserved (Reserved tag 0xF)");
+/// ```
+///
+/// # Long Items
+///
+/// A long item starts with the prefix `0xFE`, followed by `bDataSize`,
+/// `bLongItemTag` and `bDataSize` bytes of data. Since no long item tag is
+/// defined, parsers yield a placeholder holding only the first 3 bytes and skip
+/// the data, so dumping the placeholder doesn't reproduce the long item.
+///
+/// ```
+/// use hid_report::{parse, parse_strict, HidError, ReportItem};
+///
+/// let mut items = parse([0xFE, 0x03, 0xF0, 0x01, 0x02, 0x03, 0xC0]);
+/// let item = items.next().unwrap();
+/// assert_eq!(item.as_ref(), [0xFE, 0x03, 0xF0]);
+/// assert_eq!(item.to_string(), "Long Item (tag 0xF0, 3 bytes)");
+/// if let ReportItem::Reserved(reserved) = item {
+///     assert_eq!(reserved.long_item(), Some((3, 0xF0)));
+/// }
+/// assert_eq!(items.next().unwrap().to_string(), "End Collection");
+///
+/// // Strict parsers fail on long items, and continue after them.
+/// let mut items = parse_strict([0xFE, 0x03, 0xF0, 0x01, 0x02, 0x03, 0xC0]);
+/// assert_eq!(items.next(), Some(Err(HidError::LongItem { data_size: 3, tag: 0xF0 })));
+/// assert_eq!(items.next().unwrap().unwrap().to_string(), "End Collection");
+/// ```
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Reserved([u8; 5]);
 
@@ -14,6 +53,9 @@ impl AsRef<[u8]> for Reserved {
 }
 
 impl Reserved {
+    /// Prefix of long items.
+    pub const LONG_ITEM_PREFIX: u8 = 0xFE;
+
     /// Create an item with size check.
     pub fn new(raw: &[u8]) -> Result<Self, crate::HidError> {
         if raw.is_empty() {
@@ -33,10 +75,8 @@ impl Reserved {
 
     /// Create an item without size check.
     ///
-    /// # Safety
-    ///
-    /// Must ensure that the size part in the prefix is correct.
-    pub unsafe fn new_unchecked(raw: &[u8]) -> Self {
+    /// *NOTE*: must ensure that the size part in the prefix is correct.
+    pub fn new_unchecked(raw: &[u8]) -> Self {
         let mut storage = [0; 5];
         storage[..raw.len()].copy_from_slice(raw);
         Self(storage)
@@ -53,6 +93,28 @@ impl Reserved {
         &self.0[1..end]
     }
 
+    /// Get the tag of the item, i.e. bits 4-7 of the prefix.
+    pub fn tag(&self) -> u8 {
+        (self.0[0] & 0b1111_0000) >> 4
+    }
+
+    /// Get the type of the item, decoded from its prefix.
+    ///
+    /// A reserved item may still carry a main, global or local type with an
+    /// unassigned tag.
+    pub fn item_type(&self) -> ItemType {
+        __item_type(self.0[0])
+    }
+
+    /// Get `bDataSize` and `bLongItemTag` if the item is a long item placeholder,
+    /// see [Long Items](Reserved#long-items).
+    pub fn long_item(&self) -> Option<(u8, u8)> {
+        match self.as_ref() {
+            [Self::LONG_ITEM_PREFIX, data_size, tag] => Some((*data_size, *tag)),
+            _ => None,
+        }
+    }
+
     /// Set data part of the item.
     ///
     /// *NOTE*: data size must be: 0, 1, 2 or 4.
@@ -71,9 +133,15 @@ impl Reserved {
 
 impl Display for Reserved {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.0.len() {
-            1.. => write!(f, "Reserved"),
-            0 => unreachable!(),
+        if let Some((data_size, tag)) = self.long_item() {
+            return write!(f, "Long Item (tag {:#04X}, {} bytes)", tag, data_size);
         }
+        let item_type = match self.item_type() {
+            ItemType::Main => "Main",
+            ItemType::Global => "Global",
+            ItemType::Local => "Local",
+            ItemType::Reserved => "Reserved",
+        };
+        write!(f, "Reserved ({} tag 0x{:X})", item_type, self.tag())
     }
 }
@@ -0,0 +1,167 @@
+use crate::{GlobalState, ItemType, PhysicalMaximum, PhysicalMinimum, ReportItem, UsagePage};
+use alloc::{collections::BTreeMap, vec::Vec};
+
+/// A collection that is open while splitting, with the local items declaring it.
+struct OpenCollection {
+    index: usize,
+    locals: Vec<(Option<UsagePage>, ReportItem)>,
+    collection: ReportItem,
+}
+
+/// A sub-descriptor being built, with the global items and collections it
+/// declared so far.
+#[derive(Default)]
+struct SubDescriptor {
+    items: Vec<ReportItem>,
+    state: GlobalState,
+    collections: Vec<usize>,
+}
+
+impl SubDescriptor {
+    fn push_local(&mut self, usage_page: &Option<UsagePage>, local: &ReportItem) {
+        __sync(&mut self.items, &mut self.state.usage_page, usage_page);
+        self.items.push(local.clone());
+    }
+
+    fn sync_globals(&mut self, state: &GlobalState) {
+        let items = &mut self.items;
+        let emitted = &mut self.state;
+        __sync(items, &mut emitted.usage_page, &state.usage_page);
+        __sync(items, &mut emitted.logical_minimum, &state.logical_minimum);
+        __sync(items, &mut emitted.logical_maximum, &state.logical_maximum);
+        if state.physical_minimum.is_none() && emitted.physical_minimum.take().is_some() {
+            items.push(PhysicalMinimum::default().into());
+        }
+        if state.physical_maximum.is_none() && emitted.physical_maximum.take().is_some() {
+            items.push(PhysicalMaximum::default().into());
+        }
+        __sync(
+            items,
+            &mut emitted.physical_minimum,
+            &state.physical_minimum,
+        );
+        __sync(
+            items,
+            &mut emitted.physical_maximum,
+            &state.physical_maximum,
+        );
+        __sync(items, &mut emitted.unit_exponent, &state.unit_exponent);
+        __sync(items, &mut emitted.unit, &state.unit);
+        __sync(items, &mut emitted.report_size, &state.report_size);
+        __sync(items, &mut emitted.report_id, &state.report_id);
+        __sync(items, &mut emitted.report_count, &state.report_count);
+    }
+}
+
+fn __sync<T: Clone + PartialEq + Into<ReportItem>>(
+    items: &mut Vec<ReportItem>,
+    emitted: &mut Option<T>,
+    current: &Option<T>,
+) {
+    if let Some(current) = current {
+        if emitted.as_ref() != Some(current) {
+            items.push(current.clone().into());
+            *emitted = Some(current.clone());
+        }
+    }
+}
+
+/// Split a descriptor declaring several [Report ID](crate::ReportId)s into one
+/// standalone descriptor per report, keyed by report ID.
+///
+/// Each sub-descriptor contains the main items of its report, wrapped in the
+/// collections enclosing them and preceded by the local items declaring them.
+/// Global items are re-declared explicitly wherever they change, instead of
+/// relying on [Push](crate::Push) and [Pop](crate::Pop), so each sub-descriptor
+/// parses independently.
+///
+/// Main items declared before any Report ID are keyed by 0, which is reserved as
+/// a report ID by the HID specification.
+///
+/// Report IDs are a single byte, so only the first data byte of a Report ID item
+/// is used as key; [`lint()`](crate::lint()) reports wider values as
+/// [`Diagnostic::InvalidReportId`](crate::Diagnostic::InvalidReportId).
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{dump, field_map, parse, split_by_report};
+///
+/// let bytes = [
+///     0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x75, 0x08, 0x95, 0x01, 0x85, 0x01,
+///     0x09, 0x30, 0x81, 0x02, 0x85, 0x02, 0x09, 0x31, 0x81, 0x02, 0xC0,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// let reports = split_by_report(&items);
+/// assert_eq!(reports.keys().copied().collect::<Vec<_>>(), [1, 2]);
+/// assert_eq!(
+///     dump(&reports[&2]),
+///     [
+///         0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x09, 0x31, 0x75, 0x08, 0x85, 0x02,
+///         0x95, 0x01, 0x81, 0x02, 0xC0,
+///     ]
+/// );
+///
+/// // The sub-descriptor declares the same field as the original one.
+/// let original = field_map(&items);
+/// let split = field_map(&parse(dump(&reports[&2])).collect::<Vec<_>>());
+/// assert_eq!(split, original[1..]);
+/// ```
+pub fn split_by_report(items: &[ReportItem]) -> BTreeMap<u8, Vec<ReportItem>> {
+    let mut reports: BTreeMap<u8, SubDescriptor> = BTreeMap::new();
+    let mut state = GlobalState::new();
+    let mut collections: Vec<OpenCollection> = Vec::new();
+    let mut locals = Vec::new();
+    for (index, item) in items.iter().enumerate() {
+        match item {
+            ReportItem::Collection(_) => collections.push(OpenCollection {
+                index,
+                locals: std::mem::take(&mut locals),
+                collection: item.clone(),
+            }),
+            ReportItem::EndCollection(_) => {
+                if let Some(closed) = collections.pop() {
+                    for report in reports.values_mut() {
+                        if report.collections.last() == Some(&closed.index) {
+                            report.collections.pop();
+                            report.items.push(item.clone());
+                        }
+                    }
+                }
+                locals.clear();
+            }
+            ReportItem::Input(_) | ReportItem::Output(_) | ReportItem::Feature(_) => {
+                let report_id = state
+                    .report_id
+                    .as_ref()
+                    .map_or(0, |item| item.value() as u8);
+                let report = reports.entry(report_id).or_default();
+                for open in &collections[report.collections.len()..] {
+                    for (usage_page, local) in &open.locals {
+                        report.push_local(usage_page, local);
+                    }
+                    report.items.push(open.collection.clone());
+                    report.collections.push(open.index);
+                }
+                for (usage_page, local) in locals.drain(..) {
+                    report.push_local(&usage_page, &local);
+                }
+                report.sync_globals(&state);
+                report.items.push(item.clone());
+            }
+            _ if item.item_type() == ItemType::Local => {
+                locals.push((state.usage_page.clone(), item.clone()))
+            }
+            _ => state.update(item),
+        }
+    }
+    reports
+        .into_iter()
+        .map(|(report_id, mut report)| {
+            for _ in 0..report.collections.len() {
+                report.items.push(crate::EndCollection::default().into());
+            }
+            (report_id, report.items)
+        })
+        .collect()
+}
@@ -0,0 +1,247 @@
+use crate::{
+    __data_to_signed, LogicalMaximum, LogicalMinimum, PhysicalMaximum, PhysicalMinimum,
+    ReportCount, ReportId, ReportItem, ReportSize, Unit, UnitExponent, UsagePage,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Global items in effect at some point of a descriptor, `None` if not set yet,
+/// along with the states saved by [Push](crate::Push) items.
+///
+/// Saving states needs the `alloc` feature, without it [Push](crate::Push) and
+/// [Pop](crate::Pop) items are ignored.
+///
+/// Feed items to [`update()`](GlobalState::update()) to replay a descriptor.
+///
+/// The parsers track this state too, so usages are named after the usage page
+/// restored by [Pop](crate::Pop):
+///
+/// ```
+/// use hid_report::parse;
+///
+/// let bytes = [0x05, 0x01, 0xA4, 0x05, 0x09, 0x09, 0x01, 0xB4, 0x09, 0x01];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// assert_eq!(items[3].to_string(), "Usage (Button 1)");
+/// #[cfg(feature = "alloc")]
+/// assert_eq!(items[5].to_string(), "Usage (Pointer)");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GlobalState {
+    /// Current [Usage Page](UsagePage).
+    pub usage_page: Option<UsagePage>,
+    /// Current [Logical Minimum](LogicalMinimum).
+    pub logical_minimum: Option<LogicalMinimum>,
+    /// Current [Logical Maximum](LogicalMaximum).
+    pub logical_maximum: Option<LogicalMaximum>,
+    /// Current [Physical Minimum](PhysicalMinimum).
+    ///
+    /// A Physical Minimum without data resets it to `None`, unlike one with value 0.
+    pub physical_minimum: Option<PhysicalMinimum>,
+    /// Current [Physical Maximum](PhysicalMaximum).
+    ///
+    /// A Physical Maximum without data resets it to `None`, unlike one with value 0.
+    pub physical_maximum: Option<PhysicalMaximum>,
+    /// Current [Unit Exponent](UnitExponent).
+    pub unit_exponent: Option<UnitExponent>,
+    /// Current [Unit].
+    pub unit: Option<Unit>,
+    /// Current [Report Size](ReportSize).
+    pub report_size: Option<ReportSize>,
+    /// Current [Report ID](ReportId).
+    pub report_id: Option<ReportId>,
+    /// Current [Report Count](ReportCount).
+    pub report_count: Option<ReportCount>,
+    #[cfg(feature = "alloc")]
+    stack: Vec<GlobalState>,
+}
+
+impl GlobalState {
+    /// Create a state where no global item is set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Save a copy of the current state onto the stack, like a [Push](crate::Push) item.
+    #[cfg(feature = "alloc")]
+    pub fn push(&mut self) {
+        let stack = std::mem::take(&mut self.stack);
+        let snapshot = self.clone();
+        self.stack = stack;
+        self.stack.push(snapshot);
+    }
+
+    /// Restore the state last saved by [`push()`](GlobalState::push()), like a
+    /// [Pop](crate::Pop) item.
+    ///
+    /// Returns `false` and keeps the state unchanged if the stack is empty.
+    #[cfg(feature = "alloc")]
+    pub fn pop(&mut self) -> bool {
+        match self.stack.pop() {
+            Some(snapshot) => {
+                let stack = std::mem::take(&mut self.stack);
+                *self = snapshot;
+                self.stack = stack;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get the number of states saved on the stack.
+    pub fn depth(&self) -> usize {
+        #[cfg(feature = "alloc")]
+        return self.stack.len();
+        #[cfg(not(feature = "alloc"))]
+        0
+    }
+
+    /// Get the physical range used to scale logical values, which equals the logical
+    /// range unless both physical minimum and maximum are set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::{parse, GlobalState};
+    ///
+    /// let mut state = GlobalState::new();
+    /// for item in parse([0x15, 0x00, 0x25, 0x7F, 0x35, 0x00, 0x46, 0xE8, 0x03]) {
+    ///     state.update(&item);
+    /// }
+    /// assert_eq!(state.physical_range(), Some((0, 1000)));
+    ///
+    /// // Physical Minimum and Maximum without data reset the physical range.
+    /// for item in parse([0x34, 0x44]) {
+    ///     state.update(&item);
+    /// }
+    /// assert_eq!(state.physical_minimum, None);
+    /// assert_eq!(state.physical_range(), Some((0, 127)));
+    /// ```
+    pub fn physical_range(&self) -> Option<(i32, i32)> {
+        match (&self.physical_minimum, &self.physical_maximum) {
+            (Some(minimum), Some(maximum)) => Some((
+                __data_to_signed(minimum.data()),
+                __data_to_signed(maximum.data()),
+            )),
+            _ => match (&self.logical_minimum, &self.logical_maximum) {
+                (Some(minimum), Some(maximum)) => Some((
+                    __data_to_signed(minimum.data()),
+                    __data_to_signed(maximum.data()),
+                )),
+                _ => None,
+            },
+        }
+    }
+
+    /// Apply a global item to the state, including [Push](crate::Push) and
+    /// [Pop](crate::Pop). Other items are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hid_report::{parse, GlobalState};
+    ///
+    /// let mut state = GlobalState::new();
+    /// for item in parse([0x75, 0x08, 0xA4, 0x75, 0x10]) {
+    ///     state.update(&item);
+    /// }
+    /// assert_eq!(state.report_size.as_ref().unwrap().to_string(), "Report Size (16)");
+    /// #[cfg(feature = "alloc")]
+    /// {
+    ///     assert_eq!(state.depth(), 1);
+    ///     assert!(state.pop());
+    ///     assert_eq!(state.report_size.as_ref().unwrap().to_string(), "Report Size (8)");
+    ///     assert!(!state.pop());
+    /// }
+    /// ```
+    pub fn update(&mut self, item: &ReportItem) {
+        match item {
+            #[cfg(feature = "alloc")]
+            ReportItem::Push(_) => self.push(),
+            #[cfg(feature = "alloc")]
+            ReportItem::Pop(_) => {
+                self.pop();
+            }
+            ReportItem::UsagePage(inner) => self.usage_page = Some(inner.clone()),
+            ReportItem::LogicalMinimum(inner) => self.logical_minimum = Some(inner.clone()),
+            ReportItem::LogicalMaximum(inner) => self.logical_maximum = Some(inner.clone()),
+            ReportItem::PhysicalMinimum(inner) => {
+                self.physical_minimum = (!inner.data().is_empty()).then(|| inner.clone())
+            }
+            ReportItem::PhysicalMaximum(inner) => {
+                self.physical_maximum = (!inner.data().is_empty()).then(|| inner.clone())
+            }
+            ReportItem::UnitExponent(inner) => self.unit_exponent = Some(inner.clone()),
+            ReportItem::Unit(inner) => self.unit = Some(inner.clone()),
+            ReportItem::ReportSize(inner) => self.report_size = Some(inner.clone()),
+            ReportItem::ReportId(inner) => self.report_id = Some(inner.clone()),
+            ReportItem::ReportCount(inner) => self.report_count = Some(inner.clone()),
+            _ => (),
+        }
+    }
+}
+
+/// Get the global items in effect for the item at `index`, i.e. after replaying
+/// every item before it, honoring [Push](crate::Push) and [Pop](crate::Pop).
+///
+/// If `index` is out of bounds, all items are replayed.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, state_at};
+///
+/// let bytes = [
+///     0x05, 0x01, 0x75, 0x08, 0xA4, 0x75, 0x10, 0x95, 0x02, 0xB4, 0x81, 0x02,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+///
+/// let state = state_at(&items, 5);
+/// assert_eq!(state.report_size.unwrap().to_string(), "Report Size (16)");
+/// assert_eq!(state.report_count.unwrap().to_string(), "Report Count (2)");
+///
+/// // Restoring the state at Pop needs the `alloc` feature.
+/// #[cfg(feature = "alloc")]
+/// {
+///     let state = state_at(&items, 6);
+///     assert_eq!(state.usage_page.unwrap().to_string(), "Usage Page (Generic Desktop)");
+///     assert_eq!(state.report_size.unwrap().to_string(), "Report Size (8)");
+///     assert_eq!(state.report_count, None);
+/// }
+/// ```
+pub fn state_at(items: &[ReportItem], index: usize) -> GlobalState {
+    let mut state = GlobalState::new();
+    for item in &items[..index.min(items.len())] {
+        state.update(item);
+    }
+    state
+}
+
+/// Find the nearest [Usage Page](UsagePage) item before the item at `index`,
+/// scanning backward.
+///
+/// Unlike [`state_at()`], [Push](crate::Push) and [Pop](crate::Pop) are not
+/// honored, the nearest item in declaration order is returned.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, preceding_usage_page};
+///
+/// let bytes = [0x05, 0x01, 0x09, 0x02, 0x05, 0x09, 0x19, 0x01];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// assert_eq!(
+///     preceding_usage_page(&items, 1).unwrap().to_string(),
+///     "Usage Page (Generic Desktop)"
+/// );
+/// assert_eq!(
+///     preceding_usage_page(&items, 3).unwrap().to_string(),
+///     "Usage Page (Button)"
+/// );
+/// assert_eq!(preceding_usage_page(&items, 0), None);
+/// ```
+pub fn preceding_usage_page(items: &[ReportItem], index: usize) -> Option<&UsagePage> {
+    items[..index.min(items.len())]
+        .iter()
+        .rev()
+        .find_map(ReportItem::as_usage_page)
+}
@@ -0,0 +1,93 @@
+use crate::{__data_to_unsigned, __extended_usage, byte_len, GlobalState, ItemKind, ReportItem};
+use alloc::collections::{BTreeMap, BTreeSet};
+
+/// Overview of a descriptor, see [`summary()`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DescriptorSummary {
+    /// Number of items of each kind, kinds that don't appear are omitted.
+    pub kind_counts: BTreeMap<ItemKind, usize>,
+    /// Distinct [Report ID](crate::ReportId)s declared.
+    pub report_ids: BTreeSet<u32>,
+    /// Maximum nesting depth of collections, 0 if there is no collection.
+    pub max_collection_depth: usize,
+    /// Byte length of the descriptor, see [`byte_len()`].
+    pub byte_len: usize,
+    /// Distinct usage pages referenced, by [Usage Page](crate::UsagePage) items or
+    /// by 4-byte usages.
+    pub usage_pages: BTreeSet<u32>,
+    /// Extended usage (usage page in the high 16 bits) of the first top-level
+    /// collection, `None` if it has no usage.
+    pub top_level_usage: Option<u32>,
+}
+
+/// Summarize items in a single pass, for a quick overview of a descriptor.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, summary, ItemKind};
+///
+/// let bytes = [
+///     0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x85, 0x01, 0x09, 0x01, 0xA1, 0x00,
+///     0x05, 0x09, 0x19, 0x01, 0x29, 0x03, 0x95, 0x03, 0x75, 0x01, 0x81, 0x02,
+///     0xC0, 0x85, 0x02, 0x0B, 0x01, 0x00, 0x0C, 0x00, 0x81, 0x02, 0xC0,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// let summary = summary(&items);
+/// assert_eq!(summary.kind_counts[&ItemKind::Usage], 3);
+/// assert_eq!(summary.kind_counts[&ItemKind::Input], 2);
+/// assert!(!summary.kind_counts.contains_key(&ItemKind::Output));
+/// assert_eq!(summary.report_ids.len(), 2);
+/// assert_eq!(summary.max_collection_depth, 2);
+/// assert_eq!(summary.byte_len, bytes.len());
+/// assert_eq!(summary.usage_pages.iter().copied().collect::<Vec<_>>(), [0x01, 0x09, 0x0C]);
+/// assert_eq!(summary.top_level_usage, Some(0x0001_0002));
+/// ```
+pub fn summary(items: &[ReportItem]) -> DescriptorSummary {
+    let mut summary = DescriptorSummary {
+        byte_len: byte_len(items),
+        ..Default::default()
+    };
+    let mut state = GlobalState::new();
+    let mut usage = None;
+    let mut seen_collection = false;
+    let mut depth = 0;
+    for item in items {
+        *summary.kind_counts.entry(item.kind()).or_insert(0) += 1;
+        match item {
+            ReportItem::UsagePage(inner) => {
+                summary.usage_pages.insert(__data_to_unsigned(inner.data()));
+            }
+            ReportItem::ReportId(inner) => {
+                summary.report_ids.insert(inner.value());
+            }
+            ReportItem::Usage(inner) => {
+                if inner.data().len() == 4 {
+                    summary
+                        .usage_pages
+                        .insert(__data_to_unsigned(inner.data()) >> 16);
+                }
+                usage = Some(__extended_usage(inner.data(), &state));
+            }
+            ReportItem::Collection(_) => {
+                if depth == 0 && !seen_collection {
+                    seen_collection = true;
+                    summary.top_level_usage = usage;
+                }
+                depth += 1;
+                summary.max_collection_depth = summary.max_collection_depth.max(depth);
+            }
+            ReportItem::EndCollection(_) => depth = depth.saturating_sub(1),
+            _ => (),
+        }
+        match item {
+            ReportItem::Input(_)
+            | ReportItem::Output(_)
+            | ReportItem::Feature(_)
+            | ReportItem::Collection(_)
+            | ReportItem::EndCollection(_) => usage = None,
+            _ => state.update(item),
+        }
+    }
+    summary
+}
@@ -0,0 +1,197 @@
+use crate::{Collection, HidError, ReportItem};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// A node of the collection tree, see [`build_tree()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TreeNode {
+    /// Any item other than [Collection] and [End Collection](crate::EndCollection).
+    Item(ReportItem),
+    /// A collection and the nodes it contains, in declaration order.
+    Collection {
+        /// The item opening the collection.
+        collection: Collection,
+        /// The nodes between the collection and its end.
+        children: Vec<TreeNode>,
+    },
+}
+
+/// Options of [`build_tree_with()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeOptions {
+    /// Maximum nesting depth of collections, 64 by default.
+    pub max_depth: usize,
+}
+
+impl Default for TreeOptions {
+    fn default() -> Self {
+        Self { max_depth: 64 }
+    }
+}
+
+/// Build the collection tree of items, with default [TreeOptions].
+///
+/// [End Collection](crate::EndCollection) items only close their collection and
+/// don't appear in the tree.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{build_tree, parse, HidError, TreeNode};
+///
+/// let bytes = [0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x09, 0x01, 0xA1, 0x00, 0xC0, 0xC0];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// let tree = build_tree(&items).unwrap();
+/// assert_eq!(tree.len(), 3);
+/// let TreeNode::Collection { collection, children } = &tree[2] else {
+///     panic!("expected a collection");
+/// };
+/// assert_eq!(collection.to_string(), "Collection (Application)");
+/// assert_eq!(children.len(), 2);
+///
+/// let items = parse([0xA1, 0x01, 0xC0, 0xC0]).collect::<Vec<_>>();
+/// assert_eq!(build_tree(&items), Err(HidError::UnbalancedItem { index: 2 }));
+///
+/// let items = parse([0xA1, 0x01, 0xA1, 0x00, 0xC0]).collect::<Vec<_>>();
+/// assert_eq!(build_tree(&items), Err(HidError::UnbalancedItem { index: 0 }));
+/// ```
+pub fn build_tree(items: &[ReportItem]) -> Result<Vec<TreeNode>, HidError> {
+    build_tree_with(items, &TreeOptions::default())
+}
+
+/// Build the collection tree of items.
+///
+/// The tree is built iteratively, so deeply nested input can't overflow the stack.
+/// Nesting deeper than [`TreeOptions::max_depth`] is reported as
+/// [`HidError::NestingTooDeep`], and an [End Collection](crate::EndCollection)
+/// without collection or a collection without end as [`HidError::UnbalancedItem`].
+/// For collections without end, the index is the innermost one's.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{build_tree_with, parse, HidError, TreeOptions};
+///
+/// let bytes = [0xA1, 0x01, 0xA1, 0x00, 0xA1, 0x02, 0xC0, 0xC0, 0xC0];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// let options = TreeOptions { max_depth: 2 };
+/// assert_eq!(
+///     build_tree_with(&items, &options),
+///     Err(HidError::NestingTooDeep { depth: 3 })
+/// );
+/// ```
+pub fn build_tree_with(
+    items: &[ReportItem],
+    options: &TreeOptions,
+) -> Result<Vec<TreeNode>, HidError> {
+    let mut root = Vec::new();
+    let mut stack: Vec<(usize, Collection, Vec<TreeNode>)> = Vec::new();
+    for (index, item) in items.iter().enumerate() {
+        match item {
+            ReportItem::Collection(collection) => {
+                let depth = stack.len() + 1;
+                if depth > options.max_depth {
+                    return Err(HidError::NestingTooDeep { depth });
+                }
+                stack.push((index, collection.clone(), Vec::new()));
+            }
+            ReportItem::EndCollection(_) => {
+                let (_, collection, children) =
+                    stack.pop().ok_or(HidError::UnbalancedItem { index })?;
+                let node = TreeNode::Collection {
+                    collection,
+                    children,
+                };
+                match stack.last_mut() {
+                    Some((_, _, parent)) => parent.push(node),
+                    None => root.push(node),
+                }
+            }
+            _ => match stack.last_mut() {
+                Some((_, _, parent)) => parent.push(TreeNode::Item(item.clone())),
+                None => root.push(TreeNode::Item(item.clone())),
+            },
+        }
+    }
+    match stack.last() {
+        Some(&(index, _, _)) => Err(HidError::UnbalancedItem { index }),
+        None => Ok(root),
+    }
+}
+
+/// Render the collection tree of items as a [Graphviz](https://graphviz.org) DOT
+/// graph, e.g. to visualize a composite device with `dot -Tsvg`.
+///
+/// Collections and [Input](crate::Input), [Output](crate::Output) and
+/// [Feature](crate::Feature) items become nodes, labeled by the usages declared
+/// before them. Edges point from a collection to its children.
+///
+/// Fails like [`build_tree()`] on unbalanced collections.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, to_dot};
+///
+/// let bytes = [
+///     0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x09, 0x30, 0x09, 0x31, 0x75, 0x08,
+///     0x95, 0x02, 0x81, 0x06, 0xC0,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+///
+/// const EXPECTED: &str = indoc::indoc! {r#"
+///     digraph descriptor {
+///         node [shape=box];
+///         n0 [label="Collection (Application)\nUsage (Mouse)"];
+///         n1 [label="Input\nUsage (X)\nUsage (Y)"];
+///         n0 -> n1;
+///     }"#
+/// };
+///
+/// assert_eq!(to_dot(&items).unwrap(), EXPECTED);
+/// ```
+pub fn to_dot(items: &[ReportItem]) -> Result<String, HidError> {
+    let tree = build_tree(items)?;
+    let mut dot = String::from("digraph descriptor {\n    node [shape=box];\n");
+    __dot_nodes(&mut dot, &tree, None, &mut 0);
+    dot.push('}');
+    Ok(dot)
+}
+
+fn __dot_nodes(dot: &mut String, nodes: &[TreeNode], parent: Option<usize>, next_id: &mut usize) {
+    let mut usages = Vec::new();
+    for node in nodes {
+        let label = match node {
+            TreeNode::Item(
+                item @ (ReportItem::Usage(_)
+                | ReportItem::UsageMinimum(_)
+                | ReportItem::UsageMaximum(_)),
+            ) => {
+                usages.push(item.to_string());
+                continue;
+            }
+            TreeNode::Item(
+                item @ (ReportItem::Input(_) | ReportItem::Output(_) | ReportItem::Feature(_)),
+            ) => item.kind_name().to_string(),
+            TreeNode::Item(_) => continue,
+            TreeNode::Collection { collection, .. } => collection.to_string(),
+        };
+        let id = *next_id;
+        *next_id += 1;
+        let label = std::iter::once(label)
+            .chain(usages.drain(..))
+            .map(|line| line.replace('\\', "\\\\").replace('"', "\\\""))
+            .collect::<Vec<_>>()
+            .join("\\n");
+        dot.push_str(&format!("    n{id} [label=\"{label}\"];\n"));
+        if let Some(parent) = parent {
+            dot.push_str(&format!("    n{parent} -> n{id};\n"));
+        }
+        if let TreeNode::Collection { children, .. } = node {
+            __dot_nodes(dot, children, Some(id), next_id);
+        }
+    }
+}
@@ -0,0 +1,2817 @@
+// Generated by scripts/gen_usage_tables.py from the HID Usage Tables JSON,
+// do not edit by hand.
+
+/// Usage names of the HID Usage Tables, sorted by extended usage, i.e. the usage
+/// page in the high 16 bits and the usage ID in the low 16 bits.
+#[rustfmt::skip]
+pub(crate) static USAGE_NAMES: &[(u32, &str)] = &[
+    (0x0001_0000, "Undefined"),
+    (0x0001_0001, "Pointer"),
+    (0x0001_0002, "Mouse"),
+    (0x0001_0004, "Joystick"),
+    (0x0001_0005, "Gamepad"),
+    (0x0001_0006, "Keyboard"),
+    (0x0001_0007, "Keypad"),
+    (0x0001_0008, "Multi-Axis Controller"),
+    (0x0001_0009, "Tablet PC System Controls"),
+    (0x0001_000A, "Water Cooling Device"),
+    (0x0001_000B, "Computer Chassis Device"),
+    (0x0001_000C, "Wireless Radio Controls"),
+    (0x0001_000D, "Portable Device Control"),
+    (0x0001_000E, "System Multi-Axis Controller"),
+    (0x0001_000F, "Spatial Controller"),
+    (0x0001_0010, "Assistive Control"),
+    (0x0001_0011, "Device Dock"),
+    (0x0001_0012, "Dockable Device"),
+    (0x0001_0013, "Call State Management Control"),
+    (0x0001_0030, "X"),
+    (0x0001_0031, "Y"),
+    (0x0001_0032, "Z"),
+    (0x0001_0033, "Rx"),
+    (0x0001_0034, "Ry"),
+    (0x0001_0035, "Rz"),
+    (0x0001_0036, "Slider"),
+    (0x0001_0037, "Dial"),
+    (0x0001_0038, "Wheel"),
+    (0x0001_0039, "Hat Switch"),
+    (0x0001_003A, "Counted Buffer"),
+    (0x0001_003B, "Byte Count"),
+    (0x0001_003C, "Motion Wakeup"),
+    (0x0001_003D, "Start"),
+    (0x0001_003E, "Select"),
+    (0x0001_0040, "Vx"),
+    (0x0001_0041, "Vy"),
+    (0x0001_0042, "Vz"),
+    (0x0001_0043, "Vbrx"),
+    (0x0001_0044, "Vbry"),
+    (0x0001_0045, "Vbrz"),
+    (0x0001_0046, "Vno"),
+    (0x0001_0047, "Feature Notification"),
+    (0x0001_0048, "Resolution Multiplier"),
+    (0x0001_0049, "Qx"),
+    (0x0001_004A, "Qy"),
+    (0x0001_004B, "Qz"),
+    (0x0001_004C, "Qw"),
+    (0x0001_0080, "System Control"),
+    (0x0001_0081, "System Power Down"),
+    (0x0001_0082, "System Sleep"),
+    (0x0001_0083, "System Wake Up"),
+    (0x0001_0084, "System Context Menu"),
+    (0x0001_0085, "System Main Menu"),
+    (0x0001_0086, "System App Menu"),
+    (0x0001_0087, "System Menu Help"),
+    (0x0001_0088, "System Menu Exit"),
+    (0x0001_0089, "System Menu Select"),
+    (0x0001_008A, "System Menu Right"),
+    (0x0001_008B, "System Menu Left"),
+    (0x0001_008C, "System Menu Up"),
+    (0x0001_008D, "System Menu Down"),
+    (0x0001_008E, "System Cold Restart"),
+    (0x0001_008F, "System Warm Restart"),
+    (0x0001_0090, "D-pad Up"),
+    (0x0001_0091, "D-pad Down"),
+    (0x0001_0092, "D-pad Right"),
+    (0x0001_0093, "D-pad Left"),
+    (0x0001_0094, "Index Trigger"),
+    (0x0001_0095, "Palm Trigger"),
+    (0x0001_0096, "Thumbstick"),
+    (0x0001_0097, "System Function Shift"),
+    (0x0001_0098, "System Function Shift Lock"),
+    (0x0001_0099, "System Function Shift Lock Indicator"),
+    (0x0001_009A, "System Dismiss Notification"),
+    (0x0001_009B, "System Do Not Disturb"),
+    (0x0001_00A0, "System Dock"),
+    (0x0001_00A1, "System Undock"),
+    (0x0001_00A2, "System Setup"),
+    (0x0001_00A3, "System Break"),
+    (0x0001_00A4, "System Debugger Break"),
+    (0x0001_00A5, "Application Break"),
+    (0x0001_00A6, "Application Debugger Break"),
+    (0x0001_00A7, "System Speaker Mute"),
+    (0x0001_00A8, "System Hibernate"),
+    (0x0001_00A9, "System Microphone Mute"),
+    (0x0001_00B0, "System Display Invert"),
+    (0x0001_00B1, "System Display Internal"),
+    (0x0001_00B2, "System Display External"),
+    (0x0001_00B3, "System Display Both"),
+    (0x0001_00B4, "System Display Dual"),
+    (0x0001_00B5, "System Display Toggle Int/Ext"),
+    (0x0001_00B6, "System Display Swap Primary/Secondary"),
+    (0x0001_00B7, "System Display LCD Autoscale"),
+    (0x0001_00C0, "Sensor Zone"),
+    (0x0001_00C1, "RPM"),
+    (0x0001_00C2, "Coolant Level"),
+    (0x0001_00C3, "Coolant Critical Level"),
+    (0x0001_00C4, "Coolant Pump"),
+    (0x0001_00C5, "Chassis Enclosure"),
+    (0x0001_00C6, "Wireless Radio Button"),
+    (0x0001_00C7, "Wireless Radio LED"),
+    (0x0001_00C8, "Wireless Radio Slider Switch"),
+    (0x0001_00C9, "System Display Rotation Lock Button"),
+    (0x0001_00CA, "System Display Rotation Lock Slider Switch"),
+    (0x0001_00CB, "Control Enable"),
+    (0x0001_00D0, "Dockable Device Unique ID"),
+    (0x0001_00D1, "Dockable Device Vendor ID"),
+    (0x0001_00D2, "Dockable Device Primary Usage Page"),
+    (0x0001_00D3, "Dockable Device Primary Usage ID"),
+    (0x0001_00D4, "Dockable Device Docking State"),
+    (0x0001_00D5, "Dockable Device Display Occlusion"),
+    (0x0001_00D6, "Dockable Device Object Type"),
+    (0x0001_00E0, "Call Active LED"),
+    (0x0001_00E1, "Call Mute Toggle"),
+    (0x0001_00E2, "Call Mute LED"),
+    (0x0002_0000, "Undefined"),
+    (0x0002_0001, "Flight Simulation Device"),
+    (0x0002_0002, "Automobile Simulation Device"),
+    (0x0002_0003, "Tank Simulation Device"),
+    (0x0002_0004, "Spaceship Simulation Device"),
+    (0x0002_0005, "Submarine Simulation Device"),
+    (0x0002_0006, "Sailing Simulation Device"),
+    (0x0002_0007, "Motorcycle Simulation Device"),
+    (0x0002_0008, "Sports Simulation Device"),
+    (0x0002_0009, "Airplane Simulation Device"),
+    (0x0002_000A, "Helicopter Simulation Device"),
+    (0x0002_000B, "Magic Carpet Simulation Device"),
+    (0x0002_000C, "Bicycle Simulation Device"),
+    (0x0002_0020, "Flight Control Stick"),
+    (0x0002_0021, "Flight Stick"),
+    (0x0002_0022, "Cyclic Control"),
+    (0x0002_0023, "Cyclic Trim"),
+    (0x0002_0024, "Flight Yoke"),
+    (0x0002_0025, "Track Control"),
+    (0x0002_00B0, "Aileron"),
+    (0x0002_00B1, "Aileron Trim"),
+    (0x0002_00B2, "Anti-Torque Control"),
+    (0x0002_00B3, "Autopilot Enable"),
+    (0x0002_00B4, "Chaff Release"),
+    (0x0002_00B5, "Collective Control"),
+    (0x0002_00B6, "Dive Brake"),
+    (0x0002_00B7, "Electronic Countermeasures"),
+    (0x0002_00B8, "Elevator"),
+    (0x0002_00B9, "Elevator Trim"),
+    (0x0002_00BA, "Rudder"),
+    (0x0002_00BB, "Throttle"),
+    (0x0002_00BC, "Flight Communications"),
+    (0x0002_00BD, "Flare Release"),
+    (0x0002_00BE, "Landing Gear"),
+    (0x0002_00BF, "Toe Brake"),
+    (0x0002_00C0, "Trigger"),
+    (0x0002_00C1, "Weapons Arm"),
+    (0x0002_00C2, "Weapons Select"),
+    (0x0002_00C3, "Wing Flaps"),
+    (0x0002_00C4, "Accelerator"),
+    (0x0002_00C5, "Brake"),
+    (0x0002_00C6, "Clutch"),
+    (0x0002_00C7, "Shifter"),
+    (0x0002_00C8, "Steering"),
+    (0x0002_00C9, "Turret Direction"),
+    (0x0002_00CA, "Barrel Elevation"),
+    (0x0002_00CB, "Dive Plane"),
+    (0x0002_00CC, "Ballast"),
+    (0x0002_00CD, "Bicycle Crank"),
+    (0x0002_00CE, "Handle Bars"),
+    (0x0002_00CF, "Front Brake"),
+    (0x0002_00D0, "Rear Brake"),
+    (0x0003_0000, "Undefined"),
+    (0x0003_0001, "Belt"),
+    (0x0003_0002, "Body Suit"),
+    (0x0003_0003, "Flexor"),
+    (0x0003_0004, "Glove"),
+    (0x0003_0005, "Head Tracker"),
+    (0x0003_0006, "Head Mounted Display"),
+    (0x0003_0007, "Hand Tracker"),
+    (0x0003_0008, "Oculometer"),
+    (0x0003_0009, "Vest"),
+    (0x0003_000A, "Animatronic Device"),
+    (0x0003_0020, "Stereo Enable"),
+    (0x0003_0021, "Display Enable"),
+    (0x0004_0000, "Undefined"),
+    (0x0004_0001, "Baseball Bat"),
+    (0x0004_0002, "Golf Club"),
+    (0x0004_0003, "Rowing Machine"),
+    (0x0004_0004, "Treadmill"),
+    (0x0004_0030, "Oar"),
+    (0x0004_0031, "Slope"),
+    (0x0004_0032, "Rate"),
+    (0x0004_0033, "Stick Speed"),
+    (0x0004_0034, "Stick Face Angle"),
+    (0x0004_0035, "Stick Heel/Toe"),
+    (0x0004_0036, "Stick Follow Through"),
+    (0x0004_0037, "Stick Tempo"),
+    (0x0004_0038, "Stick Type"),
+    (0x0004_0039, "Stick Height"),
+    (0x0004_0050, "Putter"),
+    (0x0004_0051, "1 Iron"),
+    (0x0004_0052, "2 Iron"),
+    (0x0004_0053, "3 Iron"),
+    (0x0004_0054, "4 Iron"),
+    (0x0004_0055, "5 Iron"),
+    (0x0004_0056, "6 Iron"),
+    (0x0004_0057, "7 Iron"),
+    (0x0004_0058, "8 Iron"),
+    (0x0004_0059, "9 Iron"),
+    (0x0004_005A, "10 Iron"),
+    (0x0004_005B, "11 Iron"),
+    (0x0004_005C, "Sand Wedge"),
+    (0x0004_005D, "Loft Wedge"),
+    (0x0004_005E, "Power Wedge"),
+    (0x0004_005F, "1 Wood"),
+    (0x0004_0060, "3 Wood"),
+    (0x0004_0061, "5 Wood"),
+    (0x0004_0062, "7 Wood"),
+    (0x0004_0063, "9 Wood"),
+    (0x0005_0000, "Undefined"),
+    (0x0005_0001, "3D Game Controller"),
+    (0x0005_0002, "Pinball Device"),
+    (0x0005_0003, "Gun Device"),
+    (0x0005_0020, "Point of View"),
+    (0x0005_0021, "Turn Right/Left"),
+    (0x0005_0022, "Pitch Forward/Backward"),
+    (0x0005_0023, "Roll Right/Left"),
+    (0x0005_0024, "Move Right/Left"),
+    (0x0005_0025, "Move Forward/Backward"),
+    (0x0005_0026, "Move Up/Down"),
+    (0x0005_0027, "Lean Right/Left"),
+    (0x0005_0028, "Lean Forward/Backward"),
+    (0x0005_0029, "Height of POV"),
+    (0x0005_002A, "Flipper"),
+    (0x0005_002B, "Secondary Flipper"),
+    (0x0005_002C, "Bump"),
+    (0x0005_002D, "New Game"),
+    (0x0005_002E, "Shoot Ball"),
+    (0x0005_002F, "Player"),
+    (0x0005_0030, "Gun Bolt"),
+    (0x0005_0031, "Gun Clip"),
+    (0x0005_0032, "Gun Selector"),
+    (0x0005_0033, "Gun Single Shot"),
+    (0x0005_0034, "Gun Burst"),
+    (0x0005_0035, "Gun Automatic"),
+    (0x0005_0036, "Gun Safety"),
+    (0x0005_0037, "Gamepad Fire/Jump"),
+    (0x0005_0039, "Gamepad Trigger"),
+    (0x0005_003A, "Form-fitting Gamepad"),
+    (0x0006_0000, "Undefined"),
+    (0x0006_0001, "Background/Nonuser Controls"),
+    (0x0006_0020, "Battery Strength"),
+    (0x0006_0021, "Wireless Channel"),
+    (0x0006_0022, "Wireless ID"),
+    (0x0006_0023, "Discover Wireless Control"),
+    (0x0006_0024, "Security Code Character Entered"),
+    (0x0006_0025, "Security Code Character Erased"),
+    (0x0006_0026, "Security Code Cleared"),
+    (0x0006_0027, "Sequence ID"),
+    (0x0006_0028, "Sequence ID Reset"),
+    (0x0006_0029, "RF Signal Strength"),
+    (0x0006_002A, "Software Version"),
+    (0x0006_002B, "Protocol Version"),
+    (0x0006_002C, "Hardware Version"),
+    (0x0006_002D, "Major"),
+    (0x0006_002E, "Minor"),
+    (0x0006_002F, "Revision"),
+    (0x0006_0030, "Handedness"),
+    (0x0006_0031, "Either Hand"),
+    (0x0006_0032, "Left Hand"),
+    (0x0006_0033, "Right Hand"),
+    (0x0006_0034, "Both Hands"),
+    (0x0006_0040, "Grip Pose Offset"),
+    (0x0006_0041, "Pointer Pose Offset"),
+    (0x0007_0001, "Keyboard ErrorRollOver"),
+    (0x0007_0002, "Keyboard POSTFail"),
+    (0x0007_0003, "Keyboard ErrorUndefined"),
+    (0x0007_0004, "Keyboard a and A"),
+    (0x0007_0005, "Keyboard b and B"),
+    (0x0007_0006, "Keyboard c and C"),
+    (0x0007_0007, "Keyboard d and D"),
+    (0x0007_0008, "Keyboard e and E"),
+    (0x0007_0009, "Keyboard f and F"),
+    (0x0007_000A, "Keyboard g and G"),
+    (0x0007_000B, "Keyboard h and H"),
+    (0x0007_000C, "Keyboard i and I"),
+    (0x0007_000D, "Keyboard j and J"),
+    (0x0007_000E, "Keyboard k and K"),
+    (0x0007_000F, "Keyboard l and L"),
+    (0x0007_0010, "Keyboard m and M"),
+    (0x0007_0011, "Keyboard n and N"),
+    (0x0007_0012, "Keyboard o and O"),
+    (0x0007_0013, "Keyboard p and P"),
+    (0x0007_0014, "Keyboard q and Q"),
+    (0x0007_0015, "Keyboard r and R"),
+    (0x0007_0016, "Keyboard s and S"),
+    (0x0007_0017, "Keyboard t and T"),
+    (0x0007_0018, "Keyboard u and U"),
+    (0x0007_0019, "Keyboard v and V"),
+    (0x0007_001A, "Keyboard w and W"),
+    (0x0007_001B, "Keyboard x and X"),
+    (0x0007_001C, "Keyboard y and Y"),
+    (0x0007_001D, "Keyboard z and Z"),
+    (0x0007_001E, "Keyboard 1 and !"),
+    (0x0007_001F, "Keyboard 2 and @"),
+    (0x0007_0020, "Keyboard 3 and #"),
+    (0x0007_0021, "Keyboard 4 and $"),
+    (0x0007_0022, "Keyboard 5 and %"),
+    (0x0007_0023, "Keyboard 6 and ∧"),
+    (0x0007_0024, "Keyboard 7 and &"),
+    (0x0007_0025, "Keyboard 8 and *"),
+    (0x0007_0026, "Keyboard 9 and ("),
+    (0x0007_0027, "Keyboard 0 and )"),
+    (0x0007_0028, "Keyboard Return (ENTER)"),
+    (0x0007_0029, "Keyboard ESCAPE"),
+    (0x0007_002A, "Keyboard DELETE (Backspace)"),
+    (0x0007_002B, "Keyboard Tab"),
+    (0x0007_002C, "Keyboard Spacebar"),
+    (0x0007_002D, "Keyboard - and (underscore)"),
+    (0x0007_002E, "Keyboard = and +"),
+    (0x0007_002F, "Keyboard [ and {"),
+    (0x0007_0030, "Keyboard ] and }"),
+    (0x0007_0031, "Keyboard \\ and |"),
+    (0x0007_0032, "Keyboard Non-US # and ˜"),
+    (0x0007_0033, "Keyboard ; and :"),
+    (0x0007_0034, "Keyboard ‘ and “"),
+    (0x0007_0035, "Keyboard Grave Accent and Tilde"),
+    (0x0007_0036, "Keyboard , and <"),
+    (0x0007_0037, "Keyboard . and >"),
+    (0x0007_0038, "Keyboard / and ?"),
+    (0x0007_0039, "Keyboard Caps Lock"),
+    (0x0007_003A, "Keyboard F1"),
+    (0x0007_003B, "Keyboard F2"),
+    (0x0007_003C, "Keyboard F3"),
+    (0x0007_003D, "Keyboard F4"),
+    (0x0007_003E, "Keyboard F5"),
+    (0x0007_003F, "Keyboard F6"),
+    (0x0007_0040, "Keyboard F7"),
+    (0x0007_0041, "Keyboard F8"),
+    (0x0007_0042, "Keyboard F9"),
+    (0x0007_0043, "Keyboard F10"),
+    (0x0007_0044, "Keyboard F11"),
+    (0x0007_0045, "Keyboard F12"),
+    (0x0007_0046, "Keyboard PrintScreen"),
+    (0x0007_0047, "Keyboard Scroll Lock"),
+    (0x0007_0048, "Keyboard Pause"),
+    (0x0007_0049, "Keyboard Insert"),
+    (0x0007_004A, "Keyboard Home"),
+    (0x0007_004B, "Keyboard PageUp"),
+    (0x0007_004C, "Keyboard Delete Forward"),
+    (0x0007_004D, "Keyboard End"),
+    (0x0007_004E, "Keyboard PageDown"),
+    (0x0007_004F, "Keyboard RightArrow"),
+    (0x0007_0050, "Keyboard LeftArrow"),
+    (0x0007_0051, "Keyboard DownArrow"),
+    (0x0007_0052, "Keyboard UpArrow"),
+    (0x0007_0053, "Keypad Num Lock and Clear"),
+    (0x0007_0054, "Keypad /"),
+    (0x0007_0055, "Keypad *"),
+    (0x0007_0056, "Keypad -"),
+    (0x0007_0057, "Keypad +"),
+    (0x0007_0058, "Keypad ENTER"),
+    (0x0007_0059, "Keypad 1 and End"),
+    (0x0007_005A, "Keypad 2 and Down Arrow"),
+    (0x0007_005B, "Keypad 3 and PageDn"),
+    (0x0007_005C, "Keypad 4 and Left Arrow"),
+    (0x0007_005D, "Keypad 5"),
+    (0x0007_005E, "Keypad 6 and Right Arrow"),
+    (0x0007_005F, "Keypad 7 and Home"),
+    (0x0007_0060, "Keypad 8 and Up Arrow"),
+    (0x0007_0061, "Keypad 9 and PageUp"),
+    (0x0007_0062, "Keypad 0 and Insert"),
+    (0x0007_0063, "Keypad . and Delete"),
+    (0x0007_0064, "Keyboard Non-US \\ and |"),
+    (0x0007_0065, "Keyboard Application"),
+    (0x0007_0066, "Keyboard Power"),
+    (0x0007_0067, "Keypad ="),
+    (0x0007_0068, "Keyboard F13"),
+    (0x0007_0069, "Keyboard F14"),
+    (0x0007_006A, "Keyboard F15"),
+    (0x0007_006B, "Keyboard F16"),
+    (0x0007_006C, "Keyboard F17"),
+    (0x0007_006D, "Keyboard F18"),
+    (0x0007_006E, "Keyboard F19"),
+    (0x0007_006F, "Keyboard F20"),
+    (0x0007_0070, "Keyboard F21"),
+    (0x0007_0071, "Keyboard F22"),
+    (0x0007_0072, "Keyboard F23"),
+    (0x0007_0073, "Keyboard F24"),
+    (0x0007_0074, "Keyboard Execute"),
+    (0x0007_0075, "Keyboard Help"),
+    (0x0007_0076, "Keyboard Menu"),
+    (0x0007_0077, "Keyboard"),
+    (0x0007_0078, "Keyboard Stop"),
+    (0x0007_0079, "Keyboard Again"),
+    (0x0007_007A, "Keyboard Undo"),
+    (0x0007_007B, "Keyboard Cut"),
+    (0x0007_007C, "Keyboard Copy"),
+    (0x0007_007D, "Keyboard Paste"),
+    (0x0007_007E, "Keyboard Find"),
+    (0x0007_007F, "Keyboard Mute"),
+    (0x0007_0080, "Keyboard Volume Up"),
+    (0x0007_0081, "Keyboard Volume Down"),
+    (0x0007_0082, "Keyboard Locking Caps Lock"),
+    (0x0007_0083, "Keyboard Locking Num Lock"),
+    (0x0007_0084, "Keyboard Locking Scroll Lock"),
+    (0x0007_0085, "Keypad Comma"),
+    (0x0007_0086, "Keypad Equal Sign"),
+    (0x0007_0087, "Keyboard International1"),
+    (0x0007_0088, "Keyboard International2"),
+    (0x0007_0089, "Keyboard International3"),
+    (0x0007_008A, "Keyboard International4"),
+    (0x0007_008B, "Keyboard International5"),
+    (0x0007_008C, "Keyboard International6"),
+    (0x0007_008D, "Keyboard International7"),
+    (0x0007_008E, "Keyboard International8"),
+    (0x0007_008F, "Keyboard International9"),
+    (0x0007_0090, "Keyboard LANG1"),
+    (0x0007_0091, "Keyboard LANG2"),
+    (0x0007_0092, "Keyboard LANG3"),
+    (0x0007_0093, "Keyboard LANG4"),
+    (0x0007_0094, "Keyboard LANG5"),
+    (0x0007_0095, "Keyboard LANG6"),
+    (0x0007_0096, "Keyboard LANG7"),
+    (0x0007_0097, "Keyboard LANG8"),
+    (0x0007_0098, "Keyboard LANG9"),
+    (0x0007_0099, "Keyboard Alternate Erase"),
+    (0x0007_009A, "Keyboard SysReq/Attention"),
+    (0x0007_009B, "Keyboard Cancel"),
+    (0x0007_009C, "Keyboard Clear"),
+    (0x0007_009D, "Keyboard Prior"),
+    (0x0007_009E, "Keyboard Return"),
+    (0x0007_009F, "Keyboard Separator"),
+    (0x0007_00A0, "Keyboard Out"),
+    (0x0007_00A1, "Keyboard Oper"),
+    (0x0007_00A2, "Keyboard Clear/Again"),
+    (0x0007_00A3, "Keyboard CrSel/Props"),
+    (0x0007_00A4, "Keyboard ExSel"),
+    (0x0007_00B0, "Keypad 00"),
+    (0x0007_00B1, "Keypad 000"),
+    (0x0007_00B2, "Thousands Separator"),
+    (0x0007_00B3, "Decimal Separator"),
+    (0x0007_00B4, "Currency Unit"),
+    (0x0007_00B5, "Currency Sub-unit"),
+    (0x0007_00B6, "Keypad ("),
+    (0x0007_00B7, "Keypad )"),
+    (0x0007_00B8, "Keypad {"),
+    (0x0007_00B9, "Keypad }"),
+    (0x0007_00BA, "Keypad Tab"),
+    (0x0007_00BB, "Keypad Backspace"),
+    (0x0007_00BC, "Keypad A"),
+    (0x0007_00BD, "Keypad B"),
+    (0x0007_00BE, "Keypad C"),
+    (0x0007_00BF, "Keypad D"),
+    (0x0007_00C0, "Keypad E"),
+    (0x0007_00C1, "Keypad F"),
+    (0x0007_00C2, "Keypad XOR"),
+    (0x0007_00C3, "Keypad ∧"),
+    (0x0007_00C4, "Keypad %"),
+    (0x0007_00C5, "Keypad <"),
+    (0x0007_00C6, "Keypad >"),
+    (0x0007_00C7, "Keypad &"),
+    (0x0007_00C8, "Keypad &&"),
+    (0x0007_00C9, "Keypad |"),
+    (0x0007_00CA, "Keypad ||"),
+    (0x0007_00CB, "Keypad :"),
+    (0x0007_00CC, "Keypad #"),
+    (0x0007_00CD, "Keypad Space"),
+    (0x0007_00CE, "Keypad @"),
+    (0x0007_00CF, "Keypad !"),
+    (0x0007_00D0, "Keypad Memory Store"),
+    (0x0007_00D1, "Keypad Memory Recall"),
+    (0x0007_00D2, "Keypad Memory Clear"),
+    (0x0007_00D3, "Keypad Memory Add"),
+    (0x0007_00D4, "Keypad Memory Subtract"),
+    (0x0007_00D5, "Keypad Memory Multiply"),
+    (0x0007_00D6, "Keypad Memory Divide"),
+    (0x0007_00D7, "Keypad +/-"),
+    (0x0007_00D8, "Keypad Clear"),
+    (0x0007_00D9, "Keypad Clear Entry"),
+    (0x0007_00DA, "Keypad Binary"),
+    (0x0007_00DB, "Keypad Octal"),
+    (0x0007_00DC, "Keypad Decimal"),
+    (0x0007_00DD, "Keypad Hexadecimal"),
+    (0x0007_00E0, "Keyboard LeftControl"),
+    (0x0007_00E1, "Keyboard LeftShift"),
+    (0x0007_00E2, "Keyboard LeftAlt"),
+    (0x0007_00E3, "Keyboard Left GUI"),
+    (0x0007_00E4, "Keyboard RightControl"),
+    (0x0007_00E5, "Keyboard RightShift"),
+    (0x0007_00E6, "Keyboard RightAlt"),
+    (0x0007_00E7, "Keyboard Right GUI"),
+    (0x0008_0000, "Undefined"),
+    (0x0008_0001, "Num Lock"),
+    (0x0008_0002, "Caps Lock"),
+    (0x0008_0003, "Scroll Lock"),
+    (0x0008_0004, "Compose"),
+    (0x0008_0005, "Kana"),
+    (0x0008_0006, "Power"),
+    (0x0008_0007, "Shift"),
+    (0x0008_0008, "Do Not Disturb"),
+    (0x0008_0009, "Mute"),
+    (0x0008_000A, "Tone Enable"),
+    (0x0008_000B, "High Cut Filter"),
+    (0x0008_000C, "Low Cut Filter"),
+    (0x0008_000D, "Equalizer Enable"),
+    (0x0008_000E, "Sound Field On"),
+    (0x0008_000F, "Surround On"),
+    (0x0008_0010, "Repeat"),
+    (0x0008_0011, "Stereo"),
+    (0x0008_0012, "Sampling Rate Detect"),
+    (0x0008_0013, "Spinning"),
+    (0x0008_0014, "CAV"),
+    (0x0008_0015, "CLV"),
+    (0x0008_0016, "Recording Format Detect"),
+    (0x0008_0017, "Off-Hook"),
+    (0x0008_0018, "Ring"),
+    (0x0008_0019, "Message Waiting"),
+    (0x0008_001A, "Data Mode"),
+    (0x0008_001B, "Battery Operation"),
+    (0x0008_001C, "Battery OK"),
+    (0x0008_001D, "Battery Low"),
+    (0x0008_001E, "Speaker"),
+    (0x0008_001F, "Headset"),
+    (0x0008_0020, "Hold"),
+    (0x0008_0021, "Microphone"),
+    (0x0008_0022, "Coverage"),
+    (0x0008_0023, "Night Mode"),
+    (0x0008_0024, "Send Calls"),
+    (0x0008_0025, "Call Pickup"),
+    (0x0008_0026, "Conference"),
+    (0x0008_0027, "Stand-by"),
+    (0x0008_0028, "Camera On"),
+    (0x0008_0029, "Camera Off"),
+    (0x0008_002A, "On-Line"),
+    (0x0008_002B, "Off-Line"),
+    (0x0008_002C, "Busy"),
+    (0x0008_002D, "Ready"),
+    (0x0008_002E, "Paper-Out"),
+    (0x0008_002F, "Paper-Jam"),
+    (0x0008_0030, "Remote"),
+    (0x0008_0031, "Forward"),
+    (0x0008_0032, "Reverse"),
+    (0x0008_0033, "Stop"),
+    (0x0008_0034, "Rewind"),
+    (0x0008_0035, "Fast Forward"),
+    (0x0008_0036, "Play"),
+    (0x0008_0037, "Pause"),
+    (0x0008_0038, "Record"),
+    (0x0008_0039, "Error"),
+    (0x0008_003A, "Usage Selected Indicator"),
+    (0x0008_003B, "Usage In Use Indicator"),
+    (0x0008_003C, "Usage Multi Mode Indicator"),
+    (0x0008_003D, "Indicator On"),
+    (0x0008_003E, "Indicator Flash"),
+    (0x0008_003F, "Indicator Slow Blink"),
+    (0x0008_0040, "Indicator Fast Blink"),
+    (0x0008_0041, "Indicator Off"),
+    (0x0008_0042, "Flash On Time"),
+    (0x0008_0043, "Slow Blink On Time"),
+    (0x0008_0044, "Slow Blink Off Time"),
+    (0x0008_0045, "Fast Blink On Time"),
+    (0x0008_0046, "Fast Blink Off Time"),
+    (0x0008_0047, "Usage Indicator Color"),
+    (0x0008_0048, "Indicator Red"),
+    (0x0008_0049, "Indicator Green"),
+    (0x0008_004A, "Indicator Amber"),
+    (0x0008_004B, "Generic Indicator"),
+    (0x0008_004C, "System Suspend"),
+    (0x0008_004D, "External Power Connected"),
+    (0x0008_004E, "Indicator Blue"),
+    (0x0008_004F, "Indicator Orange"),
+    (0x0008_0050, "Good Status"),
+    (0x0008_0051, "Warning Status"),
+    (0x0008_0052, "RGB LED"),
+    (0x0008_0053, "Red LED Channel"),
+    (0x0008_0054, "Blue LED Channel"),
+    (0x0008_0055, "Green LED Channel"),
+    (0x0008_0056, "LED Intensity"),
+    (0x0008_0057, "System Microphone Mute"),
+    (0x0008_0060, "Player Indicator"),
+    (0x0008_0061, "Player 1"),
+    (0x0008_0062, "Player 2"),
+    (0x0008_0063, "Player 3"),
+    (0x0008_0064, "Player 4"),
+    (0x0008_0065, "Player 5"),
+    (0x0008_0066, "Player 6"),
+    (0x0008_0067, "Player 7"),
+    (0x0008_0068, "Player 8"),
+    (0x0009_0000, "No Button Pressed"),
+    (0x000A_0000, "Reserved"),
+    (0x000B_0000, "Undefined"),
+    (0x000B_0001, "Phone"),
+    (0x000B_0002, "Answering Machine"),
+    (0x000B_0003, "Message Controls"),
+    (0x000B_0004, "Handset"),
+    (0x000B_0005, "Headset"),
+    (0x000B_0006, "Telephony Key Pad"),
+    (0x000B_0007, "Programmable Button"),
+    (0x000B_0020, "Hook Switch"),
+    (0x000B_0021, "Flash"),
+    (0x000B_0022, "Feature"),
+    (0x000B_0023, "Hold"),
+    (0x000B_0024, "Redial"),
+    (0x000B_0025, "Transfer"),
+    (0x000B_0026, "Drop"),
+    (0x000B_0027, "Park"),
+    (0x000B_0028, "Forward Calls"),
+    (0x000B_0029, "Alternate Function"),
+    (0x000B_002A, "Line"),
+    (0x000B_002B, "Speaker Phone"),
+    (0x000B_002C, "Conference"),
+    (0x000B_002D, "Ring Enable"),
+    (0x000B_002E, "Ring Select"),
+    (0x000B_002F, "Phone Mute"),
+    (0x000B_0030, "Caller ID"),
+    (0x000B_0031, "Send"),
+    (0x000B_0050, "Speed Dial"),
+    (0x000B_0051, "Store Number"),
+    (0x000B_0052, "Recall Number"),
+    (0x000B_0053, "Phone Directory"),
+    (0x000B_0070, "Voice Mail"),
+    (0x000B_0071, "Screen Calls"),
+    (0x000B_0072, "Do Not Disturb"),
+    (0x000B_0073, "Message"),
+    (0x000B_0074, "Answer On/Off"),
+    (0x000B_0090, "Inside Dial Tone"),
+    (0x000B_0091, "Outside Dial Tone"),
+    (0x000B_0092, "Inside Ring Tone"),
+    (0x000B_0093, "Outside Ring Tone"),
+    (0x000B_0094, "Priority Ring Tone"),
+    (0x000B_0095, "Inside Ringback"),
+    (0x000B_0096, "Priority Ringback"),
+    (0x000B_0097, "Line Busy Tone"),
+    (0x000B_0098, "Reorder Tone"),
+    (0x000B_0099, "Call Waiting Tone"),
+    (0x000B_009A, "Confirmation Tone 1"),
+    (0x000B_009B, "Confirmation Tone 2"),
+    (0x000B_009C, "Tones Off"),
+    (0x000B_009D, "Outside Ringback"),
+    (0x000B_009E, "Ringer"),
+    (0x000B_00B0, "Phone Key 0"),
+    (0x000B_00B1, "Phone Key 1"),
+    (0x000B_00B2, "Phone Key 2"),
+    (0x000B_00B3, "Phone Key 3"),
+    (0x000B_00B4, "Phone Key 4"),
+    (0x000B_00B5, "Phone Key 5"),
+    (0x000B_00B6, "Phone Key 6"),
+    (0x000B_00B7, "Phone Key 7"),
+    (0x000B_00B8, "Phone Key 8"),
+    (0x000B_00B9, "Phone Key 9"),
+    (0x000B_00BA, "Phone Key Star"),
+    (0x000B_00BB, "Phone Key Pound"),
+    (0x000B_00BC, "Phone Key A"),
+    (0x000B_00BD, "Phone Key B"),
+    (0x000B_00BE, "Phone Key C"),
+    (0x000B_00BF, "Phone Key D"),
+    (0x000B_00C0, "Phone Call History Key"),
+    (0x000B_00C1, "Phone Caller ID Key"),
+    (0x000B_00C2, "Phone Settings Key"),
+    (0x000B_00F0, "Host Control"),
+    (0x000B_00F1, "Host Available"),
+    (0x000B_00F2, "Host Call Active"),
+    (0x000B_00F3, "Activate Handset Audio"),
+    (0x000B_00F4, "Ring Type"),
+    (0x000B_00F5, "Re-dialable Phone Number"),
+    (0x000B_00F8, "Stop Ring Tone"),
+    (0x000B_00F9, "PSTN Ring Tone"),
+    (0x000B_00FA, "Host Ring Tone"),
+    (0x000B_00FB, "Alert Sound Error"),
+    (0x000B_00FC, "Alert Sound Confirm"),
+    (0x000B_00FD, "Alert Sound Notification"),
+    (0x000B_00FE, "Silent Ring"),
+    (0x000B_0108, "Email Message Waiting"),
+    (0x000B_0109, "Voicemail Message Waiting"),
+    (0x000B_010A, "Host Hold"),
+    (0x000B_0110, "Incoming Call History Count"),
+    (0x000B_0111, "Outgoing Call History Count"),
+    (0x000B_0112, "Incoming Call History"),
+    (0x000B_0113, "Outgoing Call History"),
+    (0x000B_0114, "Phone Locale"),
+    (0x000B_0140, "Phone Time Second"),
+    (0x000B_0141, "Phone Time Minute"),
+    (0x000B_0142, "Phone Time Hour"),
+    (0x000B_0143, "Phone Date Day"),
+    (0x000B_0144, "Phone Date Month"),
+    (0x000B_0145, "Phone Date Year"),
+    (0x000B_0146, "Handset Nickname"),
+    (0x000B_0147, "Address Book ID"),
+    (0x000B_014A, "Call Duration"),
+    (0x000B_014B, "Dual Mode Phone"),
+    (0x000C_0000, "Undefined"),
+    (0x000C_0001, "Consumer Control"),
+    (0x000C_0002, "Numeric Key Pad"),
+    (0x000C_0003, "Programmable Buttons"),
+    (0x000C_0004, "Microphone"),
+    (0x000C_0005, "Headphone"),
+    (0x000C_0006, "Graphic Equalizer"),
+    (0x000C_0020, "+10"),
+    (0x000C_0021, "+100"),
+    (0x000C_0022, "AM/PM"),
+    (0x000C_0030, "Power"),
+    (0x000C_0031, "Reset"),
+    (0x000C_0032, "Sleep"),
+    (0x000C_0033, "Sleep After"),
+    (0x000C_0034, "Sleep Mode"),
+    (0x000C_0035, "Illumination"),
+    (0x000C_0036, "Function Buttons"),
+    (0x000C_0040, "Menu"),
+    (0x000C_0041, "Menu Pick"),
+    (0x000C_0042, "Menu Up"),
+    (0x000C_0043, "Menu Down"),
+    (0x000C_0044, "Menu Left"),
+    (0x000C_0045, "Menu Right"),
+    (0x000C_0046, "Menu Escape"),
+    (0x000C_0047, "Menu Value Increase"),
+    (0x000C_0048, "Menu Value Decrease"),
+    (0x000C_0060, "Data On Screen"),
+    (0x000C_0061, "Closed Caption"),
+    (0x000C_0062, "Closed Caption Select"),
+    (0x000C_0063, "VCR/TV"),
+    (0x000C_0064, "Broadcast Mode"),
+    (0x000C_0065, "Snapshot"),
+    (0x000C_0066, "Still"),
+    (0x000C_0067, "Picture-in-Picture Toggle"),
+    (0x000C_0068, "Picture-in-Picture Swap"),
+    (0x000C_0069, "Red Menu Button"),
+    (0x000C_006A, "Green Menu Button"),
+    (0x000C_006B, "Blue Menu Button"),
+    (0x000C_006C, "Yellow Menu Button"),
+    (0x000C_006D, "Aspect"),
+    (0x000C_006E, "3D Mode Select"),
+    (0x000C_006F, "Display Brightness Increment"),
+    (0x000C_0070, "Display Brightness Decrement"),
+    (0x000C_0071, "Display Brightness"),
+    (0x000C_0072, "Display Backlight Toggle"),
+    (0x000C_0073, "Display Set Brightness to Minimum"),
+    (0x000C_0074, "Display Set Brightness to Maximum"),
+    (0x000C_0075, "Display Set Auto Brightness"),
+    (0x000C_0076, "Camera Access Enabled"),
+    (0x000C_0077, "Camera Access Disabled"),
+    (0x000C_0078, "Camera Access Toggle"),
+    (0x000C_0079, "Keyboard Brightness Increment"),
+    (0x000C_007A, "Keyboard Brightness Decrement"),
+    (0x000C_007B, "Keyboard Backlight Set Level"),
+    (0x000C_007C, "Keyboard Backlight"),
+    (0x000C_007D, "Keyboard Backlight Set Minimum"),
+    (0x000C_007E, "Keyboard Backlight Set Maximum"),
+    (0x000C_007F, "Keyboard Backlight Auto"),
+    (0x000C_0080, "Selection"),
+    (0x000C_0081, "Assign Selection"),
+    (0x000C_0082, "Mode Step"),
+    (0x000C_0083, "Recall Last"),
+    (0x000C_0084, "Enter Channel"),
+    (0x000C_0085, "Order Movie"),
+    (0x000C_0086, "Channel"),
+    (0x000C_0087, "Media Selection"),
+    (0x000C_0088, "Media Select Computer"),
+    (0x000C_0089, "Media Select TV"),
+    (0x000C_008A, "Media Select WWW"),
+    (0x000C_008B, "Media Select DVD"),
+    (0x000C_008C, "Media Select Telephone"),
+    (0x000C_008D, "Media Select Program Guide"),
+    (0x000C_008E, "Media Select Video Phone"),
+    (0x000C_008F, "Media Select Games"),
+    (0x000C_0090, "Media Select Messages"),
+    (0x000C_0091, "Media Select CD"),
+    (0x000C_0092, "Media Select VCR"),
+    (0x000C_0093, "Media Select Tuner"),
+    (0x000C_0094, "Quit"),
+    (0x000C_0095, "Help"),
+    (0x000C_0096, "Media Select Tape"),
+    (0x000C_0097, "Media Select Cable"),
+    (0x000C_0098, "Media Select Satellite"),
+    (0x000C_0099, "Media Select Security"),
+    (0x000C_009A, "Media Select Home"),
+    (0x000C_009B, "Media Select Call"),
+    (0x000C_009C, "Channel Increment"),
+    (0x000C_009D, "Channel Decrement"),
+    (0x000C_009E, "Media Select SAP"),
+    (0x000C_00A0, "VCR Plus"),
+    (0x000C_00A1, "Once"),
+    (0x000C_00A2, "Daily"),
+    (0x000C_00A3, "Weekly"),
+    (0x000C_00A4, "Monthly"),
+    (0x000C_00B0, "Play"),
+    (0x000C_00B1, "Pause"),
+    (0x000C_00B2, "Record"),
+    (0x000C_00B3, "Fast Forward"),
+    (0x000C_00B4, "Rewind"),
+    (0x000C_00B5, "Scan Next Track"),
+    (0x000C_00B6, "Scan Previous Track"),
+    (0x000C_00B7, "Stop"),
+    (0x000C_00B8, "Eject"),
+    (0x000C_00B9, "Random Play"),
+    (0x000C_00BA, "Select Disc"),
+    (0x000C_00BB, "Enter Disc"),
+    (0x000C_00BC, "Repeat"),
+    (0x000C_00BD, "Tracking"),
+    (0x000C_00BE, "Track Normal"),
+    (0x000C_00BF, "Slow Tracking"),
+    (0x000C_00C0, "Frame Forward"),
+    (0x000C_00C1, "Frame Back"),
+    (0x000C_00C2, "Mark"),
+    (0x000C_00C3, "Clear Mark"),
+    (0x000C_00C4, "Repeat From Mark"),
+    (0x000C_00C5, "Return To Mark"),
+    (0x000C_00C6, "Search Mark Forward"),
+    (0x000C_00C7, "Search Mark Backwards"),
+    (0x000C_00C8, "Counter Reset"),
+    (0x000C_00C9, "Show Counter"),
+    (0x000C_00CA, "Tracking Increment"),
+    (0x000C_00CB, "Tracking Decrement"),
+    (0x000C_00CC, "Stop/Eject"),
+    (0x000C_00CD, "Play/Pause"),
+    (0x000C_00CE, "Play/Skip"),
+    (0x000C_00CF, "Voice Command"),
+    (0x000C_00D0, "Invoke Capture Interface"),
+    (0x000C_00D1, "Start or Stop Game Recording"),
+    (0x000C_00D2, "Historical Game Capture"),
+    (0x000C_00D3, "Capture Game Screenshot"),
+    (0x000C_00D4, "Show or Hide Recording Indicator"),
+    (0x000C_00D5, "Start or Stop Microphone Capture"),
+    (0x000C_00D6, "Start or Stop Camera Capture"),
+    (0x000C_00D7, "Start or Stop Game Broadcast"),
+    (0x000C_00D8, "Start or Stop Voice Dictation Session"),
+    (0x000C_00D9, "Invoke/Dismiss Emoji Picker"),
+    (0x000C_00E0, "Volume"),
+    (0x000C_00E1, "Balance"),
+    (0x000C_00E2, "Mute"),
+    (0x000C_00E3, "Bass"),
+    (0x000C_00E4, "Treble"),
+    (0x000C_00E5, "Bass Boost"),
+    (0x000C_00E6, "Surround Mode"),
+    (0x000C_00E7, "Loudness"),
+    (0x000C_00E8, "MPX"),
+    (0x000C_00E9, "Volume Increment"),
+    (0x000C_00EA, "Volume Decrement"),
+    (0x000C_00F0, "Speed Select"),
+    (0x000C_00F1, "Playback Speed"),
+    (0x000C_00F2, "Standard Play"),
+    (0x000C_00F3, "Long Play"),
+    (0x000C_00F4, "Extended Play"),
+    (0x000C_00F5, "Slow"),
+    (0x000C_0100, "Fan Enable"),
+    (0x000C_0101, "Fan Speed"),
+    (0x000C_0102, "Light Enable"),
+    (0x000C_0103, "Light Illumination Level"),
+    (0x000C_0104, "Climate Control Enable"),
+    (0x000C_0105, "Room Temperature"),
+    (0x000C_0106, "Security Enable"),
+    (0x000C_0107, "Fire Alarm"),
+    (0x000C_0108, "Police Alarm"),
+    (0x000C_0109, "Proximity"),
+    (0x000C_010A, "Motion"),
+    (0x000C_010B, "Duress Alarm"),
+    (0x000C_010C, "Holdup Alarm"),
+    (0x000C_010D, "Medical Alarm"),
+    (0x000C_0150, "Balance Right"),
+    (0x000C_0151, "Balance Left"),
+    (0x000C_0152, "Bass Increment"),
+    (0x000C_0153, "Bass Decrement"),
+    (0x000C_0154, "Treble Increment"),
+    (0x000C_0155, "Treble Decrement"),
+    (0x000C_0160, "Speaker System"),
+    (0x000C_0161, "Channel Left"),
+    (0x000C_0162, "Channel Right"),
+    (0x000C_0163, "Channel Center"),
+    (0x000C_0164, "Channel Front"),
+    (0x000C_0165, "Channel Center Front"),
+    (0x000C_0166, "Channel Side"),
+    (0x000C_0167, "Channel Surround"),
+    (0x000C_0168, "Channel Low Frequency Enhancement"),
+    (0x000C_0169, "Channel Top"),
+    (0x000C_016A, "Channel Unknown"),
+    (0x000C_0170, "Sub-channel"),
+    (0x000C_0171, "Sub-channel Increment"),
+    (0x000C_0172, "Sub-channel Decrement"),
+    (0x000C_0173, "Alternate Audio Increment"),
+    (0x000C_0174, "Alternate Audio Decrement"),
+    (0x000C_0180, "Application Launch Buttons"),
+    (0x000C_0181, "AL Launch Button Configuration Tool"),
+    (0x000C_0182, "AL Programmable Button Configuration"),
+    (0x000C_0183, "AL Consumer Control Configuration"),
+    (0x000C_0184, "AL Word Processor"),
+    (0x000C_0185, "AL Text Editor"),
+    (0x000C_0186, "AL Spreadsheet"),
+    (0x000C_0187, "AL Graphics Editor"),
+    (0x000C_0188, "AL Presentation App"),
+    (0x000C_0189, "AL Database App"),
+    (0x000C_018A, "AL Email Reader"),
+    (0x000C_018B, "AL Newsreader"),
+    (0x000C_018C, "AL Voicemail"),
+    (0x000C_018D, "AL Contacts/Address Book"),
+    (0x000C_018E, "AL Calendar/Schedule"),
+    (0x000C_018F, "AL Task/Project Manager"),
+    (0x000C_0190, "AL Log/Journal/Timecard"),
+    (0x000C_0191, "AL Checkbook/Finance"),
+    (0x000C_0192, "AL Calculator"),
+    (0x000C_0193, "AL A/V Capture/Playback"),
+    (0x000C_0194, "AL Local Machine Browser"),
+    (0x000C_0195, "AL LAN/WAN Browser"),
+    (0x000C_0196, "AL Internet Browser"),
+    (0x000C_0197, "AL Remote Networking/ISP Connect"),
+    (0x000C_0198, "AL Network Conference"),
+    (0x000C_0199, "AL Network Chat"),
+    (0x000C_019A, "AL Telephony/Dialer"),
+    (0x000C_019B, "AL Logon"),
+    (0x000C_019C, "AL Logoff"),
+    (0x000C_019D, "AL Logon/Logoff"),
+    (0x000C_019E, "AL Terminal Lock/Screensaver"),
+    (0x000C_019F, "AL Control Panel"),
+    (0x000C_01A0, "AL Command Line Processor/Run"),
+    (0x000C_01A1, "AL Process/Task Manager"),
+    (0x000C_01A2, "AL Select Task/Application"),
+    (0x000C_01A3, "AL Next Task/Application"),
+    (0x000C_01A4, "AL Previous Task/Application"),
+    (0x000C_01A5, "AL Preemptive Halt Task/Application"),
+    (0x000C_01A6, "AL Integrated Help Center"),
+    (0x000C_01A7, "AL Documents"),
+    (0x000C_01A8, "AL Thesaurus"),
+    (0x000C_01A9, "AL Dictionary"),
+    (0x000C_01AA, "AL Desktop"),
+    (0x000C_01AB, "AL Spell Check"),
+    (0x000C_01AC, "AL Grammar Check"),
+    (0x000C_01AD, "AL Wireless Status"),
+    (0x000C_01AE, "AL Keyboard Layout"),
+    (0x000C_01AF, "AL Virus Protection"),
+    (0x000C_01B0, "AL Encryption"),
+    (0x000C_01B1, "AL Screen Saver"),
+    (0x000C_01B2, "AL Alarms"),
+    (0x000C_01B3, "AL Clock"),
+    (0x000C_01B4, "AL File Browser"),
+    (0x000C_01B5, "AL Power Status"),
+    (0x000C_01B6, "AL Image Browser"),
+    (0x000C_01B7, "AL Audio Browser"),
+    (0x000C_01B8, "AL Movie Browser"),
+    (0x000C_01B9, "AL Digital Rights Manager"),
+    (0x000C_01BA, "AL Digital Wallet"),
+    (0x000C_01BC, "AL Instant Messaging"),
+    (0x000C_01BD, "AL OEM Features/ Tips/Tutorial Browser"),
+    (0x000C_01BE, "AL OEM Help"),
+    (0x000C_01BF, "AL Online Community"),
+    (0x000C_01C0, "AL Entertainment Content Browser"),
+    (0x000C_01C1, "AL Online Shopping Browser"),
+    (0x000C_01C2, "AL SmartCard Information/Help"),
+    (0x000C_01C3, "AL Market Monitor/Finance Browser"),
+    (0x000C_01C4, "AL Customized Corporate News Browser"),
+    (0x000C_01C5, "AL Online Activity Browser"),
+    (0x000C_01C6, "AL Research/Search Browser"),
+    (0x000C_01C7, "AL Audio Player"),
+    (0x000C_01C8, "AL Message Status"),
+    (0x000C_01C9, "AL Contact Sync"),
+    (0x000C_01CA, "AL Navigation"),
+    (0x000C_01CB, "AL Context-aware Desktop Assistant"),
+    (0x000C_0200, "Generic GUI Application Controls"),
+    (0x000C_0201, "AC New"),
+    (0x000C_0202, "AC Open"),
+    (0x000C_0203, "AC Close"),
+    (0x000C_0204, "AC Exit"),
+    (0x000C_0205, "AC Maximize"),
+    (0x000C_0206, "AC Minimize"),
+    (0x000C_0207, "AC Save"),
+    (0x000C_0208, "AC Print"),
+    (0x000C_0209, "AC Properties"),
+    (0x000C_021A, "AC Undo"),
+    (0x000C_021B, "AC Copy"),
+    (0x000C_021C, "AC Cut"),
+    (0x000C_021D, "AC Paste"),
+    (0x000C_021E, "AC Select All"),
+    (0x000C_021F, "AC Find"),
+    (0x000C_0220, "AC Find and Replace"),
+    (0x000C_0221, "AC Search"),
+    (0x000C_0222, "AC Go To"),
+    (0x000C_0223, "AC Home"),
+    (0x000C_0224, "AC Back"),
+    (0x000C_0225, "AC Forward"),
+    (0x000C_0226, "AC Stop"),
+    (0x000C_0227, "AC Refresh"),
+    (0x000C_0228, "AC Previous Link"),
+    (0x000C_0229, "AC Next Link"),
+    (0x000C_022A, "AC Bookmarks"),
+    (0x000C_022B, "AC History"),
+    (0x000C_022C, "AC Subscriptions"),
+    (0x000C_022D, "AC Zoom In"),
+    (0x000C_022E, "AC Zoom Out"),
+    (0x000C_022F, "AC Zoom"),
+    (0x000C_0230, "AC Full Screen View"),
+    (0x000C_0231, "AC Normal View"),
+    (0x000C_0232, "AC View Toggle"),
+    (0x000C_0233, "AC Scroll Up"),
+    (0x000C_0234, "AC Scroll Down"),
+    (0x000C_0235, "AC Scroll"),
+    (0x000C_0236, "AC Pan Left"),
+    (0x000C_0237, "AC Pan Right"),
+    (0x000C_0238, "AC Pan"),
+    (0x000C_0239, "AC New Window"),
+    (0x000C_023A, "AC Tile Horizontally"),
+    (0x000C_023B, "AC Tile Vertically"),
+    (0x000C_023C, "AC Format"),
+    (0x000C_023D, "AC Edit"),
+    (0x000C_023E, "AC Bold"),
+    (0x000C_023F, "AC Italics"),
+    (0x000C_0240, "AC Underline"),
+    (0x000C_0241, "AC Strikethrough"),
+    (0x000C_0242, "AC Subscript"),
+    (0x000C_0243, "AC Superscript"),
+    (0x000C_0244, "AC All Caps"),
+    (0x000C_0245, "AC Rotate"),
+    (0x000C_0246, "AC Resize"),
+    (0x000C_0247, "AC Flip Horizontal"),
+    (0x000C_0248, "AC Flip Vertical"),
+    (0x000C_0249, "AC Mirror Horizontal"),
+    (0x000C_024A, "AC Mirror Vertical"),
+    (0x000C_024B, "AC Font Select"),
+    (0x000C_024C, "AC Font Color"),
+    (0x000C_024D, "AC Font Size"),
+    (0x000C_024E, "AC Justify Left"),
+    (0x000C_024F, "AC Justify Center H"),
+    (0x000C_0250, "AC Justify Right"),
+    (0x000C_0251, "AC Justify Block H"),
+    (0x000C_0252, "AC Justify Top"),
+    (0x000C_0253, "AC Justify Center V"),
+    (0x000C_0254, "AC Justify Bottom"),
+    (0x000C_0255, "AC Justify Block V"),
+    (0x000C_0256, "AC Indent Decrease"),
+    (0x000C_0257, "AC Indent Increase"),
+    (0x000C_0258, "AC Numbered List"),
+    (0x000C_0259, "AC Restart Numbering"),
+    (0x000C_025A, "AC Bulleted List"),
+    (0x000C_025B, "AC Promote"),
+    (0x000C_025C, "AC Demote"),
+    (0x000C_025D, "AC Yes"),
+    (0x000C_025E, "AC No"),
+    (0x000C_025F, "AC Cancel"),
+    (0x000C_0260, "AC Catalog"),
+    (0x000C_0261, "AC Buy/Checkout"),
+    (0x000C_0262, "AC Add to Cart"),
+    (0x000C_0263, "AC Expand"),
+    (0x000C_0264, "AC Expand All"),
+    (0x000C_0265, "AC Collapse"),
+    (0x000C_0266, "AC Collapse All"),
+    (0x000C_0267, "AC Print Preview"),
+    (0x000C_0268, "AC Paste Special"),
+    (0x000C_0269, "AC Insert Mode"),
+    (0x000C_026A, "AC Delete"),
+    (0x000C_026B, "AC Lock"),
+    (0x000C_026C, "AC Unlock"),
+    (0x000C_026D, "AC Protect"),
+    (0x000C_026E, "AC Unprotect"),
+    (0x000C_026F, "AC Attach Comment"),
+    (0x000C_0270, "AC Delete Comment"),
+    (0x000C_0271, "AC View Comment"),
+    (0x000C_0272, "AC Select Word"),
+    (0x000C_0273, "AC Select Sentence"),
+    (0x000C_0274, "AC Select Paragraph"),
+    (0x000C_0275, "AC Select Column"),
+    (0x000C_0276, "AC Select Row"),
+    (0x000C_0277, "AC Select Table"),
+    (0x000C_0278, "AC Select Object"),
+    (0x000C_0279, "AC Redo/Repeat"),
+    (0x000C_027A, "AC Sort"),
+    (0x000C_027B, "AC Sort Ascending"),
+    (0x000C_027C, "AC Sort Descending"),
+    (0x000C_027D, "AC Filter"),
+    (0x000C_027E, "AC Set Clock"),
+    (0x000C_027F, "AC View Clock"),
+    (0x000C_0280, "AC Select Time Zone"),
+    (0x000C_0281, "AC Edit Time Zones"),
+    (0x000C_0282, "AC Set Alarm"),
+    (0x000C_0283, "AC Clear Alarm"),
+    (0x000C_0284, "AC Snooze Alarm"),
+    (0x000C_0285, "AC Reset Alarm"),
+    (0x000C_0286, "AC Synchronize"),
+    (0x000C_0287, "AC Send/Receive"),
+    (0x000C_0288, "AC Send To"),
+    (0x000C_0289, "AC Reply"),
+    (0x000C_028A, "AC Reply All"),
+    (0x000C_028B, "AC Forward Msg"),
+    (0x000C_028C, "AC Send"),
+    (0x000C_028D, "AC Attach File"),
+    (0x000C_028E, "AC Upload"),
+    (0x000C_028F, "AC Download (Save Target As)"),
+    (0x000C_0290, "AC Set Borders"),
+    (0x000C_0291, "AC Insert Row"),
+    (0x000C_0292, "AC Insert Column"),
+    (0x000C_0293, "AC Insert File"),
+    (0x000C_0294, "AC Insert Picture"),
+    (0x000C_0295, "AC Insert Object"),
+    (0x000C_0296, "AC Insert Symbol"),
+    (0x000C_0297, "AC Save and Close"),
+    (0x000C_0298, "AC Rename"),
+    (0x000C_0299, "AC Merge"),
+    (0x000C_029A, "AC Split"),
+    (0x000C_029B, "AC Disribute Horizontally"),
+    (0x000C_029C, "AC Distribute Vertically"),
+    (0x000C_029D, "AC Next Keyboard Layout Select"),
+    (0x000C_029E, "AC Navigation Guidance"),
+    (0x000C_029F, "AC Desktop Show All Windows"),
+    (0x000C_02A0, "AC Soft Key Left"),
+    (0x000C_02A1, "AC Soft Key Right"),
+    (0x000C_02A2, "AC Desktop Show All Applications"),
+    (0x000C_02B0, "AC Idle Keep Alive"),
+    (0x000C_02C0, "Extended Keyboard Attributes Collection"),
+    (0x000C_02C1, "Keyboard Form Factor"),
+    (0x000C_02C2, "Keyboard Key Type"),
+    (0x000C_02C3, "Keyboard Physical Layout"),
+    (0x000C_02C4, "Vendor-Specific Keyboard Physical Layout"),
+    (0x000C_02C5, "Keyboard IETF Language Tag Index"),
+    (0x000C_02C6, "Implemented Keyboard Input Assist Controls"),
+    (0x000C_02C7, "Keyboard Input Assist Previous"),
+    (0x000C_02C8, "Keyboard Input Assist Next"),
+    (0x000C_02C9, "Keyboard Input Assist Previous Group"),
+    (0x000C_02CA, "Keyboard Input Assist Next Group"),
+    (0x000C_02CB, "Keyboard Input Assist Accept"),
+    (0x000C_02CC, "Keyboard Input Assist Cancel"),
+    (0x000C_02D0, "Privacy Screen Toggle"),
+    (0x000C_02D1, "Privacy Screen Level Decrement"),
+    (0x000C_02D2, "Privacy Screen Level Increment"),
+    (0x000C_02D3, "Privacy Screen Level Minimum"),
+    (0x000C_02D4, "Privacy Screen Level Maximum"),
+    (0x000C_0500, "Contact Edited"),
+    (0x000C_0501, "Contact Added"),
+    (0x000C_0502, "Contact Record Active"),
+    (0x000C_0503, "Contact Index"),
+    (0x000C_0504, "Contact Nickname"),
+    (0x000C_0505, "Contact First Name"),
+    (0x000C_0506, "Contact Last Name"),
+    (0x000C_0507, "Contact Full Name"),
+    (0x000C_0508, "Contact Phone Number Personal"),
+    (0x000C_0509, "Contact Phone Number Business"),
+    (0x000C_050A, "Contact Phone Number Mobile"),
+    (0x000C_050B, "Contact Phone Number Pager"),
+    (0x000C_050C, "Contact Phone Number Fax"),
+    (0x000C_050D, "Contact Phone Number Other"),
+    (0x000C_050E, "Contact Email Personal"),
+    (0x000C_050F, "Contact Email Business"),
+    (0x000C_0510, "Contact Email Other"),
+    (0x000C_0511, "Contact Email Main"),
+    (0x000C_0512, "Contact Speed Dial Number"),
+    (0x000C_0513, "Contact Status Flag"),
+    (0x000C_0514, "Contact Misc"),
+    (0x000D_0000, "Undefined"),
+    (0x000D_0001, "Digitizer"),
+    (0x000D_0002, "Pen"),
+    (0x000D_0003, "Light Pen"),
+    (0x000D_0004, "Touch Screen"),
+    (0x000D_0005, "Touch Pad"),
+    (0x000D_0006, "Whiteboard"),
+    (0x000D_0007, "Coordinate Measuring Machine"),
+    (0x000D_0008, "3D Digitizer"),
+    (0x000D_0009, "Stereo Plotter"),
+    (0x000D_000A, "Articulated Arm"),
+    (0x000D_000B, "Armature"),
+    (0x000D_000C, "Multiple Point Digitizer"),
+    (0x000D_000D, "Free Space Wand"),
+    (0x000D_000E, "Device Configuration"),
+    (0x000D_000F, "Capacitive Heat Map Digitizer"),
+    (0x000D_0020, "Stylus"),
+    (0x000D_0021, "Puck"),
+    (0x000D_0022, "Finger"),
+    (0x000D_0023, "Device settings"),
+    (0x000D_0024, "Character Gesture"),
+    (0x000D_0030, "Tip Pressure"),
+    (0x000D_0031, "Barrel Pressure"),
+    (0x000D_0032, "In Range"),
+    (0x000D_0033, "Touch"),
+    (0x000D_0034, "Untouch"),
+    (0x000D_0035, "Tap"),
+    (0x000D_0036, "Quality"),
+    (0x000D_0037, "Data Valid"),
+    (0x000D_0038, "Transducer Index"),
+    (0x000D_0039, "Tablet Function Keys"),
+    (0x000D_003A, "Program Change Keys"),
+    (0x000D_003B, "Battery Strength"),
+    (0x000D_003C, "Invert"),
+    (0x000D_003D, "X Tilt"),
+    (0x000D_003E, "Y Tilt"),
+    (0x000D_003F, "Azimuth"),
+    (0x000D_0040, "Altitude"),
+    (0x000D_0041, "Twist"),
+    (0x000D_0042, "Tip Switch"),
+    (0x000D_0043, "Secondary Tip Switch"),
+    (0x000D_0044, "Barrel Switch"),
+    (0x000D_0045, "Eraser"),
+    (0x000D_0046, "Tablet Pick"),
+    (0x000D_0047, "Touch Valid"),
+    (0x000D_0048, "Width"),
+    (0x000D_0049, "Height"),
+    (0x000D_0051, "Contact Identifier"),
+    (0x000D_0052, "Device Mode"),
+    (0x000D_0053, "Device Identifier"),
+    (0x000D_0054, "Contact Count"),
+    (0x000D_0055, "Contact Count Maximum"),
+    (0x000D_0056, "Scan Time"),
+    (0x000D_0057, "Surface Switch"),
+    (0x000D_0058, "Button Switch"),
+    (0x000D_0059, "Pad Type"),
+    (0x000D_005A, "Secondary Barrel Switch"),
+    (0x000D_005B, "Transducer Serial Number"),
+    (0x000D_005C, "Preferred Color"),
+    (0x000D_005D, "Preferred Color is Locked"),
+    (0x000D_005E, "Preferred Line Width"),
+    (0x000D_005F, "Preferred Line Width is Locked"),
+    (0x000D_0060, "Latency Mode"),
+    (0x000D_0061, "Gesture Character Quality"),
+    (0x000D_0062, "Character Gesture Data Length"),
+    (0x000D_0063, "Character Gesture Data"),
+    (0x000D_0064, "Gesture Character Encoding"),
+    (0x000D_0065, "UTF8 Character Gesture Encoding"),
+    (0x000D_0066, "UTF16 Little Endian Character Gesture Encoding"),
+    (0x000D_0067, "UTF16 Big Endian Character Gesture Encoding"),
+    (0x000D_0068, "UTF32 Little Endian Character Gesture Encoding"),
+    (0x000D_0069, "UTF32 Big Endian Character Gesture Encoding"),
+    (0x000D_006A, "Capacitive Heat Map Protocol Vendor ID"),
+    (0x000D_006B, "Capacitive Heat Map Protocol Version"),
+    (0x000D_006C, "Capacitive Heat Map Frame Data"),
+    (0x000D_006D, "Gesture Character Enable"),
+    (0x000D_006E, "Transducer Serial Number Part 2"),
+    (0x000D_006F, "No Preferred Color"),
+    (0x000D_0070, "Preferred Line Style"),
+    (0x000D_0071, "Preferred Line Style is Locked"),
+    (0x000D_0072, "Ink"),
+    (0x000D_0073, "Pencil"),
+    (0x000D_0074, "Highlighter"),
+    (0x000D_0075, "Chisel Marker"),
+    (0x000D_0076, "Brush"),
+    (0x000D_0077, "No Preference"),
+    (0x000D_0080, "Digitizer Diagnostic"),
+    (0x000D_0081, "Digitizer Error"),
+    (0x000D_0082, "Err Normal Status"),
+    (0x000D_0083, "Err Transducers Exceeded"),
+    (0x000D_0084, "Err Full Trans Features Unavailable"),
+    (0x000D_0085, "Err Charge Low"),
+    (0x000D_0090, "Transducer Software Info"),
+    (0x000D_0091, "Transducer Vendor Id"),
+    (0x000D_0092, "Transducer Product Id"),
+    (0x000D_0093, "Device Supported Protocols"),
+    (0x000D_0094, "Transducer Supported Protocols"),
+    (0x000D_0095, "No Protocol"),
+    (0x000D_0096, "Wacom AES Protocol"),
+    (0x000D_0097, "USI Protocol"),
+    (0x000D_0098, "Microsoft Pen Protocol"),
+    (0x000D_00A0, "Supported Report Rates"),
+    (0x000D_00A1, "Report Rate"),
+    (0x000D_00A2, "Transducer Connected"),
+    (0x000D_00A3, "Switch Disabled"),
+    (0x000D_00A4, "Switch Unimplemented"),
+    (0x000D_00A5, "Transducer Switches"),
+    (0x000D_00A6, "Transducer Index Selector"),
+    (0x000D_00B0, "Button Press Threshold"),
+    (0x000E_0000, "Undefined"),
+    (0x000E_0001, "Simple Haptic Controller"),
+    (0x000E_0010, "Waveform List"),
+    (0x000E_0011, "Duration List"),
+    (0x000E_0020, "Auto Trigger"),
+    (0x000E_0021, "Manual Trigger"),
+    (0x000E_0022, "Auto Trigger Associated Control"),
+    (0x000E_0023, "Intensity"),
+    (0x000E_0024, "Repeat Count"),
+    (0x000E_0025, "Retrigger Period"),
+    (0x000E_0026, "Waveform Vendor Page"),
+    (0x000E_0027, "Waveform Vendor ID"),
+    (0x000E_0028, "Waveform Cutoff Time"),
+    (0x000E_1001, "Waveform None"),
+    (0x000E_1002, "Waveform Stop"),
+    (0x000E_1003, "Waveform Click"),
+    (0x000E_1004, "Waveform Buzz Continuous"),
+    (0x000E_1005, "Waveform Rumble Continuous"),
+    (0x000E_1006, "Waveform Press"),
+    (0x000E_1007, "Waveform Release"),
+    (0x000E_1008, "Waveform Hover"),
+    (0x000E_1009, "Waveform Success"),
+    (0x000E_100A, "Waveform Error"),
+    (0x000E_100B, "Waveform Ink Continuous"),
+    (0x000E_100C, "Waveform Pencil Continuous"),
+    (0x000E_100D, "Waveform Marker Continuous"),
+    (0x000E_100E, "Waveform Chisel Marker Continuous"),
+    (0x000E_100F, "Waveform Brush Continuous"),
+    (0x000E_1010, "Waveform Eraser Continuous"),
+    (0x000E_1011, "Waveform Sparkle Continuous"),
+    (0x000F_0000, "Undefined"),
+    (0x000F_0001, "Physical Input Device"),
+    (0x000F_0020, "Normal"),
+    (0x000F_0021, "Set Effect Report"),
+    (0x000F_0022, "Effect Parameter Block Index"),
+    (0x000F_0023, "Parameter Block Offset"),
+    (0x000F_0024, "ROM Flag"),
+    (0x000F_0025, "Effect Type"),
+    (0x000F_0026, "ET Constant-Force"),
+    (0x000F_0027, "ET Ramp"),
+    (0x000F_0028, "ET Custom-Force"),
+    (0x000F_0030, "ET Square"),
+    (0x000F_0031, "ET Sine"),
+    (0x000F_0032, "ET Triangle"),
+    (0x000F_0033, "ET Sawtooth Up"),
+    (0x000F_0034, "ET Sawtooth Down"),
+    (0x000F_0040, "ET Spring"),
+    (0x000F_0041, "ET Damper"),
+    (0x000F_0042, "ET Inertia"),
+    (0x000F_0043, "ET Friction"),
+    (0x000F_0050, "Duration"),
+    (0x000F_0051, "Sample Period"),
+    (0x000F_0052, "Gain"),
+    (0x000F_0053, "Trigger Button"),
+    (0x000F_0054, "Trigger Repeat Interval"),
+    (0x000F_0055, "Axes Enable"),
+    (0x000F_0056, "Direction Enable"),
+    (0x000F_0057, "Direction"),
+    (0x000F_0058, "Type Specific Block Offset"),
+    (0x000F_0059, "Block Type"),
+    (0x000F_005A, "Set Envelope Report"),
+    (0x000F_005B, "Attack Level"),
+    (0x000F_005C, "Attack Time"),
+    (0x000F_005D, "Fade Level"),
+    (0x000F_005E, "Fade Time"),
+    (0x000F_005F, "Set Condition Report"),
+    (0x000F_0060, "Center-Point Offset"),
+    (0x000F_0061, "Positive Coefficient"),
+    (0x000F_0062, "Negative Coefficient"),
+    (0x000F_0063, "Positive Saturation"),
+    (0x000F_0064, "Negative Saturation"),
+    (0x000F_0065, "Dead Band"),
+    (0x000F_0066, "Download Force Sample"),
+    (0x000F_0067, "Isoch Custom-Force Enable"),
+    (0x000F_0068, "Custom-Force Data Report"),
+    (0x000F_0069, "Custom-Force Data"),
+    (0x000F_006A, "Custom-Force Vendor Defined Data"),
+    (0x000F_006B, "Set Custom-Force Report"),
+    (0x000F_006C, "Custom-Force Data Offset"),
+    (0x000F_006D, "Sample Count"),
+    (0x000F_006E, "Set Periodic Report"),
+    (0x000F_006F, "Offset"),
+    (0x000F_0070, "Magnitude"),
+    (0x000F_0071, "Phase"),
+    (0x000F_0072, "Period"),
+    (0x000F_0073, "Set Constant-Force Report"),
+    (0x000F_0074, "Set Ramp-Force Report"),
+    (0x000F_0075, "Ramp Start"),
+    (0x000F_0076, "Ramp End"),
+    (0x000F_0077, "Effect Operation Report"),
+    (0x000F_0078, "Effect Operation"),
+    (0x000F_0079, "Op Effect Start"),
+    (0x000F_007A, "Op Effect Start Solo"),
+    (0x000F_007B, "Op Effect Stop"),
+    (0x000F_007C, "Loop Count"),
+    (0x000F_007D, "Device Gain Report"),
+    (0x000F_007E, "Device Gain"),
+    (0x000F_007F, "Parameter Block Pools Report"),
+    (0x000F_0080, "RAM Pool Size"),
+    (0x000F_0081, "ROM Pool Size"),
+    (0x000F_0082, "ROM Effect Block Count"),
+    (0x000F_0083, "Simultaneous Effects Max"),
+    (0x000F_0084, "Pool Alignment"),
+    (0x000F_0085, "Parameter Block Move Report"),
+    (0x000F_0086, "Move Source"),
+    (0x000F_0087, "Move Destination"),
+    (0x000F_0088, "Move Length"),
+    (0x000F_0089, "Effect Parameter Block Load Report"),
+    (0x000F_008B, "Effect Parameter Block Load Status"),
+    (0x000F_008C, "Block Load Success"),
+    (0x000F_008D, "Block Load Full"),
+    (0x000F_008E, "Block Load Error"),
+    (0x000F_008F, "Block Handle"),
+    (0x000F_0090, "Effect Parameter Block Free Report"),
+    (0x000F_0091, "Type Specific Block Handle"),
+    (0x000F_0092, "PID State Report"),
+    (0x000F_0094, "Effect Playing"),
+    (0x000F_0095, "PID Device Control Report"),
+    (0x000F_0096, "PID Device Control"),
+    (0x000F_0097, "DC Enable Actuators"),
+    (0x000F_0098, "DC Disable Actuators"),
+    (0x000F_0099, "DC Stop All Effects"),
+    (0x000F_009A, "DC Reset"),
+    (0x000F_009B, "DC Pause"),
+    (0x000F_009C, "DC Continue"),
+    (0x000F_009F, "Device Paused"),
+    (0x000F_00A0, "Actuators Enabled"),
+    (0x000F_00A4, "Safety Switch"),
+    (0x000F_00A5, "Actuator Override Switch"),
+    (0x000F_00A6, "Actuator Power"),
+    (0x000F_00A7, "Start Delay"),
+    (0x000F_00A8, "Parameter Block Size"),
+    (0x000F_00A9, "Device-Managed Pool"),
+    (0x000F_00AA, "Shared Parameter Blocks"),
+    (0x000F_00AB, "Create New Effect Parameter Block Report"),
+    (0x000F_00AC, "RAM Pool Available"),
+    (0x0011_0000, "Undefined"),
+    (0x0011_0001, "SocControl"),
+    (0x0011_0002, "FirmwareTransfer"),
+    (0x0011_0003, "FirmwareFileId"),
+    (0x0011_0004, "FileOffsetInBytes"),
+    (0x0011_0005, "FileTransferSizeMaxInBytes"),
+    (0x0011_0006, "FilePayload"),
+    (0x0011_0007, "FilePayloadSizeInBytes"),
+    (0x0011_0008, "FilePayloadContainsLastBytes"),
+    (0x0011_0009, "FileTransferStop"),
+    (0x0011_000A, "FileTransferTillEnd"),
+    (0x0012_0000, "Undefined"),
+    (0x0012_0001, "Eye Tracker"),
+    (0x0012_0002, "Head Tracker"),
+    (0x0012_0010, "Tracking Data"),
+    (0x0012_0011, "Capabilities"),
+    (0x0012_0012, "Configuration"),
+    (0x0012_0013, "Status"),
+    (0x0012_0014, "Control"),
+    (0x0012_0020, "Sensor Timestamp"),
+    (0x0012_0021, "Position X"),
+    (0x0012_0022, "Position Y"),
+    (0x0012_0023, "Position Z"),
+    (0x0012_0024, "Gaze Point"),
+    (0x0012_0025, "Left Eye Position"),
+    (0x0012_0026, "Right Eye Position"),
+    (0x0012_0027, "Head Position"),
+    (0x0012_0028, "Head Direction Point"),
+    (0x0012_0029, "Rotation about X axis"),
+    (0x0012_002A, "Rotation about Y axis"),
+    (0x0012_002B, "Rotation about Z axis"),
+    (0x0012_0100, "Tracker Quality"),
+    (0x0012_0101, "Minimum Tracking Distance"),
+    (0x0012_0102, "Optimum Tracking Distance"),
+    (0x0012_0103, "Maximum Tracking Distance"),
+    (0x0012_0104, "Maximum Screen Plane Width"),
+    (0x0012_0105, "Maximum Screen Plane Height"),
+    (0x0012_0200, "Display Manufacturer ID"),
+    (0x0012_0201, "Display Product ID"),
+    (0x0012_0202, "Display Serial Number"),
+    (0x0012_0203, "Display Manufacturer Date"),
+    (0x0012_0204, "Calibrated Screen Width"),
+    (0x0012_0205, "Calibrated Screen Height"),
+    (0x0012_0300, "Sampling Frequency"),
+    (0x0012_0301, "Configuration Status"),
+    (0x0012_0400, "Device Mode Request"),
+    (0x0014_0000, "Undefined"),
+    (0x0014_0001, "Alphanumeric Display"),
+    (0x0014_0002, "Auxiliary Display"),
+    (0x0014_0020, "Display Attributes Report"),
+    (0x0014_0021, "ASCII Character Set"),
+    (0x0014_0022, "Data Read Back"),
+    (0x0014_0023, "Font Read Back"),
+    (0x0014_0024, "Display Control Report"),
+    (0x0014_0025, "Clear Display"),
+    (0x0014_0026, "Display Enable"),
+    (0x0014_0027, "Screen Saver Delay"),
+    (0x0014_0028, "Screen Saver Enable"),
+    (0x0014_0029, "Vertical Scroll"),
+    (0x0014_002A, "Horizontal Scroll"),
+    (0x0014_002B, "Character Report"),
+    (0x0014_002C, "Display Data"),
+    (0x0014_002D, "Display Status"),
+    (0x0014_002E, "Stat Not Ready"),
+    (0x0014_002F, "Stat Ready"),
+    (0x0014_0030, "Err Not a loadable character"),
+    (0x0014_0031, "Err Font data cannot be read"),
+    (0x0014_0032, "Cursor Position Report"),
+    (0x0014_0033, "Row"),
+    (0x0014_0034, "Column"),
+    (0x0014_0035, "Rows"),
+    (0x0014_0036, "Columns"),
+    (0x0014_0037, "Cursor Pixel Positioning"),
+    (0x0014_0038, "Cursor Mode"),
+    (0x0014_0039, "Cursor Enable"),
+    (0x0014_003A, "Cursor Blink"),
+    (0x0014_003B, "Font Report"),
+    (0x0014_003C, "Font Data"),
+    (0x0014_003D, "Character Width"),
+    (0x0014_003E, "Character Height"),
+    (0x0014_003F, "Character Spacing Horizontal"),
+    (0x0014_0040, "Character Spacing Vertical"),
+    (0x0014_0041, "Unicode Character Set"),
+    (0x0014_0042, "Font 7-Segment"),
+    (0x0014_0043, "7-Segment Direct Map"),
+    (0x0014_0044, "Font 14-Segment"),
+    (0x0014_0045, "14-Segment Direct Map"),
+    (0x0014_0046, "Display Brightness"),
+    (0x0014_0047, "Display Contrast"),
+    (0x0014_0048, "Character Attribute"),
+    (0x0014_0049, "Attribute Readback"),
+    (0x0014_004A, "Attribute Data"),
+    (0x0014_004B, "Char Attr Enhance"),
+    (0x0014_004C, "Char Attr Underline"),
+    (0x0014_004D, "Char Attr Blink"),
+    (0x0014_0080, "Bitmap Size X"),
+    (0x0014_0081, "Bitmap Size Y"),
+    (0x0014_0082, "Max Blit Size"),
+    (0x0014_0083, "Bit Depth Format"),
+    (0x0014_0084, "Display Orientation"),
+    (0x0014_0085, "Palette Report"),
+    (0x0014_0086, "Palette Data Size"),
+    (0x0014_0087, "Palette Data Offset"),
+    (0x0014_0088, "Palette Data"),
+    (0x0014_008A, "Blit Report"),
+    (0x0014_008B, "Blit Rectangle X1"),
+    (0x0014_008C, "Blit Rectangle Y1"),
+    (0x0014_008D, "Blit Rectangle X2"),
+    (0x0014_008E, "Blit Rectangle Y2"),
+    (0x0014_008F, "Blit Data"),
+    (0x0014_0090, "Soft Button"),
+    (0x0014_0091, "Soft Button ID"),
+    (0x0014_0092, "Soft Button Side"),
+    (0x0014_0093, "Soft Button Offset 1"),
+    (0x0014_0094, "Soft Button Offset 2"),
+    (0x0014_0095, "Soft Button Report"),
+    (0x0014_00C2, "Soft Keys"),
+    (0x0014_00CC, "Display Data Extensions"),
+    (0x0014_00CF, "Character Mapping"),
+    (0x0014_00DD, "Unicode Equivalent"),
+    (0x0014_00DF, "Character Page Mapping"),
+    (0x0014_00FF, "Request Report"),
+    (0x0020_0000, "Undefined"),
+    (0x0020_0001, "Sensor"),
+    (0x0020_0010, "Biometric"),
+    (0x0020_0011, "Biometric: Human Presence"),
+    (0x0020_0012, "Biometric: Human Proximity"),
+    (0x0020_0013, "Biometric: Human Touch"),
+    (0x0020_0014, "Biometric: Blood Pressure"),
+    (0x0020_0015, "Biometric: Body Temperature"),
+    (0x0020_0016, "Biometric: Heart Rate"),
+    (0x0020_0017, "Biometric: Heart Rate Variability"),
+    (0x0020_0018, "Biometric: Peripheral Oxygen Saturation"),
+    (0x0020_0019, "Biometric: Respiratory Rate"),
+    (0x0020_0020, "Electrical"),
+    (0x0020_0021, "Electrical: Capacitance"),
+    (0x0020_0022, "Electrical: Current"),
+    (0x0020_0023, "Electrical: Power"),
+    (0x0020_0024, "Electrical: Inductance"),
+    (0x0020_0025, "Electrical: Resistance"),
+    (0x0020_0026, "Electrical: Voltage"),
+    (0x0020_0027, "Electrical: Potentiometer"),
+    (0x0020_0028, "Electrical: Frequency"),
+    (0x0020_0029, "Electrical: Period"),
+    (0x0020_0030, "Environmental"),
+    (0x0020_0031, "Environmental: Atmospheric Pressure"),
+    (0x0020_0032, "Environmental: Humidity"),
+    (0x0020_0033, "Environmental: Temperature"),
+    (0x0020_0034, "Environmental: Wind Direction"),
+    (0x0020_0035, "Environmental: Wind Speed"),
+    (0x0020_0036, "Environmental: Air Quality"),
+    (0x0020_0037, "Environmental: Heat Index"),
+    (0x0020_0038, "Environmental: Surface Temperature"),
+    (0x0020_0039, "Environmental: Volatile Organic Compounds"),
+    (0x0020_003A, "Environmental: Object Presence"),
+    (0x0020_003B, "Environmental: Object Proximity"),
+    (0x0020_0040, "Light"),
+    (0x0020_0041, "Light: Ambient Light"),
+    (0x0020_0042, "Light: Consumer Infrared"),
+    (0x0020_0043, "Light: Infrared Light"),
+    (0x0020_0044, "Light: Visible Light"),
+    (0x0020_0045, "Light: Ultraviolet Light"),
+    (0x0020_0050, "Location"),
+    (0x0020_0051, "Location: Broadcast"),
+    (0x0020_0052, "Location: Dead Reckoning"),
+    (0x0020_0053, "Location: GPS (Global Positioning System)"),
+    (0x0020_0054, "Location: Lookup"),
+    (0x0020_0055, "Location: Other"),
+    (0x0020_0056, "Location: Static"),
+    (0x0020_0057, "Location: Triangulation"),
+    (0x0020_0060, "Mechanical"),
+    (0x0020_0061, "Mechanical: Boolean Switch"),
+    (0x0020_0062, "Mechanical: Boolean Switch Array"),
+    (0x0020_0063, "Mechanical: Multivalue Switch"),
+    (0x0020_0064, "Mechanical: Force"),
+    (0x0020_0065, "Mechanical: Pressure"),
+    (0x0020_0066, "Mechanical: Strain"),
+    (0x0020_0067, "Mechanical: Weight"),
+    (0x0020_0068, "Mechanical: Haptic Vibrator"),
+    (0x0020_0069, "Mechanical: Hall Effect Switch"),
+    (0x0020_0070, "Motion"),
+    (0x0020_0071, "Motion: Accelerometer 1D"),
+    (0x0020_0072, "Motion: Accelerometer 2D"),
+    (0x0020_0073, "Motion: Accelerometer 3D"),
+    (0x0020_0074, "Motion: Gyrometer 1D"),
+    (0x0020_0075, "Motion: Gyrometer 2D"),
+    (0x0020_0076, "Motion: Gyrometer 3D"),
+    (0x0020_0077, "Motion: Motion Detector"),
+    (0x0020_0078, "Motion: Speedometer"),
+    (0x0020_0079, "Motion: Accelerometer"),
+    (0x0020_007A, "Motion: Gyrometer"),
+    (0x0020_007B, "Motion: Gravity Vector"),
+    (0x0020_007C, "Motion: Linear Accelerometer"),
+    (0x0020_0080, "Orientation"),
+    (0x0020_0081, "Orientation: Compass 1D"),
+    (0x0020_0082, "Orientation: Compass 2D"),
+    (0x0020_0083, "Orientation: Compass 3D"),
+    (0x0020_0084, "Orientation: Inclinometer 1D"),
+    (0x0020_0085, "Orientation: Inclinometer 2D"),
+    (0x0020_0086, "Orientation: Inclinometer 3D"),
+    (0x0020_0087, "Orientation: Distance 1D"),
+    (0x0020_0088, "Orientation: Distance 2D"),
+    (0x0020_0089, "Orientation: Distance 3D"),
+    (0x0020_008A, "Orientation: Device Orientation"),
+    (0x0020_008B, "Orientation: Compass"),
+    (0x0020_008C, "Orientation: Inclinometer"),
+    (0x0020_008D, "Orientation: Distance"),
+    (0x0020_008E, "Orientation: Relative Orientation"),
+    (0x0020_008F, "Orientation: Simple Orientation"),
+    (0x0020_0090, "Scanner"),
+    (0x0020_0091, "Scanner: Barcode"),
+    (0x0020_0092, "Scanner: RFID"),
+    (0x0020_0093, "Scanner: NFC"),
+    (0x0020_00A0, "Time"),
+    (0x0020_00A1, "Time: Alarm Timer"),
+    (0x0020_00A2, "Time: Real Time Clock"),
+    (0x0020_00B0, "Personal Activity"),
+    (0x0020_00B1, "Personal Activity: Activity Detection"),
+    (0x0020_00B2, "Personal Activity: Device Position"),
+    (0x0020_00B3, "Personal Activity: Floor Tracker"),
+    (0x0020_00B4, "Personal Activity: Pedometer"),
+    (0x0020_00B5, "Personal Activity: Step Detection"),
+    (0x0020_00C0, "Orientation Extended"),
+    (0x0020_00C1, "Orientation Extended: Geomagnetic Orientation"),
+    (0x0020_00C2, "Orientation Extended: Magnetometer"),
+    (0x0020_00D0, "Gesture"),
+    (0x0020_00D1, "Gesture: Chassis Flip Gesture"),
+    (0x0020_00D2, "Gesture: Hinge Fold Gesture"),
+    (0x0020_00E0, "Other"),
+    (0x0020_00E1, "Other: Custom"),
+    (0x0020_00E2, "Other: Generic"),
+    (0x0020_00E3, "Other: Generic Enumerator"),
+    (0x0020_00E4, "Other: Hinge Angle"),
+    (0x0020_00F0, "Vendor Reserved 1"),
+    (0x0020_00F1, "Vendor Reserved 2"),
+    (0x0020_00F2, "Vendor Reserved 3"),
+    (0x0020_00F3, "Vendor Reserved 4"),
+    (0x0020_00F4, "Vendor Reserved 5"),
+    (0x0020_00F5, "Vendor Reserved 6"),
+    (0x0020_00F6, "Vendor Reserved 7"),
+    (0x0020_00F7, "Vendor Reserved 8"),
+    (0x0020_00F8, "Vendor Reserved 9"),
+    (0x0020_00F9, "Vendor Reserved 10"),
+    (0x0020_00FA, "Vendor Reserved 11"),
+    (0x0020_00FB, "Vendor Reserved 12"),
+    (0x0020_00FC, "Vendor Reserved 13"),
+    (0x0020_00FD, "Vendor Reserved 14"),
+    (0x0020_00FE, "Vendor Reserved 15"),
+    (0x0020_00FF, "Vendor Reserved 16"),
+    (0x0020_0200, "Event"),
+    (0x0020_0201, "Event: Sensor State"),
+    (0x0020_0202, "Event: Sensor Event"),
+    (0x0020_0300, "Property"),
+    (0x0020_0301, "Property: Friendly Name"),
+    (0x0020_0302, "Property: Persistent Unique ID"),
+    (0x0020_0303, "Property: Sensor Status"),
+    (0x0020_0304, "Property: Minimum Report Interval"),
+    (0x0020_0305, "Property: Sensor Manufacturer"),
+    (0x0020_0306, "Property: Sensor Model"),
+    (0x0020_0307, "Property: Sensor Serial Number"),
+    (0x0020_0308, "Property: Sensor Description"),
+    (0x0020_0309, "Property: Sensor Connection Type"),
+    (0x0020_030A, "Property: Sensor Device Path"),
+    (0x0020_030B, "Property: Hardware Revision"),
+    (0x0020_030C, "Property: Firmware Version"),
+    (0x0020_030D, "Property: Release Date"),
+    (0x0020_030E, "Property: Report Interval"),
+    (0x0020_030F, "Property: Change Sensitivity Absolute"),
+    (0x0020_0310, "Property: Change Sensitivity Percent of Range"),
+    (0x0020_0311, "Property: Change Sensitivity Percent Relative"),
+    (0x0020_0312, "Property: Accuracy"),
+    (0x0020_0313, "Property: Resolution"),
+    (0x0020_0314, "Property: Maximum"),
+    (0x0020_0315, "Property: Minimum"),
+    (0x0020_0316, "Property: Reporting State"),
+    (0x0020_0317, "Property: Sampling Rate"),
+    (0x0020_0318, "Property: Response Curve"),
+    (0x0020_0319, "Property: Power State"),
+    (0x0020_031A, "Property: Maximum FIFO Events"),
+    (0x0020_031B, "Property: Report Latency"),
+    (0x0020_031C, "Property: Flush FIFO Events"),
+    (0x0020_031D, "Property: Maximum Power Consumption"),
+    (0x0020_031E, "Property: Is Primary"),
+    (0x0020_031F, "Property: Human Presence Detection Type"),
+    (0x0020_0400, "Data Field: Location"),
+    (0x0020_0402, "Data Field: Altitude Antenna Sea Level"),
+    (0x0020_0403, "Data Field: Differential Reference Station ID"),
+    (0x0020_0404, "Data Field: Altitude Ellipsoid Error"),
+    (0x0020_0405, "Data Field: Altitude Ellipsoid"),
+    (0x0020_0406, "Data Field: Altitude Sea Level Error"),
+    (0x0020_0407, "Data Field: Altitude Sea Level"),
+    (0x0020_0408, "Data Field: Differential GPS Data Age"),
+    (0x0020_0409, "Data Field: Error Radius"),
+    (0x0020_040A, "Data Field: Fix Quality"),
+    (0x0020_040B, "Data Field: Fix Type"),
+    (0x0020_040C, "Data Field: Geoidal Separation"),
+    (0x0020_040D, "Data Field: GPS Operation Mode"),
+    (0x0020_040E, "Data Field: GPS Selection Mode"),
+    (0x0020_040F, "Data Field: GPS Status"),
+    (0x0020_0410, "Data Field: Position Dilution of Precision"),
+    (0x0020_0411, "Data Field: Horizontal Dilution of Precision"),
+    (0x0020_0412, "Data Field: Vertical Dilution of Precision"),
+    (0x0020_0413, "Data Field: Latitude"),
+    (0x0020_0414, "Data Field: Longitude"),
+    (0x0020_0415, "Data Field: True Heading"),
+    (0x0020_0416, "Data Field: Magnetic Heading"),
+    (0x0020_0417, "Data Field: Magnetic Variation"),
+    (0x0020_0418, "Data Field: Speed"),
+    (0x0020_0419, "Data Field: Satellites in View"),
+    (0x0020_041A, "Data Field: Satellites in View Azimuth"),
+    (0x0020_041B, "Data Field: Satellites in View Elevation"),
+    (0x0020_041C, "Data Field: Satellites in View IDs"),
+    (0x0020_041D, "Data Field: Satellites in View PRNs"),
+    (0x0020_041E, "Data Field: Satellites in View S/N Ratios"),
+    (0x0020_041F, "Data Field: Satellites Used Count"),
+    (0x0020_0420, "Data Field: Satellites Used PRNs"),
+    (0x0020_0421, "Data Field: NMEA Sentence"),
+    (0x0020_0422, "Data Field: Address Line 1"),
+    (0x0020_0423, "Data Field: Address Line 2"),
+    (0x0020_0424, "Data Field: City"),
+    (0x0020_0425, "Data Field: State or Province"),
+    (0x0020_0426, "Data Field: Country or Region"),
+    (0x0020_0427, "Data Field: Postal Code"),
+    (0x0020_042A, "Property: Location"),
+    (0x0020_042B, "Property: Location Desired Accuracy"),
+    (0x0020_0430, "Data Field: Environmental"),
+    (0x0020_0431, "Data Field: Atmospheric Pressure"),
+    (0x0020_0433, "Data Field: Relative Humidity"),
+    (0x0020_0434, "Data Field: Temperature"),
+    (0x0020_0435, "Data Field: Wind Direction"),
+    (0x0020_0436, "Data Field: Wind Speed"),
+    (0x0020_0437, "Data Field: Air Quality Index"),
+    (0x0020_0438, "Data Field: Equivalent CO2"),
+    (0x0020_0439, "Data Field: Volatile Organic Compound Concentration"),
+    (0x0020_043A, "Data Field: Object Presence"),
+    (0x0020_043B, "Data Field: Object Proximity Range"),
+    (0x0020_043C, "Data Field: Object Proximity Out of Range"),
+    (0x0020_0440, "Property: Environmental"),
+    (0x0020_0441, "Property: Reference Pressure"),
+    (0x0020_0450, "Data Field: Motion"),
+    (0x0020_0451, "Data Field: Motion State"),
+    (0x0020_0452, "Data Field: Acceleration"),
+    (0x0020_0453, "Data Field: Acceleration Axis X"),
+    (0x0020_0454, "Data Field: Acceleration Axis Y"),
+    (0x0020_0455, "Data Field: Acceleration Axis Z"),
+    (0x0020_0456, "Data Field: Angular Velocity"),
+    (0x0020_0457, "Data Field: Angular Velocity about X Axis"),
+    (0x0020_0458, "Data Field: Angular Velocity about Y Axis"),
+    (0x0020_0459, "Data Field: Angular Velocity about Z Axis"),
+    (0x0020_045A, "Data Field: Angular Position"),
+    (0x0020_045B, "Data Field: Angular Position about X Axis"),
+    (0x0020_045C, "Data Field: Angular Position about Y Axis"),
+    (0x0020_045D, "Data Field: Angular Position about Z Axis"),
+    (0x0020_045E, "Data Field: Motion Speed"),
+    (0x0020_045F, "Data Field: Motion Intensity"),
+    (0x0020_0470, "Data Field: Orientation"),
+    (0x0020_0471, "Data Field: Heading"),
+    (0x0020_0472, "Data Field: Heading X Axis"),
+    (0x0020_0473, "Data Field: Heading Y Axis"),
+    (0x0020_0474, "Data Field: Heading Z Axis"),
+    (0x0020_0475, "Data Field: Heading Compensated Magnetic North"),
+    (0x0020_0476, "Data Field: Heading Compensated True North"),
+    (0x0020_0477, "Data Field: Heading Magnetic North"),
+    (0x0020_0478, "Data Field: Heading True North"),
+    (0x0020_0479, "Data Field: Distance"),
+    (0x0020_047A, "Data Field: Distance X Axis"),
+    (0x0020_047B, "Data Field: Distance Y Axis"),
+    (0x0020_047C, "Data Field: Distance Z Axis"),
+    (0x0020_047D, "Data Field: Distance Out-of-Range"),
+    (0x0020_047E, "Data Field: Tilt"),
+    (0x0020_047F, "Data Field: Tilt X Axis"),
+    (0x0020_0480, "Data Field: Tilt Y Axis"),
+    (0x0020_0481, "Data Field: Tilt Z Axis"),
+    (0x0020_0482, "Data Field: Rotation Matrix"),
+    (0x0020_0483, "Data Field: Quaternion"),
+    (0x0020_0484, "Data Field: Magnetic Flux"),
+    (0x0020_0485, "Data Field: Magnetic Flux X Axis"),
+    (0x0020_0486, "Data Field: Magnetic Flux Y Axis"),
+    (0x0020_0487, "Data Field: Magnetic Flux Z Axis"),
+    (0x0020_0488, "Data Field: Magnetometer Accuracy"),
+    (0x0020_0489, "Data Field: Simple Orientation Direction"),
+    (0x0020_0490, "Data Field: Mechanical"),
+    (0x0020_0491, "Data Field: Boolean Switch State"),
+    (0x0020_0492, "Data Field: Boolean Switch Array States"),
+    (0x0020_0493, "Data Field: Multivalue Switch Value"),
+    (0x0020_0494, "Data Field: Force"),
+    (0x0020_0495, "Data Field: Absolute Pressure"),
+    (0x0020_0496, "Data Field: Gauge Pressure"),
+    (0x0020_0497, "Data Field: Strain"),
+    (0x0020_0498, "Data Field: Weight"),
+    (0x0020_04A0, "Property: Mechanical"),
+    (0x0020_04A1, "Property: Vibration State"),
+    (0x0020_04A2, "Property: Forward Vibration Speed"),
+    (0x0020_04A3, "Property: Backward Vibration Speed"),
+    (0x0020_04B0, "Data Field: Biometric"),
+    (0x0020_04B1, "Data Field: Human Presence"),
+    (0x0020_04B2, "Data Field: Human Proximity Range"),
+    (0x0020_04B3, "Data Field: Human Proximity Out of Range"),
+    (0x0020_04B4, "Data Field: Human Touch State"),
+    (0x0020_04B5, "Data Field: Blood Pressure"),
+    (0x0020_04B6, "Data Field: Blood Pressure Diastolic"),
+    (0x0020_04B7, "Data Field: Blood Pressure Systolic"),
+    (0x0020_04B8, "Data Field: Heart Rate"),
+    (0x0020_04B9, "Data Field: Resting Heart Rate"),
+    (0x0020_04BA, "Data Field: Heartbeat Interval"),
+    (0x0020_04BB, "Data Field: Respiratory Rate"),
+    (0x0020_04BC, "Data Field: SpO2"),
+    (0x0020_04BD, "Data Field: Human Attention Detected"),
+    (0x0020_04BE, "Data Field: Human Head Azimuth"),
+    (0x0020_04BF, "Data Field: Human Head Altitude"),
+    (0x0020_04C0, "Data Field: Human Head Roll"),
+    (0x0020_04C1, "Data Field: Human Head Pitch"),
+    (0x0020_04C2, "Data Field: Human Head Yaw"),
+    (0x0020_04C3, "Data Field: Human Correlation Id"),
+    (0x0020_04D0, "Data Field: Light"),
+    (0x0020_04D1, "Data Field: Illuminance"),
+    (0x0020_04D2, "Data Field: Color Temperature"),
+    (0x0020_04D3, "Data Field: Chromaticity"),
+    (0x0020_04D4, "Data Field: Chromaticity X"),
+    (0x0020_04D5, "Data Field: Chromaticity Y"),
+    (0x0020_04D6, "Data Field: Consumer IR Sentence Receive"),
+    (0x0020_04D7, "Data Field: Infrared Light"),
+    (0x0020_04D8, "Data Field: Red Light"),
+    (0x0020_04D9, "Data Field: Green Light"),
+    (0x0020_04DA, "Data Field: Blue Light"),
+    (0x0020_04DB, "Data Field: Ultraviolet A Light"),
+    (0x0020_04DC, "Data Field: Ultraviolet B Light"),
+    (0x0020_04DD, "Data Field: Ultraviolet Index"),
+    (0x0020_04DE, "Data Field: Near Infrared Light"),
+    (0x0020_04DF, "Property: Light"),
+    (0x0020_04E0, "Property: Consumer IR Sentence Send"),
+    (0x0020_04E2, "Property: Auto Brightness Preferred"),
+    (0x0020_04E3, "Property: Auto Color Preferred"),
+    (0x0020_04F0, "Data Field: Scanner"),
+    (0x0020_04F1, "Data Field: RFID Tag 40 Bit"),
+    (0x0020_04F2, "Data Field: NFC Sentence Receive"),
+    (0x0020_04F8, "Property: Scanner"),
+    (0x0020_04F9, "Property: NFC Sentence Send"),
+    (0x0020_0500, "Data Field: Electrical"),
+    (0x0020_0501, "Data Field: Capacitance"),
+    (0x0020_0502, "Data Field: Current"),
+    (0x0020_0503, "Data Field: Electrical Power"),
+    (0x0020_0504, "Data Field: Inductance"),
+    (0x0020_0505, "Data Field: Resistance"),
+    (0x0020_0506, "Data Field: Voltage"),
+    (0x0020_0507, "Data Field: Frequency"),
+    (0x0020_0508, "Data Field: Period"),
+    (0x0020_0509, "Data Field: Percent of Range"),
+    (0x0020_0520, "Data Field: Time"),
+    (0x0020_0521, "Data Field: Year"),
+    (0x0020_0522, "Data Field: Month"),
+    (0x0020_0523, "Data Field: Day"),
+    (0x0020_0524, "Data Field: Day of Week"),
+    (0x0020_0525, "Data Field: Hour"),
+    (0x0020_0526, "Data Field: Minute"),
+    (0x0020_0527, "Data Field: Second"),
+    (0x0020_0528, "Data Field: Millisecond"),
+    (0x0020_0529, "Data Field: Timestamp"),
+    (0x0020_052A, "Data Field: Julian Day of Year"),
+    (0x0020_052B, "Data Field: Time Since System Boot"),
+    (0x0020_0530, "Property: Time"),
+    (0x0020_0531, "Property: Time Zone Offset from UTC"),
+    (0x0020_0532, "Property: Time Zone Name"),
+    (0x0020_0533, "Property: Daylight Savings Time Observed"),
+    (0x0020_0534, "Property: Time Trim Adjustment"),
+    (0x0020_0535, "Property: Arm Alarm"),
+    (0x0020_0540, "Data Field: Custom"),
+    (0x0020_0541, "Data Field: Custom Usage"),
+    (0x0020_0542, "Data Field: Custom Boolean Array"),
+    (0x0020_0543, "Data Field: Custom Value"),
+    (0x0020_0544, "Data Field: Custom Value 1"),
+    (0x0020_0545, "Data Field: Custom Value 2"),
+    (0x0020_0546, "Data Field: Custom Value 3"),
+    (0x0020_0547, "Data Field: Custom Value 4"),
+    (0x0020_0548, "Data Field: Custom Value 5"),
+    (0x0020_0549, "Data Field: Custom Value 6"),
+    (0x0020_054A, "Data Field: Custom Value 7"),
+    (0x0020_054B, "Data Field: Custom Value 8"),
+    (0x0020_054C, "Data Field: Custom Value 9"),
+    (0x0020_054D, "Data Field: Custom Value 10"),
+    (0x0020_054E, "Data Field: Custom Value 11"),
+    (0x0020_054F, "Data Field: Custom Value 12"),
+    (0x0020_0550, "Data Field: Custom Value 13"),
+    (0x0020_0551, "Data Field: Custom Value 14"),
+    (0x0020_0552, "Data Field: Custom Value 15"),
+    (0x0020_0553, "Data Field: Custom Value 16"),
+    (0x0020_0554, "Data Field: Custom Value 17"),
+    (0x0020_0555, "Data Field: Custom Value 18"),
+    (0x0020_0556, "Data Field: Custom Value 19"),
+    (0x0020_0557, "Data Field: Custom Value 20"),
+    (0x0020_0558, "Data Field: Custom Value 21"),
+    (0x0020_0559, "Data Field: Custom Value 22"),
+    (0x0020_055A, "Data Field: Custom Value 23"),
+    (0x0020_055B, "Data Field: Custom Value 24"),
+    (0x0020_055C, "Data Field: Custom Value 25"),
+    (0x0020_055D, "Data Field: Custom Value 26"),
+    (0x0020_055E, "Data Field: Custom Value 27"),
+    (0x0020_055F, "Data Field: Custom Value 28"),
+    (0x0020_0560, "Data Field: Generic"),
+    (0x0020_0561, "Data Field: Generic GUID or PROPERTYKEY"),
+    (0x0020_0562, "Data Field: Generic Category GUID"),
+    (0x0020_0563, "Data Field: Generic Type GUID"),
+    (0x0020_0564, "Data Field: Generic Event PROPERTYKEY"),
+    (0x0020_0565, "Data Field: Generic Property PROPERTYKEY"),
+    (0x0020_0566, "Data Field: Generic Data Field PROPERTYKEY"),
+    (0x0020_0567, "Data Field: Generic Event"),
+    (0x0020_0568, "Data Field: Generic Property"),
+    (0x0020_0569, "Data Field: Generic Data Field"),
+    (0x0020_056A, "Data Field: Enumerator Table Row Index"),
+    (0x0020_056B, "Data Field: Enumerator Table Row Count"),
+    (0x0020_056C, "Data Field: Generic GUID or PROPERTYKEY kind"),
+    (0x0020_056D, "Data Field: Generic GUID"),
+    (0x0020_056E, "Data Field: Generic PROPERTYKEY"),
+    (0x0020_056F, "Data Field: Generic Top Level Collection ID"),
+    (0x0020_0570, "Data Field: Generic Report ID"),
+    (0x0020_0571, "Data Field: Generic Report Item Position Index"),
+    (0x0020_0572, "Data Field: Generic Firmware VARTYPE"),
+    (0x0020_0573, "Data Field: Generic Unit of Measure"),
+    (0x0020_0574, "Data Field: Generic Unit Exponent"),
+    (0x0020_0575, "Data Field: Generic Report Size"),
+    (0x0020_0576, "Data Field: Generic Report Count"),
+    (0x0020_0580, "Property: Generic"),
+    (0x0020_0581, "Property: Enumerator Table Row Index"),
+    (0x0020_0582, "Property: Enumerator Table Row Count"),
+    (0x0020_0590, "Data Field: Personal Activity"),
+    (0x0020_0591, "Data Field: Activity Type"),
+    (0x0020_0592, "Data Field: Activity State"),
+    (0x0020_0593, "Data Field: Device Position"),
+    (0x0020_0594, "Data Field: Step Count"),
+    (0x0020_0595, "Data Field: Step Count Reset"),
+    (0x0020_0596, "Data Field: Step Duration"),
+    (0x0020_0597, "Data Field: Step Type"),
+    (0x0020_05A0, "Property: Minimum Activity Detection Interval"),
+    (0x0020_05A1, "Property: Supported Activity Types"),
+    (0x0020_05A2, "Property: Subscribed Activity Types"),
+    (0x0020_05A3, "Property: Supported Step Types"),
+    (0x0020_05A4, "Property: Subscribed Step Types"),
+    (0x0020_05A5, "Property: Floor Height"),
+    (0x0020_05B0, "Data Field: Custom Type ID"),
+    (0x0020_05C0, "Property: Custom"),
+    (0x0020_05C1, "Property: Custom Value 1"),
+    (0x0020_05C2, "Property: Custom Value 2"),
+    (0x0020_05C3, "Property: Custom Value 3"),
+    (0x0020_05C4, "Property: Custom Value 4"),
+    (0x0020_05C5, "Property: Custom Value 5"),
+    (0x0020_05C6, "Property: Custom Value 6"),
+    (0x0020_05C7, "Property: Custom Value 7"),
+    (0x0020_05C8, "Property: Custom Value 8"),
+    (0x0020_05C9, "Property: Custom Value 9"),
+    (0x0020_05CA, "Property: Custom Value 10"),
+    (0x0020_05CB, "Property: Custom Value 11"),
+    (0x0020_05CC, "Property: Custom Value 12"),
+    (0x0020_05CD, "Property: Custom Value 13"),
+    (0x0020_05CE, "Property: Custom Value 14"),
+    (0x0020_05CF, "Property: Custom Value 15"),
+    (0x0020_05D0, "Property: Custom Value 16"),
+    (0x0020_05E0, "Data Field: Hinge"),
+    (0x0020_05E1, "Data Field: Hinge Angle"),
+    (0x0020_05F0, "Data Field: Gesture Sensor"),
+    (0x0020_05F1, "Data Field: Gesture State"),
+    (0x0020_05F2, "Data Field: Hinge Fold Initial Angle"),
+    (0x0020_05F3, "Data Field: Hinge Fold Final Angle"),
+    (0x0020_05F4, "Data Field: Hinge Fold Contributing Panel"),
+    (0x0020_05F5, "Data Field: Hinge Fold Type"),
+    (0x0020_0800, "Sensor State: Undefined"),
+    (0x0020_0801, "Sensor State: Ready"),
+    (0x0020_0802, "Sensor State: Not Available"),
+    (0x0020_0803, "Sensor State: No Data"),
+    (0x0020_0804, "Sensor State: Initializing"),
+    (0x0020_0805, "Sensor State: Access Denied"),
+    (0x0020_0806, "Sensor State: Error"),
+    (0x0020_0810, "Sensor Event: Unknown"),
+    (0x0020_0811, "Sensor Event: State Changed"),
+    (0x0020_0812, "Sensor Event: Property Changed"),
+    (0x0020_0813, "Sensor Event: Data Updated"),
+    (0x0020_0814, "Sensor Event: Poll Response"),
+    (0x0020_0815, "Sensor Event: Change Sensitivity"),
+    (0x0020_0816, "Sensor Event: Range Maximum Reached"),
+    (0x0020_0817, "Sensor Event: Range Minimum Reached"),
+    (0x0020_0818, "Sensor Event: High Threshold Cross Upward"),
+    (0x0020_0819, "Sensor Event: High Threshold Cross Downward"),
+    (0x0020_081A, "Sensor Event: Low Threshold Cross Upward"),
+    (0x0020_081B, "Sensor Event: Low Threshold Cross Downward"),
+    (0x0020_081C, "Sensor Event: Zero Threshold Cross Upward"),
+    (0x0020_081D, "Sensor Event: Zero Threshold Cross Downward"),
+    (0x0020_081E, "Sensor Event: Period Exceeded"),
+    (0x0020_081F, "Sensor Event: Frequency Exceeded"),
+    (0x0020_0820, "Sensor Event: Complex Trigger"),
+    (0x0020_0830, "Connection Type: PC Integrated"),
+    (0x0020_0831, "Connection Type: PC Attached"),
+    (0x0020_0832, "Connection Type: PC External"),
+    (0x0020_0840, "Reporting State: Report No Events"),
+    (0x0020_0841, "Reporting State: Report All Events"),
+    (0x0020_0842, "Reporting State: Report Threshold Events"),
+    (0x0020_0843, "Reporting State: Wake On No Events"),
+    (0x0020_0844, "Reporting State: Wake On All Events"),
+    (0x0020_0845, "Reporting State: Wake On Threshold Events"),
+    (0x0020_0846, "Reporting State: Anytime"),
+    (0x0020_0850, "Power State: Undefined"),
+    (0x0020_0851, "Power State: D0 Full Power"),
+    (0x0020_0852, "Power State: D1 Low Power"),
+    (0x0020_0853, "Power State: D2 Standby Power with Wakeup"),
+    (0x0020_0854, "Power State: D3 Sleep with Wakeup"),
+    (0x0020_0855, "Power State: D4 Power Off"),
+    (0x0020_0860, "Accuracy: Default"),
+    (0x0020_0861, "Accuracy: High"),
+    (0x0020_0862, "Accuracy: Medium"),
+    (0x0020_0863, "Accuracy: Low"),
+    (0x0020_0870, "Fix Quality: No Fix"),
+    (0x0020_0871, "Fix Quality: GPS"),
+    (0x0020_0872, "Fix Quality: DGPS"),
+    (0x0020_0880, "Fix Type: No Fix"),
+    (0x0020_0881, "Fix Type: GPS SPS Mode, Fix Valid"),
+    (0x0020_0882, "Fix Type: DGPS SPS Mode, Fix Valid"),
+    (0x0020_0883, "Fix Type: GPS PPS Mode, Fix Valid"),
+    (0x0020_0884, "Fix Type: Real Time Kinematic"),
+    (0x0020_0885, "Fix Type: Float RTK"),
+    (0x0020_0886, "Fix Type: Estimated (dead reckoned)"),
+    (0x0020_0887, "Fix Type: Manual Input Mode"),
+    (0x0020_0888, "Fix Type: Simulator Mode"),
+    (0x0020_0890, "GPS Operation Mode: Manual"),
+    (0x0020_0891, "GPS Operation Mode: Automatic"),
+    (0x0020_08A0, "GPS Selection Mode: Autonomous"),
+    (0x0020_08A1, "GPS Selection Mode: DGPS"),
+    (0x0020_08A2, "GPS Selection Mode: Estimated (dead reckoned)"),
+    (0x0020_08A3, "GPS Selection Mode: Manual Input"),
+    (0x0020_08A4, "GPS Selection Mode: Simulator"),
+    (0x0020_08A5, "GPS Selection Mode: Data Not Valid"),
+    (0x0020_08B0, "GPS Status Data: Valid"),
+    (0x0020_08B1, "GPS Status Data: Not Valid"),
+    (0x0020_08C0, "Day of Week: Sunday"),
+    (0x0020_08C1, "Day of Week: Monday"),
+    (0x0020_08C2, "Day of Week: Tuesday"),
+    (0x0020_08C3, "Day of Week: Wednesday"),
+    (0x0020_08C4, "Day of Week: Thursday"),
+    (0x0020_08C5, "Day of Week: Friday"),
+    (0x0020_08C6, "Day of Week: Saturday"),
+    (0x0020_08D0, "Kind: Category"),
+    (0x0020_08D1, "Kind: Type"),
+    (0x0020_08D2, "Kind: Event"),
+    (0x0020_08D3, "Kind: Property"),
+    (0x0020_08D4, "Kind: Data Field"),
+    (0x0020_08E0, "Magnetometer Accuracy: Low"),
+    (0x0020_08E1, "Magnetometer Accuracy: Medium"),
+    (0x0020_08E2, "Magnetometer Accuracy: High"),
+    (0x0020_08F0, "Simple Orientation Direction: Not Rotated"),
+    (0x0020_08F1, "Simple Orientation Direction: Rotated 90 Degrees CCW"),
+    (0x0020_08F2, "Simple Orientation Direction: Degrees CCW"),
+    (0x0020_08F3, "Simple Orientation Direction: Degrees CCW"),
+    (0x0020_08F4, "Simple Orientation Direction: Face Up"),
+    (0x0020_08F5, "Simple Orientation Direction: Face Down"),
+    (0x0020_0900, "VT_NULL"),
+    (0x0020_0901, "VT_BOOL"),
+    (0x0020_0902, "VT_UI1"),
+    (0x0020_0903, "VT_I1"),
+    (0x0020_0904, "VT_UI2"),
+    (0x0020_0905, "VT_I2"),
+    (0x0020_0906, "VT_UI4"),
+    (0x0020_0907, "VT_I4"),
+    (0x0020_0908, "VT_UI8"),
+    (0x0020_0909, "VT_I8"),
+    (0x0020_090A, "VT_R4"),
+    (0x0020_090B, "VT_R8"),
+    (0x0020_090C, "VT_WSTR"),
+    (0x0020_090D, "VT_STR"),
+    (0x0020_090E, "VT_CLSID"),
+    (0x0020_090F, "VT_VECTOR VT_UI1"),
+    (0x0020_0910, "VT_F16E0"),
+    (0x0020_0911, "VT_F16E1"),
+    (0x0020_0912, "VT_F16E2"),
+    (0x0020_0913, "VT_F16E3"),
+    (0x0020_0914, "VT_F16E4"),
+    (0x0020_0915, "VT_F16E5"),
+    (0x0020_0916, "VT_F16E6"),
+    (0x0020_0917, "VT_F16E7"),
+    (0x0020_0918, "VT_F16E8"),
+    (0x0020_0919, "VT_F16E9"),
+    (0x0020_091A, "VT_F16EA"),
+    (0x0020_091B, "VT_F16EB"),
+    (0x0020_091C, "VT_F16EC"),
+    (0x0020_091D, "VT_F16ED"),
+    (0x0020_091E, "VT_F16EE"),
+    (0x0020_091F, "VT_F16EF"),
+    (0x0020_0920, "VT_F32E0"),
+    (0x0020_0921, "VT_F32E1"),
+    (0x0020_0922, "VT_F32E2"),
+    (0x0020_0923, "VT_F32E3"),
+    (0x0020_0924, "VT_F32E4"),
+    (0x0020_0925, "VT_F32E5"),
+    (0x0020_0926, "VT_F32E6"),
+    (0x0020_0927, "VT_F32E7"),
+    (0x0020_0928, "VT_F32E8"),
+    (0x0020_0929, "VT_F32E9"),
+    (0x0020_092A, "VT_F32EA"),
+    (0x0020_092B, "VT_F32EB"),
+    (0x0020_092C, "VT_F32EC"),
+    (0x0020_092D, "VT_F32ED"),
+    (0x0020_092E, "VT_F32EE"),
+    (0x0020_092F, "VT_F32EF"),
+    (0x0020_0930, "Activity Type: Unknown"),
+    (0x0020_0931, "Activity Type: Stationary"),
+    (0x0020_0932, "Activity Type: Fidgeting"),
+    (0x0020_0933, "Activity Type: Walking"),
+    (0x0020_0934, "Activity Type: Running"),
+    (0x0020_0935, "Activity Type: In Vehicle"),
+    (0x0020_0936, "Activity Type: Biking"),
+    (0x0020_0937, "Activity Type: Idle"),
+    (0x0020_0940, "Unit: Not Specified"),
+    (0x0020_0941, "Unit: Lux"),
+    (0x0020_0942, "Unit: Degrees Kelvin"),
+    (0x0020_0943, "Unit: Degrees Celsius"),
+    (0x0020_0944, "Unit: Pascal"),
+    (0x0020_0945, "Unit: Newton"),
+    (0x0020_0946, "Unit: Meters/Second"),
+    (0x0020_0947, "Unit: Kilogram"),
+    (0x0020_0948, "Unit: Meter"),
+    (0x0020_0949, "Unit: Meters/Second/Second"),
+    (0x0020_094A, "Unit: Farad"),
+    (0x0020_094B, "Unit: Ampere"),
+    (0x0020_094C, "Unit: Watt"),
+    (0x0020_094D, "Unit: Henry"),
+    (0x0020_094E, "Unit: Ohm"),
+    (0x0020_094F, "Unit: Volt"),
+    (0x0020_0950, "Unit: Hertz"),
+    (0x0020_0951, "Unit: Bar"),
+    (0x0020_0952, "Unit: Degrees Anti-clockwise"),
+    (0x0020_0953, "Unit: Degrees Clockwise"),
+    (0x0020_0954, "Unit: Degrees"),
+    (0x0020_0955, "Unit: Degrees/Second"),
+    (0x0020_0956, "Unit: Degrees/Second/Second"),
+    (0x0020_0957, "Unit: Knot"),
+    (0x0020_0958, "Unit: Percent"),
+    (0x0020_0959, "Unit: Second"),
+    (0x0020_095A, "Unit: Millisecond"),
+    (0x0020_095B, "Unit: G"),
+    (0x0020_095C, "Unit: Bytes"),
+    (0x0020_095D, "Unit: Milligauss"),
+    (0x0020_095E, "Unit: Bits"),
+    (0x0020_0960, "Activity State: No State Change"),
+    (0x0020_0961, "Activity State: Start Activity"),
+    (0x0020_0962, "Activity State: End Activity"),
+    (0x0020_0970, "Exponent 0"),
+    (0x0020_0971, "Exponent 1"),
+    (0x0020_0972, "Exponent 2"),
+    (0x0020_0973, "Exponent 3"),
+    (0x0020_0974, "Exponent 4"),
+    (0x0020_0975, "Exponent 5"),
+    (0x0020_0976, "Exponent 6"),
+    (0x0020_0977, "Exponent 7"),
+    (0x0020_0978, "Exponent 8"),
+    (0x0020_0979, "Exponent 9"),
+    (0x0020_097A, "Exponent A"),
+    (0x0020_097B, "Exponent B"),
+    (0x0020_097C, "Exponent C"),
+    (0x0020_097D, "Exponent D"),
+    (0x0020_097E, "Exponent E"),
+    (0x0020_097F, "Exponent F"),
+    (0x0020_0980, "Device Position: Unknown"),
+    (0x0020_0981, "Device Position: Unchanged"),
+    (0x0020_0982, "Device Position: On Desk"),
+    (0x0020_0983, "Device Position: In Hand"),
+    (0x0020_0984, "Device Position: Moving in Bag"),
+    (0x0020_0985, "Device Position: Stationary in Bag"),
+    (0x0020_0990, "Step Type: Unknown"),
+    (0x0020_0991, "Step Type: Walking"),
+    (0x0020_0992, "Step Type: Running"),
+    (0x0020_09A0, "Gesture State: Unknown"),
+    (0x0020_09A1, "Gesture State: Started"),
+    (0x0020_09A2, "Gesture State: Completed"),
+    (0x0020_09A3, "Gesture State: Cancelled"),
+    (0x0020_09B0, "Hinge Fold Contributing Panel: Unknown"),
+    (0x0020_09B1, "Hinge Fold Contributing Panel: Panel 1"),
+    (0x0020_09B2, "Hinge Fold Contributing Panel: Panel 2"),
+    (0x0020_09B3, "Hinge Fold Contributing Panel: Both"),
+    (0x0020_09B4, "Hinge Fold Type: Unknown"),
+    (0x0020_09B5, "Hinge Fold Type: Increasing"),
+    (0x0020_09B6, "Hinge Fold Type: Decreasing"),
+    (0x0020_09C0, "Human Presence Detection Type: Vendor-Defined Non-Biometric"),
+    (0x0020_09C1, "Human Presence Detection Type: Vendor-Defined Biometric"),
+    (0x0020_09C2, "Human Presence Detection Type: Facial Biometric"),
+    (0x0020_09C3, "Human Presence Detection Type: Audio Biometric"),
+    (0x0020_1000, "Modifier: Change Sensitivity Absolute"),
+    (0x0020_2000, "Modifier: Maximum"),
+    (0x0020_3000, "Modifier: Minimum"),
+    (0x0020_4000, "Modifier: Accuracy"),
+    (0x0020_5000, "Modifier: Resolution"),
+    (0x0020_6000, "Modifier: Threshold High"),
+    (0x0020_7000, "Modifier: Threshold Low"),
+    (0x0020_8000, "Modifier: Calibration Offset"),
+    (0x0020_9000, "Modifier: Calibration Multiplier"),
+    (0x0020_A000, "Modifier: Report Interval"),
+    (0x0020_B000, "Modifier: Frequency Max"),
+    (0x0020_C000, "Modifier: Period Max"),
+    (0x0020_D000, "Modifier: Change Sensitivity Percent of Range"),
+    (0x0020_E000, "Modifier: Change Sensitivity Percent Relative"),
+    (0x0020_F000, "Modifier: Vendor Reserved"),
+    (0x0040_0000, "Undefined"),
+    (0x0040_0001, "Medical Ultrasound"),
+    (0x0040_0020, "VCR/Acquisition"),
+    (0x0040_0021, "Freeze/Thaw"),
+    (0x0040_0022, "Clip Store"),
+    (0x0040_0023, "Update"),
+    (0x0040_0024, "Next"),
+    (0x0040_0025, "Save"),
+    (0x0040_0026, "Print"),
+    (0x0040_0027, "Microphone Enable"),
+    (0x0040_0040, "Cine"),
+    (0x0040_0041, "Transmit Power"),
+    (0x0040_0042, "Volume"),
+    (0x0040_0043, "Focus"),
+    (0x0040_0044, "Depth"),
+    (0x0040_0060, "Soft Step - Primary"),
+    (0x0040_0061, "Soft Step - Secondary"),
+    (0x0040_0070, "Depth Gain Compensation"),
+    (0x0040_0080, "Zoom Select"),
+    (0x0040_0081, "Zoom Adjust"),
+    (0x0040_0082, "Spectral Doppler Mode Select"),
+    (0x0040_0083, "Spectral Doppler Adjust"),
+    (0x0040_0084, "Color Doppler Mode Select"),
+    (0x0040_0085, "Color Doppler Adjust"),
+    (0x0040_0086, "Motion Mode Select"),
+    (0x0040_0087, "Motion Mode Adjust"),
+    (0x0040_0088, "2-D Mode Select"),
+    (0x0040_0089, "2-D Mode Adjust"),
+    (0x0040_00A0, "Soft Control Select"),
+    (0x0040_00A1, "Soft Control Adjust"),
+    (0x0041_0000, "Undefined"),
+    (0x0041_0001, "Braille Display"),
+    (0x0041_0002, "Braille Row"),
+    (0x0041_0003, "8 Dot Braille Cell"),
+    (0x0041_0004, "6 Dot Braille Cell"),
+    (0x0041_0005, "Number of Braille Cells"),
+    (0x0041_0006, "Screen Reader Control"),
+    (0x0041_0007, "Screen Reader Identifier"),
+    (0x0041_00FA, "Router Set 1"),
+    (0x0041_00FB, "Router Set 2"),
+    (0x0041_00FC, "Router Set 3"),
+    (0x0041_0100, "Router Key"),
+    (0x0041_0101, "Row Router Key"),
+    (0x0041_0200, "Braille Buttons"),
+    (0x0041_0201, "Braille Keyboard Dot 1"),
+    (0x0041_0202, "Braille Keyboard Dot 2"),
+    (0x0041_0203, "Braille Keyboard Dot 3"),
+    (0x0041_0204, "Braille Keyboard Dot 4"),
+    (0x0041_0205, "Braille Keyboard Dot 5"),
+    (0x0041_0206, "Braille Keyboard Dot 6"),
+    (0x0041_0207, "Braille Keyboard Dot 7"),
+    (0x0041_0208, "Braille Keyboard Dot 8"),
+    (0x0041_0209, "Braille Keyboard Space"),
+    (0x0041_020A, "Braille Keyboard Left Space"),
+    (0x0041_020B, "Braille Keyboard Right Space"),
+    (0x0041_020C, "Braille Face Controls"),
+    (0x0041_020D, "Braille Left Controls"),
+    (0x0041_020E, "Braille Right Controls"),
+    (0x0041_020F, "Braille Top Controls"),
+    (0x0041_0210, "Braille Joystick Center"),
+    (0x0041_0211, "Braille Joystick Up"),
+    (0x0041_0212, "Braille Joystick Down"),
+    (0x0041_0213, "Braille Joystick Left"),
+    (0x0041_0214, "Braille Joystick Right"),
+    (0x0041_0215, "Braille D-Pad Center"),
+    (0x0041_0216, "Braille D-Pad Up"),
+    (0x0041_0217, "Braille D-Pad Down"),
+    (0x0041_0218, "Braille D-Pad Left"),
+    (0x0041_0219, "Braille D-Pad Right"),
+    (0x0041_021A, "Braille Pan Left"),
+    (0x0041_021B, "Braille Pan Right"),
+    (0x0041_021C, "Braille Rocker Up"),
+    (0x0041_021D, "Braille Rocker Down"),
+    (0x0041_021E, "Braille Rocker Press"),
+    (0x0059_0000, "Undefined"),
+    (0x0059_0001, "LampArray"),
+    (0x0059_0002, "LampArrayAttributesReport"),
+    (0x0059_0003, "LampCount"),
+    (0x0059_0004, "BoundingBoxWidthInMicrometers"),
+    (0x0059_0005, "BoundingBoxHeightInMicrometers"),
+    (0x0059_0006, "BoundingBoxDepthInMicrometers"),
+    (0x0059_0007, "LampArrayKind"),
+    (0x0059_0008, "MinUpdateIntervalInMicroseconds"),
+    (0x0059_0020, "LampAttributesRequestReport"),
+    (0x0059_0021, "LampId"),
+    (0x0059_0022, "LampAttributesResponseReport"),
+    (0x0059_0023, "PositionXInMicrometers"),
+    (0x0059_0024, "PositionYInMicrometers"),
+    (0x0059_0025, "PositionZInMicrometers"),
+    (0x0059_0026, "LampPurposes"),
+    (0x0059_0027, "UpdateLatencyInMicroseconds"),
+    (0x0059_0028, "RedLevelCount"),
+    (0x0059_0029, "GreenLevelCount"),
+    (0x0059_002A, "BlueLevelCount"),
+    (0x0059_002B, "IntensityLevelCount"),
+    (0x0059_002C, "IsProgrammable"),
+    (0x0059_002D, "InputBinding"),
+    (0x0059_0050, "LampMultiUpdateReport"),
+    (0x0059_0051, "RedUpdateChannel"),
+    (0x0059_0052, "GreenUpdateChannel"),
+    (0x0059_0053, "BlueUpdateChannel"),
+    (0x0059_0054, "IntensityUpdateChannel"),
+    (0x0059_0055, "LampUpdateFlags"),
+    (0x0059_0060, "LampRangeUpdateReport"),
+    (0x0059_0061, "LampIdStart"),
+    (0x0059_0062, "LampIdEnd"),
+    (0x0059_0070, "LampArrayControlReport"),
+    (0x0059_0071, "AutonomousMode"),
+    (0x0080_0000, "Undefined"),
+    (0x0080_0001, "Monitor Control"),
+    (0x0080_0002, "EDID Information"),
+    (0x0080_0003, "VDIF Information"),
+    (0x0080_0004, "VESA Version"),
+    (0x0081_0000, "Reserved"),
+    (0x0082_0000, "Undefined"),
+    (0x0082_0001, "Degauss"),
+    (0x0082_0010, "Brightness"),
+    (0x0082_0012, "Contrast"),
+    (0x0082_0016, "Red Video Gain"),
+    (0x0082_0018, "Green Video Gain"),
+    (0x0082_001A, "Blue Video Gain"),
+    (0x0082_001C, "Focus"),
+    (0x0082_0020, "Horizontal Position"),
+    (0x0082_0022, "Horizontal Size"),
+    (0x0082_0024, "Horizontal Pincushion"),
+    (0x0082_0026, "Horizontal Pincushion Balance"),
+    (0x0082_0028, "Horizontal Misconvergence"),
+    (0x0082_002A, "Horizontal Linearity"),
+    (0x0082_002C, "Horizontal Linearity Balance"),
+    (0x0082_0030, "Vertical Position"),
+    (0x0082_0032, "Vertical Size"),
+    (0x0082_0034, "Vertical Pincushion"),
+    (0x0082_0036, "Vertical Pincushion Balance"),
+    (0x0082_0038, "Vertical Misconvergence"),
+    (0x0082_003A, "Vertical Linearity"),
+    (0x0082_003C, "Vertical Linearity Balance"),
+    (0x0082_0040, "Parallelogram Distortion (Key Balance)"),
+    (0x0082_0042, "Trapezoidal Distortion (Key)"),
+    (0x0082_0044, "Tilt (Rotation)"),
+    (0x0082_0046, "Top Corner Distortion Control"),
+    (0x0082_0048, "Top Corner Distortion Balance"),
+    (0x0082_004A, "Bottom Corner Distortion Control"),
+    (0x0082_004C, "Bottom Corner Distortion Balance"),
+    (0x0082_0056, "Horizontal Moiré"),
+    (0x0082_0058, "Vertical Moiré"),
+    (0x0082_005E, "Input Level Select"),
+    (0x0082_0060, "Input Source Select"),
+    (0x0082_006C, "Red Video Black Level"),
+    (0x0082_006E, "Green Video Black Level"),
+    (0x0082_0070, "Blue Video Black Level"),
+    (0x0082_00A2, "Auto Size Center"),
+    (0x0082_00A4, "Polarity Horizontal Synchronization"),
+    (0x0082_00A6, "Polarity Vertical Synchronization"),
+    (0x0082_00A8, "Synchronization Type"),
+    (0x0082_00AA, "Screen Orientation"),
+    (0x0082_00AC, "Horizontal Frequency"),
+    (0x0082_00AE, "Vertical Frequency"),
+    (0x0082_00B0, "Settings"),
+    (0x0082_00CA, "On Screen Display"),
+    (0x0082_00D4, "Stereo Mode"),
+    (0x0084_0000, "Undefined"),
+    (0x0084_0001, "iName"),
+    (0x0084_0002, "Present Status"),
+    (0x0084_0003, "Changed Status"),
+    (0x0084_0004, "UPS"),
+    (0x0084_0005, "Power Supply"),
+    (0x0084_0010, "Battery System"),
+    (0x0084_0011, "Battery System Id"),
+    (0x0084_0012, "Battery"),
+    (0x0084_0013, "Battery Id"),
+    (0x0084_0014, "Charger"),
+    (0x0084_0015, "Charger Id"),
+    (0x0084_0016, "Power Converter"),
+    (0x0084_0017, "Power Converter Id"),
+    (0x0084_0018, "Outlet System"),
+    (0x0084_0019, "Outlet System Id"),
+    (0x0084_001A, "Input"),
+    (0x0084_001B, "Input Id"),
+    (0x0084_001C, "Output"),
+    (0x0084_001D, "Output Id"),
+    (0x0084_001E, "Flow"),
+    (0x0084_001F, "Flow Id"),
+    (0x0084_0020, "Outlet"),
+    (0x0084_0021, "Outlet Id"),
+    (0x0084_0022, "Gang"),
+    (0x0084_0023, "Gang Id"),
+    (0x0084_0024, "Power Summary"),
+    (0x0084_0025, "Power Summary Id"),
+    (0x0084_0030, "Voltage"),
+    (0x0084_0031, "Current"),
+    (0x0084_0032, "Frequency"),
+    (0x0084_0033, "Apparent Power"),
+    (0x0084_0034, "Active Power"),
+    (0x0084_0035, "Percent Load"),
+    (0x0084_0036, "Temperature"),
+    (0x0084_0037, "Humidity"),
+    (0x0084_0038, "Bad Count"),
+    (0x0084_0040, "Config Voltage"),
+    (0x0084_0041, "Config Current"),
+    (0x0084_0042, "Config Frequency"),
+    (0x0084_0043, "Config Apparent Power"),
+    (0x0084_0044, "Config Active Power"),
+    (0x0084_0045, "Config Percent Load"),
+    (0x0084_0046, "Config Temperature"),
+    (0x0084_0047, "Config Humidity"),
+    (0x0084_0050, "Switch On Control"),
+    (0x0084_0051, "Switch Off Control"),
+    (0x0084_0052, "Toggle Control"),
+    (0x0084_0053, "Low Voltage Transfer"),
+    (0x0084_0054, "High Voltage Transfer"),
+    (0x0084_0055, "Delay Before Reboot"),
+    (0x0084_0056, "Delay Before Startup"),
+    (0x0084_0057, "Delay Before Shutdown"),
+    (0x0084_0058, "Test"),
+    (0x0084_0059, "Module Reset"),
+    (0x0084_005A, "Audible Alarm Control"),
+    (0x0084_0060, "Present"),
+    (0x0084_0061, "Good"),
+    (0x0084_0062, "Internal Failure"),
+    (0x0084_0063, "Voltag Out Of Range"),
+    (0x0084_0064, "Frequency Out Of Range"),
+    (0x0084_0065, "Overload"),
+    (0x0084_0066, "Over Charged"),
+    (0x0084_0067, "Over Temperature"),
+    (0x0084_0068, "Shutdown Requested"),
+    (0x0084_0069, "Shutdown Imminent"),
+    (0x0084_006B, "Switch On/Off"),
+    (0x0084_006C, "Switchable"),
+    (0x0084_006D, "Used"),
+    (0x0084_006E, "Boost"),
+    (0x0084_006F, "Buck"),
+    (0x0084_0070, "Initialized"),
+    (0x0084_0071, "Tested"),
+    (0x0084_0072, "Awaiting Power"),
+    (0x0084_0073, "Communication Lost"),
+    (0x0084_00FD, "iManufacturer"),
+    (0x0084_00FE, "iProduct"),
+    (0x0084_00FF, "iSerialNumber"),
+    (0x0085_0000, "Undefined"),
+    (0x0085_0001, "Smart Battery Battery Mode"),
+    (0x0085_0002, "Smart Battery Battery Status"),
+    (0x0085_0003, "Smart Battery Alarm Warning"),
+    (0x0085_0004, "Smart Battery Charger Mode"),
+    (0x0085_0005, "Smart Battery Charger Status"),
+    (0x0085_0006, "Smart Battery Charger Spec Info"),
+    (0x0085_0007, "Smart Battery Selector State"),
+    (0x0085_0008, "Smart Battery Selector Presets"),
+    (0x0085_0009, "Smart Battery Selector Info"),
+    (0x0085_0010, "Optional Mfg Function 1"),
+    (0x0085_0011, "Optional Mfg Function 2"),
+    (0x0085_0012, "Optional Mfg Function 3"),
+    (0x0085_0013, "Optional Mfg Function 4"),
+    (0x0085_0014, "Optional Mfg Function 5"),
+    (0x0085_0015, "Connection To SM Bus"),
+    (0x0085_0016, "Output Connection"),
+    (0x0085_0017, "Charger Connection"),
+    (0x0085_0018, "Battery Insertion"),
+    (0x0085_0019, "Use Next"),
+    (0x0085_001A, "OK To Use"),
+    (0x0085_001B, "Battery Supported"),
+    (0x0085_001C, "Selector Revision"),
+    (0x0085_001D, "Charging Indicator"),
+    (0x0085_0028, "Manufacturer Access"),
+    (0x0085_0029, "Remaining Capacity Limit"),
+    (0x0085_002A, "Remaining Time Limit"),
+    (0x0085_002B, "At Rate"),
+    (0x0085_002C, "Capacity Mode"),
+    (0x0085_002D, "Broadcast To Charger"),
+    (0x0085_002E, "Primary Battery"),
+    (0x0085_002F, "Charge Controller"),
+    (0x0085_0040, "Terminate Charge"),
+    (0x0085_0041, "Terminate Discharge"),
+    (0x0085_0042, "Below Remaining Capacity Limit"),
+    (0x0085_0043, "Remaining Time Limit Expired"),
+    (0x0085_0044, "Charging"),
+    (0x0085_0045, "Discharging"),
+    (0x0085_0046, "Fully Charged"),
+    (0x0085_0047, "Fully Discharged"),
+    (0x0085_0048, "Conditioning Flag"),
+    (0x0085_0049, "At Rate OK"),
+    (0x0085_004A, "Smart Battery Error Code"),
+    (0x0085_004B, "Need Replacement"),
+    (0x0085_0060, "At Rate Time To Full"),
+    (0x0085_0061, "At Rate Time To Empty"),
+    (0x0085_0062, "Average Current"),
+    (0x0085_0063, "Max Error"),
+    (0x0085_0064, "Relative State Of Charge"),
+    (0x0085_0065, "Absolute State Of Charge"),
+    (0x0085_0066, "Remaining Capacity"),
+    (0x0085_0067, "Full Charge Capacity"),
+    (0x0085_0068, "Run Time To Empty"),
+    (0x0085_0069, "Average Time To Empty"),
+    (0x0085_006A, "Average Time To Full"),
+    (0x0085_006B, "Cycle Count"),
+    (0x0085_0080, "Battery Pack Model Level"),
+    (0x0085_0081, "Internal Charge Controller"),
+    (0x0085_0082, "Primary Battery Support"),
+    (0x0085_0083, "Design Capacity"),
+    (0x0085_0084, "Specification Info"),
+    (0x0085_0085, "Manufacture Date"),
+    (0x0085_0086, "Serial Number"),
+    (0x0085_0087, "iManufacturer Name"),
+    (0x0085_0088, "iDevice Name"),
+    (0x0085_0089, "iDevice Chemistry"),
+    (0x0085_008A, "Manufacturer Data"),
+    (0x0085_008B, "Rechargable"),
+    (0x0085_008C, "Warning Capacity Limit"),
+    (0x0085_008D, "Capacity Granularity 1"),
+    (0x0085_008E, "Capacity Granularity 2"),
+    (0x0085_008F, "iOEM Information"),
+    (0x0085_00C0, "Inhibit Charge"),
+    (0x0085_00C1, "Enable Polling"),
+    (0x0085_00C2, "Reset To Zero"),
+    (0x0085_00D0, "AC Present"),
+    (0x0085_00D1, "Battery Present"),
+    (0x0085_00D2, "Power Fail"),
+    (0x0085_00D3, "Alarm Inhibited"),
+    (0x0085_00D4, "Thermistor Under Range"),
+    (0x0085_00D5, "Thermistor Hot"),
+    (0x0085_00D6, "Thermistor Cold"),
+    (0x0085_00D7, "Thermistor Over Range"),
+    (0x0085_00D8, "Voltage Out Of Range"),
+    (0x0085_00D9, "Current Out Of Range"),
+    (0x0085_00DA, "Current Not Regulated"),
+    (0x0085_00DB, "Voltage Not Regulated"),
+    (0x0085_00DC, "Master Mode"),
+    (0x0085_00F0, "Charger Selector Support"),
+    (0x0085_00F1, "Charger Spec"),
+    (0x0085_00F2, "Level 2"),
+    (0x0085_00F3, "Level 3"),
+    (0x008C_0000, "Undefined"),
+    (0x008C_0001, "Barcode Badge Reader"),
+    (0x008C_0002, "Barcode Scanner"),
+    (0x008C_0003, "Dumb Bar Code Scanner"),
+    (0x008C_0004, "Cordless Scanner Base"),
+    (0x008C_0005, "Bar Code Scanner Cradle"),
+    (0x008C_0010, "Attribute Report"),
+    (0x008C_0011, "Settings Report"),
+    (0x008C_0012, "Scanned Data Report"),
+    (0x008C_0013, "Raw Scanned Data Report"),
+    (0x008C_0014, "Trigger Report"),
+    (0x008C_0015, "Status Report"),
+    (0x008C_0016, "UPC/EAN Control Report"),
+    (0x008C_0017, "EAN 2/3 Label Control Report"),
+    (0x008C_0018, "Code 39 Control Report"),
+    (0x008C_0019, "Interleaved 2 of 5 Control Report"),
+    (0x008C_001A, "Standard 2 of 5 Control Report"),
+    (0x008C_001B, "MSI Plessey Control Report"),
+    (0x008C_001C, "Codabar Control Report"),
+    (0x008C_001D, "Code 128 Control Report"),
+    (0x008C_001E, "Misc 1D Control Report"),
+    (0x008C_001F, "2D Control Report"),
+    (0x008C_0030, "Aiming/Pointer Mode"),
+    (0x008C_0031, "Bar Code Present Sensor"),
+    (0x008C_0032, "Class 1A Laser"),
+    (0x008C_0033, "Class 2 Laser"),
+    (0x008C_0034, "Heater Present"),
+    (0x008C_0035, "Contact Scanner"),
+    (0x008C_0036, "Electronic Article Surveillance Notification"),
+    (0x008C_0037, "Constant Electronic Article Surveillance"),
+    (0x008C_0038, "Error Indication"),
+    (0x008C_0039, "Fixed Beeper"),
+    (0x008C_003A, "Good Decode Indication"),
+    (0x008C_003B, "Hands Free Scanning"),
+    (0x008C_003C, "Intrinsically Safe"),
+    (0x008C_003D, "Klasse Eins Laser"),
+    (0x008C_003E, "Long Range Scanner"),
+    (0x008C_003F, "Mirror Speed Control"),
+    (0x008C_0040, "Not On File Indication"),
+    (0x008C_0041, "Programmable Beeper"),
+    (0x008C_0042, "Triggerless"),
+    (0x008C_0043, "Wand"),
+    (0x008C_0044, "Water Resistant"),
+    (0x008C_0045, "Multi-Range Scanner"),
+    (0x008C_0046, "Proximity Sensor"),
+    (0x008C_004D, "Fragment Decoding"),
+    (0x008C_004E, "Scanner Read Confidence"),
+    (0x008C_004F, "Data Prefix"),
+    (0x008C_0050, "Prefix AIMI"),
+    (0x008C_0051, "Prefix None"),
+    (0x008C_0052, "Prefix Proprietary"),
+    (0x008C_0055, "Active Time"),
+    (0x008C_0056, "Aiming Laser Pattern"),
+    (0x008C_0057, "Bar Code Present"),
+    (0x008C_0058, "Beeper State"),
+    (0x008C_0059, "Laser On Time"),
+    (0x008C_005A, "Laser State"),
+    (0x008C_005B, "Lockout Time"),
+    (0x008C_005C, "Motor State"),
+    (0x008C_005D, "Motor Timeout"),
+    (0x008C_005E, "Power On Reset Scanner"),
+    (0x008C_005F, "Prevent Read of Barcodes"),
+    (0x008C_0060, "Initiate Barcode Read"),
+    (0x008C_0061, "Trigger State"),
+    (0x008C_0062, "Trigger Mode"),
+    (0x008C_0063, "Trigger Mode Blinking Laser On"),
+    (0x008C_0064, "Trigger Mode Continuous Laser On"),
+    (0x008C_0065, "Trigger Mode Laser on while Pulled"),
+    (0x008C_0066, "Trigger Mode Laser stays on after release"),
+    (0x008C_006D, "Commit Parameters to NVM"),
+    (0x008C_006E, "Parameter Scanning"),
+    (0x008C_006F, "Parameters Changed"),
+    (0x008C_0070, "Set parameter default values"),
+    (0x008C_0075, "Scanner In Cradle"),
+    (0x008C_0076, "Scanner In Range"),
+    (0x008C_007A, "Aim Duration"),
+    (0x008C_007B, "Good Read Lamp Duration"),
+    (0x008C_007C, "Good Read Lamp Intensity"),
+    (0x008C_007D, "Good Read LED"),
+    (0x008C_007E, "Good Read Tone Frequency"),
+    (0x008C_007F, "Good Read Tone Length"),
+    (0x008C_0080, "Good Read Tone Volume"),
+    (0x008C_0082, "No Read Message"),
+    (0x008C_0083, "Not on File Volume"),
+    (0x008C_0084, "Powerup Beep"),
+    (0x008C_0085, "Sound Error Beep"),
+    (0x008C_0086, "Sound Good Read Beep"),
+    (0x008C_0087, "Sound Not On File Beep"),
+    (0x008C_0088, "Good Read When to Write"),
+    (0x008C_0089, "GRWTI After Decode"),
+    (0x008C_008A, "GRWTI Beep/Lamp after transmit"),
+    (0x008C_008B, "GRWTI No Beep/Lamp use at all"),
+    (0x008C_0091, "Bookland EAN"),
+    (0x008C_0092, "Convert EAN 8 to 13 Type"),
+    (0x008C_0093, "Convert UPC A to EAN-13"),
+    (0x008C_0094, "Convert UPC-E to A"),
+    (0x008C_0095, "EAN-13"),
+    (0x008C_0096, "EAN-8"),
+    (0x008C_0097, "EAN-99 128 Mandatory"),
+    (0x008C_0098, "EAN-99 P5/128 Optional"),
+    (0x008C_0099, "Enable EAN Two Label"),
+    (0x008C_009A, "UPC/EAN"),
+    (0x008C_009B, "UPC/EAN Coupon Code"),
+    (0x008C_009C, "UPC/EAN Periodicals"),
+    (0x008C_009D, "UPC-A"),
+    (0x008C_009E, "UPC-A Mandatory"),
+    (0x008C_009F, "UPC-A Optional"),
+    (0x008C_00A0, "UPC-A with P5 Optional"),
+    (0x008C_00A1, "UPC-E"),
+    (0x008C_00A2, "UPC-E1"),
+    (0x008C_00A9, "Periodical"),
+    (0x008C_00AA, "Periodical Auto-Discriminate +2"),
+    (0x008C_00AB, "Periodical Only Decode with +2"),
+    (0x008C_00AC, "Periodical Ignore +2"),
+    (0x008C_00AD, "Periodical Auto-Discriminate +5"),
+    (0x008C_00AE, "Periodical Only Decode with +5"),
+    (0x008C_00AF, "Periodical Ignore +5"),
+    (0x008C_00B0, "Check"),
+    (0x008C_00B1, "Check Disable Price"),
+    (0x008C_00B2, "Check Enable 4 digit Price"),
+    (0x008C_00B3, "Check Enable 5 digit Price"),
+    (0x008C_00B4, "Check Enable European 4 digit Price"),
+    (0x008C_00B5, "Check Enable European 5 digit Price"),
+    (0x008C_00B7, "EAN Two Label"),
+    (0x008C_00B8, "EAN Three Label"),
+    (0x008C_00B9, "EAN 8 Flag Digit 1"),
+    (0x008C_00BA, "EAN 8 Flag Digit 2"),
+    (0x008C_00BB, "EAN 8 Flag Digit 3"),
+    (0x008C_00BC, "EAN 13 Flag Digit 1"),
+    (0x008C_00BD, "EAN 13 Flag Digit 2"),
+    (0x008C_00BE, "EAN 13 Flag Digit 3"),
+    (0x008C_00BF, "Add Label Definition"),
+    (0x008C_00C0, "Clear all Label Definitions"),
+    (0x008C_00C3, "Codabar"),
+    (0x008C_00C4, "Code 128"),
+    (0x008C_00C7, "Code 39"),
+    (0x008C_00C8, "Code 93"),
+    (0x008C_00C9, "Full ASCII Conversion"),
+    (0x008C_00CA, "Interleaved 2 of 5"),
+    (0x008C_00CB, "Italian Pharmacy Code"),
+    (0x008C_00CC, "MSI/Plessey"),
+    (0x008C_00CD, "Standard 2 of 5 IATA"),
+    (0x008C_00CE, "Standard 2 of 5"),
+    (0x008C_00D3, "Transmit Start/Stop"),
+    (0x008C_00D4, "Tri-Optic"),
+    (0x008C_00D5, "UCC/EAN-128"),
+    (0x008C_00D6, "Check Digit"),
+    (0x008C_00D7, "Check Digit Disable"),
+    (0x008C_00D8, "Check Digit Enable Interleaved 2 of 5 OPCC"),
+    (0x008C_00D9, "Check Digit Enable Interleaved 2 of 5 USS"),
+    (0x008C_00DA, "Check Digit Enable Standard 2 of 5 OPCC"),
+    (0x008C_00DB, "Check Digit Enable Standard 2 of 5 USS"),
+    (0x008C_00DC, "Check Digit Enable One MSI Plessey"),
+    (0x008C_00DD, "Check Digit Enable Two MSI Plessey"),
+    (0x008C_00DE, "Check Digit Codabar Enable"),
+    (0x008C_00DF, "Check Digit Code 39 Enable"),
+    (0x008C_00F0, "Transmit Check Digit"),
+    (0x008C_00F1, "Disable Check Digit Transmit"),
+    (0x008C_00F2, "Enable Check Digit Transmit"),
+    (0x008C_00FB, "Symbology Identifier 1"),
+    (0x008C_00FC, "Symbology Identifier 2"),
+    (0x008C_00FD, "Symbology Identifier 3"),
+    (0x008C_00FE, "Decoded Data"),
+    (0x008C_00FF, "Decode Data Continued"),
+    (0x008C_0100, "Bar Space Data"),
+    (0x008C_0101, "Scanner Data Accuracy"),
+    (0x008C_0102, "Raw Data Polarity"),
+    (0x008C_0103, "Polarity Inverted Bar Code"),
+    (0x008C_0104, "Polarity Normal Bar Code"),
+    (0x008C_0106, "Minimum Length to Decode"),
+    (0x008C_0107, "Maximum Length to Decode"),
+    (0x008C_0108, "Discrete Length to Decode 1"),
+    (0x008C_0109, "Discrete Length to Decode 2"),
+    (0x008C_010A, "Data Length Method"),
+    (0x008C_010B, "DL Method Read any"),
+    (0x008C_010C, "DL Method Check in Range"),
+    (0x008C_010D, "DL Method Check for Discrete"),
+    (0x008C_0110, "Aztec Code"),
+    (0x008C_0111, "BC412"),
+    (0x008C_0112, "Channel Code"),
+    (0x008C_0113, "Code 16"),
+    (0x008C_0114, "Code 32"),
+    (0x008C_0115, "Code 49"),
+    (0x008C_0116, "Code One"),
+    (0x008C_0117, "Colorcode"),
+    (0x008C_0118, "Data Matrix"),
+    (0x008C_0119, "MaxiCode"),
+    (0x008C_011A, "MicroPDF"),
+    (0x008C_011B, "PDF-417"),
+    (0x008C_011C, "PosiCode"),
+    (0x008C_011D, "QR Code"),
+    (0x008C_011E, "SuperCode"),
+    (0x008C_011F, "UltraCode"),
+    (0x008C_0120, "USD-5 (Slug Code)"),
+    (0x008C_0121, "VeriCode"),
+    (0x008D_0000, "Undefined"),
+    (0x008D_0001, "Scales"),
+    (0x008D_0020, "Scale Device"),
+    (0x008D_0021, "Scale Class"),
+    (0x008D_0022, "Scale Class I Metric"),
+    (0x008D_0023, "Scale Class II Metric"),
+    (0x008D_0024, "Scale Class III Metric"),
+    (0x008D_0025, "Scale Class IIIL Metric"),
+    (0x008D_0026, "Scale Class IV Metric"),
+    (0x008D_0027, "Scale Class III English"),
+    (0x008D_0028, "Scale Class IIIL English"),
+    (0x008D_0029, "Scale Class IV English"),
+    (0x008D_002A, "Scale Class Generic"),
+    (0x008D_0030, "Scale Attribute Report"),
+    (0x008D_0031, "Scale Control Report"),
+    (0x008D_0032, "Scale Data Report"),
+    (0x008D_0033, "Scale Status Report"),
+    (0x008D_0034, "Scale Weight Limit Report"),
+    (0x008D_0035, "Scale Statistics Report"),
+    (0x008D_0040, "Data Weight"),
+    (0x008D_0041, "Data Scaling"),
+    (0x008D_0050, "Weight Unit"),
+    (0x008D_0051, "Weight Unit Milligram"),
+    (0x008D_0052, "Weight Unit Gram"),
+    (0x008D_0053, "Weight Unit Kilogram"),
+    (0x008D_0054, "Weight Unit Carats"),
+    (0x008D_0055, "Weight Unit Taels"),
+    (0x008D_0056, "Weight Unit Grains"),
+    (0x008D_0057, "Weight Unit Pennyweights"),
+    (0x008D_0058, "Weight Unit Metric Ton"),
+    (0x008D_0059, "Weight Unit Avoir Ton"),
+    (0x008D_005A, "Weight Unit Troy Ounce"),
+    (0x008D_005B, "Weight Unit Ounce"),
+    (0x008D_005C, "Weight Unit Pound"),
+    (0x008D_0060, "Calibration Count"),
+    (0x008D_0061, "Re-Zero Count"),
+    (0x008D_0070, "Scale Status"),
+    (0x008D_0071, "Scale Status Fault"),
+    (0x008D_0072, "Scale Status Stable at Center of Zero"),
+    (0x008D_0073, "Scale Status In Motion"),
+    (0x008D_0074, "Scale Status Weight Stable"),
+    (0x008D_0075, "Scale Status Under Zero"),
+    (0x008D_0076, "Scale Status Over Weight Limit"),
+    (0x008D_0077, "Scale Status Requires Calibration"),
+    (0x008D_0078, "Scale Status Requires Rezeroing"),
+    (0x008D_0080, "Zero Scale"),
+    (0x008D_0081, "Enforced Zero Return"),
+    (0x008E_0000, "Undefined"),
+    (0x008E_0001, "MSR Device Read-Only"),
+    (0x008E_0011, "Track 1 Length"),
+    (0x008E_0012, "Track 2 Length"),
+    (0x008E_0013, "Track 3 Length"),
+    (0x008E_0014, "Track JIS Length"),
+    (0x008E_0020, "Track Data"),
+    (0x008E_0021, "Track 1 Data"),
+    (0x008E_0022, "Track 2 Data"),
+    (0x008E_0023, "Track 3 Data"),
+    (0x008E_0024, "Track JIS Data"),
+    (0x0090_0000, "Undefined"),
+    (0x0090_0020, "Camera Auto-focus"),
+    (0x0090_0021, "Camera Shutter"),
+    (0x0091_0000, "Undefined"),
+    (0x0091_0001, "General Purpose IO Card"),
+    (0x0091_0002, "Coin Door"),
+    (0x0091_0003, "Watchdog Timer"),
+    (0x0091_0030, "General Purpose Analog Input State"),
+    (0x0091_0031, "General Purpose Digital Input State"),
+    (0x0091_0032, "General Purpose Optical Input State"),
+    (0x0091_0033, "General Purpose Digital Output State"),
+    (0x0091_0034, "Number of Coin Doors"),
+    (0x0091_0035, "Coin Drawer Drop Count"),
+    (0x0091_0036, "Coin Drawer Start"),
+    (0x0091_0037, "Coin Drawer Service"),
+    (0x0091_0038, "Coin Drawer Tilt"),
+    (0x0091_0039, "Coin Door Test"),
+    (0x0091_0040, "Coin Door Lockout"),
+    (0x0091_0041, "Watchdog Timeout"),
+    (0x0091_0042, "Watchdog Action"),
+    (0x0091_0043, "Watchdog Reboot"),
+    (0x0091_0044, "Watchdog Restart"),
+    (0x0091_0045, "Alarm Input"),
+    (0x0091_0046, "Coin Door Counter"),
+    (0x0091_0047, "I/O Direction Mapping"),
+    (0x0091_0048, "Set I/O Direction Mapping"),
+    (0x0091_0049, "Extended Optical Input State"),
+    (0x0091_004A, "Pin Pad Input State"),
+    (0x0091_004B, "Pin Pad Status"),
+    (0x0091_004C, "Pin Pad Output"),
+    (0x0091_004D, "Pin Pad Command"),
+    (0x0092_0040, "ACK"),
+    (0x0092_0041, "Enable"),
+    (0x0092_0042, "Disable"),
+    (0x0092_0043, "Self Test"),
+    (0x0092_0044, "Request GAT Report"),
+    (0x0092_0047, "Calculate CRC"),
+    (0x0092_0210, "Number of Note Data Entries"),
+    (0x0092_0211, "Read Note Table"),
+    (0x0092_0212, "Extend Timeout"),
+    (0x0092_0213, "Accept Note/Ticket"),
+    (0x0092_0214, "Return Note/Ticket"),
+    (0x0092_021A, "Read Note Acceptor Metrics"),
+    (0xF1D0_0000, "Undefined"),
+    (0xF1D0_0001, "U2F Authenticator Device"),
+    (0xF1D0_0020, "Input Report Data"),
+    (0xF1D0_0021, "Output Report Data"),
+];
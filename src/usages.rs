@@ -0,0 +1,190 @@
+use crate::{
+    __data_to_unsigned, __extended_usage, field::MAX_USAGES, CollectionType, GlobalState,
+    ReportItem, UsageMaximum, UsageMinimum,
+};
+use alloc::{string::String, vec::Vec};
+
+/// Expand a [Usage Minimum](UsageMinimum) / [Usage Maximum](UsageMaximum) pair
+/// into every usage ID in `[min, max]`.
+///
+/// If `max` is less than `min`, the returned iterator is empty. The range stops at
+/// the end of the usage page of `min`, so it yields at most 65536 usages.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{expand_usage_range, UsageMaximum, UsageMinimum};
+///
+/// let min = UsageMinimum::new_with(&[0xE0]).unwrap();
+/// let max = UsageMaximum::new_with(&[0xE7]).unwrap();
+/// let usages = expand_usage_range(&min, &max).collect::<Vec<_>>();
+/// assert_eq!(usages, [0xE0, 0xE1, 0xE2, 0xE3, 0xE4, 0xE5, 0xE6, 0xE7]);
+///
+/// let min = UsageMinimum::new_with(&[0x03]).unwrap();
+/// let max = UsageMaximum::new_with(&[0x01]).unwrap();
+/// assert_eq!(expand_usage_range(&min, &max).count(), 0);
+///
+/// let min = UsageMinimum::new_with(&[0x00]).unwrap();
+/// let max = UsageMaximum::new_with(&[0xFF, 0xFF, 0xFF, 0xFF]).unwrap();
+/// assert_eq!(expand_usage_range(&min, &max).count(), 0x10000);
+/// ```
+pub fn expand_usage_range(min: &UsageMinimum, max: &UsageMaximum) -> impl Iterator<Item = u32> {
+    __usage_range(
+        __data_to_unsigned(min.data()),
+        __data_to_unsigned(max.data()),
+    )
+}
+
+fn __usage_range(min: u32, max: u32) -> impl Iterator<Item = u32> {
+    min..=max.min(min | 0xFFFF)
+}
+
+/// A set of usages bracketed by [Delimiter](crate::Delimiter) items.
+///
+/// Only the first usage of a delimited set is the most preferred one, the others
+/// are alternatives (e.g. the same key on different keyboard layouts).
+/// Usages declared outside of delimiters form an alias set of their own
+/// without alternatives.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsageAlias {
+    /// The most preferred usage.
+    pub preferred: u32,
+    /// Alternative usages, in declaration order.
+    pub alternatives: Vec<u32>,
+}
+
+/// Collect usages declared by local items, grouping delimited sets into [UsageAlias]es.
+///
+/// Usages are extended usages, i.e. the usage page in the high 16 bits and the
+/// usage ID in the low 16 bits, like those of [FieldInfo](crate::FieldInfo).
+///
+/// [Usage Minimum](UsageMinimum) / [Usage Maximum](UsageMaximum) pairs outside of
+/// delimiters are expanded into one alias set per usage, within the usage page of
+/// the minimum. Expansion stops once 1048576 alias sets are collected, so hostile
+/// descriptors can't exhaust memory.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{collect_usages, parse, UsageAlias};
+///
+/// let bytes = [
+///     0x05, 0x07, 0xA9, 0x01, 0x09, 0x14, 0x09, 0x04, 0xA9, 0x00,
+///     0x19, 0x1E, 0x29, 0x1F,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// assert_eq!(
+///     collect_usages(&items),
+///     [
+///         UsageAlias { preferred: 0x0007_0014, alternatives: vec![0x0007_0004] },
+///         UsageAlias { preferred: 0x0007_001E, alternatives: vec![] },
+///         UsageAlias { preferred: 0x0007_001F, alternatives: vec![] },
+///     ]
+/// );
+/// ```
+pub fn collect_usages<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+    item_stream: ItemStream,
+) -> Vec<UsageAlias> {
+    let mut aliases = Vec::new();
+    let mut delimited: Option<Vec<u32>> = None;
+    let mut usage_minimum = None;
+    let mut state = GlobalState::new();
+    for item in item_stream {
+        match item {
+            ReportItem::Delimiter(delimiter) => match __data_to_unsigned(delimiter.data()) {
+                0 => {
+                    if let Some(mut set) = delimited.take() {
+                        if !set.is_empty() {
+                            let preferred = set.remove(0);
+                            aliases.push(UsageAlias {
+                                preferred,
+                                alternatives: set,
+                            });
+                        }
+                    }
+                }
+                _ => delimited = Some(Vec::new()),
+            },
+            ReportItem::Usage(usage) => {
+                let usage = __extended_usage(usage.data(), &state);
+                match &mut delimited {
+                    Some(set) => set.push(usage),
+                    None => aliases.push(UsageAlias {
+                        preferred: usage,
+                        alternatives: Vec::new(),
+                    }),
+                }
+            }
+            ReportItem::UsageMinimum(minimum) if delimited.is_none() => {
+                usage_minimum = Some(__extended_usage(minimum.data(), &state))
+            }
+            ReportItem::UsageMaximum(maximum) if delimited.is_none() => {
+                if let Some(minimum) = usage_minimum.take() {
+                    let maximum = __extended_usage(maximum.data(), &state);
+                    let room = MAX_USAGES.saturating_sub(aliases.len());
+                    aliases.extend(__usage_range(minimum, maximum).take(room).map(|usage| {
+                        UsageAlias {
+                            preferred: usage,
+                            alternatives: Vec::new(),
+                        }
+                    }));
+                }
+            }
+            _ => state.update(item),
+        }
+    }
+    aliases
+}
+
+/// Pair each [Collection](crate::Collection) with the last [Usage](crate::Usage)
+/// declared before it, which names the collection.
+///
+/// The usage is `None` if no usage is declared since the previous main item.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{collection_usages, parse, CollectionType};
+///
+/// let bytes = [
+///     0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x09, 0x01, 0xA1, 0x00, 0xC0, 0xA1, 0x02,
+///     0xC0, 0xC0,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// assert_eq!(
+///     collection_usages(&items),
+///     [
+///         (Some(0x02), CollectionType::Application),
+///         (Some(0x01), CollectionType::Physical),
+///         (None, CollectionType::Logical),
+///     ]
+/// );
+/// ```
+pub fn collection_usages(items: &[ReportItem]) -> Vec<(Option<u32>, CollectionType)> {
+    let mut collections = Vec::new();
+    let mut usage = None;
+    for item in items {
+        match item {
+            ReportItem::Usage(inner) => usage = Some(__data_to_unsigned(inner.data())),
+            ReportItem::Collection(inner) => {
+                collections.push((usage.take(), inner.collection_type()))
+            }
+            ReportItem::Input(_)
+            | ReportItem::Output(_)
+            | ReportItem::Feature(_)
+            | ReportItem::EndCollection(_) => usage = None,
+            _ => (),
+        }
+    }
+    collections
+}
+
+/// Resolve usage names that the built-in usage tables don't know, e.g. usages
+/// on vendor defined pages.
+///
+/// See [`parse_with_resolver()`](crate::parse_with_resolver()).
+pub trait UsageResolver {
+    /// Get the name of `usage` on `usage_page`, or `None` to fall back to the
+    /// built-in usage tables.
+    fn name(&self, usage_page: u32, usage: u32) -> Option<String>;
+}
@@ -0,0 +1,39 @@
+//! Helpers to decode raw item data.
+
+use crate::{__data_to_signed, __data_to_unsigned};
+
+/// Decode little-endian item data of 0, 1, 2 or 4 bytes as a signed value.
+///
+/// Empty data decodes to 0.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::util::data_to_signed;
+///
+/// assert_eq!(data_to_signed(&[]), 0);
+/// assert_eq!(data_to_signed(&[0x81]), -127);
+/// assert_eq!(data_to_signed(&[0x3C, 0x02]), 572);
+/// assert_eq!(data_to_signed(&[0xFF, 0xFF, 0xFF, 0xFF]), -1);
+/// ```
+pub fn data_to_signed(data: &[u8]) -> i32 {
+    __data_to_signed(data)
+}
+
+/// Decode little-endian item data of 0, 1, 2 or 4 bytes as an unsigned value.
+///
+/// Empty data decodes to 0.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::util::data_to_unsigned;
+///
+/// assert_eq!(data_to_unsigned(&[]), 0);
+/// assert_eq!(data_to_unsigned(&[0x81]), 0x81);
+/// assert_eq!(data_to_unsigned(&[0x3C, 0x02]), 572);
+/// assert_eq!(data_to_unsigned(&[0xFF, 0xFF, 0xFF, 0xFF]), u32::MAX);
+/// ```
+pub fn data_to_unsigned(data: &[u8]) -> u32 {
+    __data_to_unsigned(data)
+}
@@ -0,0 +1,204 @@
+use crate::{
+    __data_to_signed, __data_to_unsigned, __extended_usage, GlobalState, ReportId, ReportItem,
+    ReportType,
+};
+use alloc::{format, string::String, vec::Vec};
+
+/// A collection being built, see [`to_webhid_json()`].
+struct CollectionInfo {
+    usage_page: u32,
+    usage: u32,
+    collection_type: u32,
+    children: Vec<String>,
+    /// Report ID and items of each report, indexed by [ReportType].
+    reports: [Vec<(u32, Vec<String>)>; 3],
+}
+
+impl CollectionInfo {
+    fn to_json(&self) -> String {
+        let reports = self.reports.each_ref().map(|reports| {
+            let reports = reports
+                .iter()
+                .map(|(report_id, items)| {
+                    format!(
+                        "{{\"reportId\":{},\"items\":[{}]}}",
+                        report_id,
+                        items.join(",")
+                    )
+                })
+                .collect::<Vec<_>>();
+            reports.join(",")
+        });
+        format!(
+            "{{\"usagePage\":{},\"usage\":{},\"type\":{},\"children\":[{}],\
+            \"inputReports\":[{}],\"outputReports\":[{}],\"featureReports\":[{}]}}",
+            self.usage_page,
+            self.usage,
+            self.collection_type,
+            self.children.join(","),
+            reports[0],
+            reports[1],
+            reports[2]
+        )
+    }
+}
+
+/// Export items as JSON shaped like the `collections` attribute of a WebHID
+/// `HIDDevice`, i.e. an array of top-level collections, each with its nested
+/// `children` and the `inputReports`, `outputReports` and `featureReports` it
+/// declares.
+///
+/// Report items are described with the `HIDReportItem` attributes `usages`,
+/// `usageMinimum`, `usageMaximum`, `isRange`, `reportSize`, `reportCount`,
+/// `unitExponent`, `logicalMinimum`, `logicalMaximum`, `physicalMinimum`,
+/// `physicalMaximum` and the flags of the main item. Usages are extended usages,
+/// i.e. the usage page in the high 16 bits. Reports without
+/// [Report ID](crate::ReportId) get the report ID 0, and main items outside of
+/// any collection are skipped, like WebHID does.
+///
+/// # Example
+///
+/// ```
+/// use hid_report::{parse, to_webhid_json};
+///
+/// let bytes = [
+///     0x05, 0x0C, 0x09, 0x01, 0xA1, 0x01, 0x85, 0x02, 0x09, 0xE9, 0x15, 0x00, 0x25,
+///     0x01, 0x75, 0x01, 0x95, 0x01, 0x81, 0x02, 0xC0,
+/// ];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// let json = to_webhid_json(&items);
+/// assert!(json.starts_with(
+///     r#"[{"usagePage":12,"usage":1,"type":1,"children":[],"inputReports":[{"reportId":2,"#
+/// ));
+/// assert!(json.contains(r#""usages":[786665],"usageMinimum":0,"usageMaximum":0,"#));
+/// assert!(json.contains(r#""reportSize":1,"reportCount":1,"#));
+/// assert!(json.ends_with(r#""outputReports":[],"featureReports":[]}]"#));
+///
+/// // Unit Exponent (-2), encoded as the nibble 0x0E.
+/// let bytes = [0xA1, 0x01, 0x55, 0x0E, 0x75, 0x08, 0x95, 0x01, 0x81, 0x02, 0xC0];
+/// let items = parse(bytes).collect::<Vec<_>>();
+/// assert!(to_webhid_json(&items).contains(r#""unitExponent":-2,"#));
+/// ```
+pub fn to_webhid_json<'a, ItemStream: IntoIterator<Item = &'a ReportItem>>(
+    item_stream: ItemStream,
+) -> String {
+    let mut collections = Vec::new();
+    let mut stack: Vec<CollectionInfo> = Vec::new();
+    let mut state = GlobalState::new();
+    let mut usages = Vec::new();
+    let mut usage_minimum = None;
+    let mut usage_maximum = None;
+    for item in item_stream {
+        let report_type = match item {
+            ReportItem::Input(_) => Some(ReportType::Input),
+            ReportItem::Output(_) => Some(ReportType::Output),
+            ReportItem::Feature(_) => Some(ReportType::Feature),
+            _ => None,
+        };
+        match item {
+            ReportItem::Collection(inner) => {
+                let usage = usages.last().copied().unwrap_or_default();
+                stack.push(CollectionInfo {
+                    usage_page: usage >> 16,
+                    usage: usage & 0xFFFF,
+                    collection_type: __data_to_unsigned(inner.data()),
+                    children: Vec::new(),
+                    reports: Default::default(),
+                });
+            }
+            ReportItem::EndCollection(_) => {
+                if let Some(collection) = stack.pop() {
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(collection.to_json()),
+                        None => collections.push(collection.to_json()),
+                    }
+                }
+            }
+            ReportItem::Usage(inner) => usages.push(__extended_usage(inner.data(), &state)),
+            ReportItem::UsageMinimum(inner) => {
+                usage_minimum = Some(__extended_usage(inner.data(), &state))
+            }
+            ReportItem::UsageMaximum(inner) => {
+                usage_maximum = Some(__extended_usage(inner.data(), &state))
+            }
+            _ => state.update(item),
+        }
+        if let (Some(report_type), Some(collection)) = (report_type, stack.last_mut()) {
+            let report_id = state.report_id.as_ref().map_or(0, ReportId::value);
+            let reports = &mut collection.reports[report_type as usize];
+            let index = match reports.iter().position(|(id, _)| *id == report_id) {
+                Some(index) => index,
+                None => {
+                    reports.push((report_id, Vec::new()));
+                    reports.len() - 1
+                }
+            };
+            let json = __report_item_json(
+                __data_to_unsigned(item.data()),
+                &state,
+                &usages,
+                usage_minimum,
+                usage_maximum,
+            );
+            reports[index].1.push(json);
+        }
+        if report_type.is_some()
+            || matches!(
+                item,
+                ReportItem::Collection(_) | ReportItem::EndCollection(_)
+            )
+        {
+            usages.clear();
+            usage_minimum = None;
+            usage_maximum = None;
+        }
+    }
+    format!("[{}]", collections.join(","))
+}
+
+fn __report_item_json(
+    flags: u32,
+    state: &GlobalState,
+    usages: &[u32],
+    usage_minimum: Option<u32>,
+    usage_maximum: Option<u32>,
+) -> String {
+    let unsigned = |item: Option<&[u8]>| item.map_or(0, __data_to_unsigned);
+    let signed = |item: Option<&[u8]>| item.map_or(0, __data_to_signed);
+    let flag = |bit: u32| flags & (1 << bit) != 0;
+    let usages = usages
+        .iter()
+        .map(|usage| format!("{}", usage))
+        .collect::<Vec<_>>();
+    format!(
+        "{{\"usages\":[{}],\"usageMinimum\":{},\"usageMaximum\":{},\"isRange\":{},\
+        \"reportSize\":{},\"reportCount\":{},\"unitExponent\":{},\
+        \"logicalMinimum\":{},\"logicalMaximum\":{},\
+        \"physicalMinimum\":{},\"physicalMaximum\":{},\
+        \"isConstant\":{},\"isArray\":{},\"isAbsolute\":{},\"wrap\":{},\"isLinear\":{},\
+        \"hasPreferredState\":{},\"hasNull\":{},\"isVolatile\":{},\"isBufferedBytes\":{}}}",
+        usages.join(","),
+        usage_minimum.unwrap_or_default(),
+        usage_maximum.unwrap_or_default(),
+        usage_minimum.is_some() && usage_maximum.is_some(),
+        unsigned(state.report_size.as_ref().map(|item| item.data())),
+        unsigned(state.report_count.as_ref().map(|item| item.data())),
+        state.unit_exponent.as_ref().map_or(0, |item| {
+            item.exponent()
+                .unwrap_or_else(|| __data_to_signed(item.data()))
+        }),
+        signed(state.logical_minimum.as_ref().map(|item| item.data())),
+        signed(state.logical_maximum.as_ref().map(|item| item.data())),
+        signed(state.physical_minimum.as_ref().map(|item| item.data())),
+        signed(state.physical_maximum.as_ref().map(|item| item.data())),
+        flag(0),
+        !flag(1),
+        !flag(2),
+        flag(3),
+        !flag(4),
+        !flag(5),
+        flag(6),
+        flag(7),
+        flag(8),
+    )
+}